@@ -0,0 +1,89 @@
+use criterion::{criterion_group, criterion_main, Bencher, Criterion};
+use futures::future::try_join_all;
+use sqlx::sqlite::{SqliteConnectOptions, SqlitePoolOptions};
+use sqlx::{ConnectOptions, Connection, SqliteConnection, SqlitePool};
+use std::time::{Duration, Instant};
+
+// a query that returns 100 rows without touching any tables
+const QUERY: &str = "WITH RECURSIVE numbers(n) AS (SELECT 1 UNION ALL SELECT n + 1 FROM numbers \
+                     WHERE n < 100) SELECT n FROM numbers";
+
+fn bench_sqlite_connect(c: &mut Criterion) {
+    c.bench_function("bench_sqlite_connect", |b| {
+        let options = SqliteConnectOptions::new();
+
+        b.iter_custom(|iters| {
+            sqlx_rt::block_on(async {
+                let start = Instant::now();
+                for _ in 0..iters {
+                    let conn = options
+                        .connect()
+                        .await
+                        .expect("failed to open connection for benchmark");
+
+                    conn.close().await.expect("failed to close connection");
+                }
+                start.elapsed()
+            })
+        });
+    });
+}
+
+fn bench_sqlite_fetch_all(c: &mut Criterion) {
+    let mut group = c.benchmark_group("bench_sqlite_fetch_all");
+
+    for &concurrent in [1usize, 10, 50, 100, 500].iter() {
+        group.bench_with_input(
+            format!("{} concurrent", concurrent),
+            &concurrent,
+            |b, &concurrent| do_bench_fetch_all(b, concurrent),
+        );
+    }
+
+    group.finish();
+}
+
+fn do_bench_fetch_all(b: &mut Bencher, concurrent: usize) {
+    let pool: SqlitePool = sqlx_rt::block_on(
+        SqlitePoolOptions::new()
+            .connect_timeout(Duration::from_secs(3600))
+            // force the pool to start full, one connection for each task
+            .min_connections(concurrent as u32)
+            .max_connections(concurrent as u32)
+            // we're not benchmarking `ping()`
+            .test_before_acquire(false)
+            .connect("sqlite::memory:"),
+    )
+    .expect("failed to open SqlitePool");
+
+    b.iter_custom(|iters| {
+        sqlx_rt::block_on(async {
+            // hold every connection for the whole run
+            let mut conns: Vec<_> = try_join_all((0..concurrent).map(|_| pool.acquire()))
+                .await
+                .expect("failed to acquire connections for benchmark");
+
+            // take the start time inside the future to make sure we only count once it's running
+            let start = Instant::now();
+            for _ in 0..iters {
+                try_join_all(
+                    conns
+                        .iter_mut()
+                        .map(|conn| sqlx::query(QUERY).fetch_all(conn)),
+                )
+                .await
+                .expect("failed to execute query for benchmark");
+            }
+            let elapsed = start.elapsed();
+
+            drop(criterion::black_box(conns));
+
+            elapsed
+        })
+    });
+
+    sqlx_rt::block_on(pool.close());
+}
+
+criterion_group!(sqlite, bench_sqlite_connect, bench_sqlite_fetch_all);
+criterion_main!(sqlite);
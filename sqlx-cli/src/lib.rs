@@ -20,9 +20,21 @@ pub async fn run(opt: Opt) -> anyhow::Result<()> {
 
     match opt.command {
         Command::Migrate(migrate) => match migrate.command {
-            MigrateCommand::Add { description } => migrate::add(&description)?,
-            MigrateCommand::Run => migrate::run(&database_url).await?,
-            MigrateCommand::Info => migrate::info(&database_url).await?,
+            MigrateCommand::Add {
+                description,
+                reversible,
+            } => migrate::add(&description, reversible)?,
+            MigrateCommand::Run {
+                dry_run: false,
+                script: None,
+            } => migrate::run(&database_url).await?,
+            MigrateCommand::Run { script, .. } => {
+                migrate::dry_run(&database_url, script.as_deref()).await?
+            }
+            MigrateCommand::Revert { target_version } => {
+                migrate::revert(&database_url, target_version).await?
+            }
+            MigrateCommand::Info { json } => migrate::info(&database_url, json).await?,
         },
 
         Command::Database(database) => match database.command {
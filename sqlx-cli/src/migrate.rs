@@ -1,57 +1,208 @@
 use anyhow::{bail, Context};
-use console::style;
-use sqlx::migrate::{Migrate, MigrateError, Migrator};
+use console::{style, StyledObject};
+use sqlx::migrate::{Migrate, MigrateError, MigrationType, Migrator};
 use sqlx::{AnyConnection, Connection};
+use std::collections::{BTreeMap, BTreeSet};
 use std::fs::{self, File};
 use std::io::Write;
 use std::path::Path;
+use std::time::Instant;
 
 const MIGRATION_FOLDER: &'static str = "migrations";
 
-pub fn add(description: &str) -> anyhow::Result<()> {
-    use chrono::prelude::*;
+fn create_file(
+    migration_source: &str,
+    file_prefix: &str,
+    description: &str,
+    migration_type: MigrationType,
+) -> anyhow::Result<()> {
     use std::path::PathBuf;
 
-    fs::create_dir_all(MIGRATION_FOLDER).context("Unable to create migrations directory")?;
-
-    let dt = Utc::now();
-    let mut file_name = dt.format("%Y%m%d%H%M%S").to_string();
+    let mut file_name = file_prefix.to_string();
     file_name.push_str("_");
     file_name.push_str(&description.replace(' ', "_"));
-    file_name.push_str(".sql");
+    file_name.push_str(match migration_type {
+        MigrationType::Simple => ".sql",
+        MigrationType::ReversibleUp => ".up.sql",
+        MigrationType::ReversibleDown => ".down.sql",
+    });
 
     let mut path = PathBuf::new();
-    path.push(MIGRATION_FOLDER);
+    path.push(migration_source);
     path.push(&file_name);
 
     println!("Creating {}", style(path.display()).cyan());
 
     let mut file = File::create(&path).context("Failed to create migration file")?;
 
-    file.write_all(b"-- Add migration script here\n")?;
+    file.write_all(if migration_type.is_down_migration() {
+        b"-- Add revert script here\n"
+    } else {
+        b"-- Add migration script here\n"
+    })?;
 
     Ok(())
 }
 
-pub async fn info(uri: &str) -> anyhow::Result<()> {
+pub fn add(description: &str, reversible: bool) -> anyhow::Result<()> {
+    use chrono::prelude::*;
+
+    fs::create_dir_all(MIGRATION_FOLDER).context("Unable to create migrations directory")?;
+
+    let dt = Utc::now();
+    let file_prefix = dt.format("%Y%m%d%H%M%S").to_string();
+
+    if reversible {
+        create_file(
+            MIGRATION_FOLDER,
+            &file_prefix,
+            description,
+            MigrationType::ReversibleUp,
+        )?;
+        create_file(
+            MIGRATION_FOLDER,
+            &file_prefix,
+            description,
+            MigrationType::ReversibleDown,
+        )?;
+    } else {
+        create_file(
+            MIGRATION_FOLDER,
+            &file_prefix,
+            description,
+            MigrationType::Simple,
+        )?;
+    }
+
+    Ok(())
+}
+
+/// The state of a migration, as reported by `sqlx migrate info`.
+enum MigrationStatus {
+    /// Not yet applied to the database.
+    Pending,
+
+    /// Applied successfully; the local file matches what was applied.
+    Applied,
+
+    /// Failed part way through, leaving the database dirty.
+    Dirty,
+
+    /// Applied to the database, but the local file no longer exists.
+    Missing,
+
+    /// Applied to the database, but the local file has changed since.
+    ChecksumMismatch,
+}
+
+impl MigrationStatus {
+    fn as_str(&self) -> &'static str {
+        match self {
+            MigrationStatus::Pending => "pending",
+            MigrationStatus::Applied => "applied",
+            MigrationStatus::Dirty => "dirty",
+            MigrationStatus::Missing => "missing",
+            MigrationStatus::ChecksumMismatch => "checksum_mismatch",
+        }
+    }
+
+    fn styled(&self) -> StyledObject<&'static str> {
+        match self {
+            MigrationStatus::Pending => style("pending").yellow(),
+            MigrationStatus::Applied => style("applied").green(),
+            MigrationStatus::Dirty => style("dirty").red(),
+            MigrationStatus::Missing => style("missing").red(),
+            MigrationStatus::ChecksumMismatch => style("checksum mismatch").red(),
+        }
+    }
+}
+
+pub async fn info(uri: &str, json: bool) -> anyhow::Result<()> {
     let migrator = Migrator::new(Path::new(MIGRATION_FOLDER)).await?;
     let mut conn = AnyConnection::connect(uri).await?;
 
     conn.ensure_migrations_table().await?;
 
-    let (version, _) = conn.version().await?.unwrap_or((0, false));
+    let local: BTreeMap<_, _> = migrator
+        .iter()
+        .filter(|m| m.migration_type().is_up_migration())
+        .map(|m| (m.version(), m))
+        .collect();
 
-    for migration in migrator.iter() {
-        println!(
+    let applied: BTreeMap<_, _> = conn
+        .list_applied_migrations()
+        .await?
+        .into_iter()
+        .map(|m| (m.version, m))
+        .collect();
+
+    let versions: BTreeSet<i64> = local.keys().chain(applied.keys()).copied().collect();
+
+    let mut entries = Vec::with_capacity(versions.len());
+
+    for version in versions {
+        let local = local.get(&version);
+        let applied = applied.get(&version);
+
+        let status = match (local, applied) {
+            (_, Some(applied)) if !applied.success => MigrationStatus::Dirty,
+            (None, Some(_)) => MigrationStatus::Missing,
+            (Some(local), Some(applied)) if *applied.checksum != *local.checksum => {
+                MigrationStatus::ChecksumMismatch
+            }
+            (Some(_), Some(_)) => MigrationStatus::Applied,
+            (Some(_), None) => MigrationStatus::Pending,
+            (None, None) => unreachable!(),
+        };
+
+        let description = match (local, applied) {
+            (Some(local), _) => local.description(),
+            (None, Some(applied)) => &*applied.description,
+            (None, None) => unreachable!(),
+        };
+
+        entries.push((version, description, status, applied));
+    }
+
+    if json {
+        let entries: Vec<_> = entries
+            .iter()
+            .map(|(version, description, status, applied)| {
+                serde_json::json!({
+                    "version": version,
+                    "description": description,
+                    "status": status.as_str(),
+                    "installed_on": applied.map(|m| &m.installed_on),
+                    "execution_time": applied.map(|m| m.execution_time.as_nanos() as u64),
+                })
+            })
+            .collect();
+
+        println!("{}", serde_json::to_string_pretty(&entries)?);
+
+        return Ok(());
+    }
+
+    for (version, description, status, applied) in entries {
+        print!(
             "{}/{} {}",
-            style(migration.version()).cyan(),
-            if version >= migration.version() {
-                style("installed").green()
-            } else {
-                style("pending").yellow()
-            },
-            migration.description(),
+            style(version).cyan(),
+            status.styled(),
+            description
         );
+
+        if let Some(applied) = applied {
+            print!(
+                " {}",
+                style(format!(
+                    "(installed on {}, took {:?})",
+                    applied.installed_on, applied.execution_time
+                ))
+                .dim()
+            );
+        }
+
+        println!();
     }
 
     Ok(())
@@ -70,13 +221,17 @@ pub async fn run(uri: &str) -> anyhow::Result<()> {
     }
 
     for migration in migrator.iter() {
+        if migration.migration_type().is_down_migration() {
+            continue;
+        }
+
         if migration.version() > version {
             let elapsed = conn.apply(migration).await?;
 
             println!(
                 "{}/{} {} {}",
                 style(migration.version()).cyan(),
-                style("migrate").green(),
+                style(migration.migration_type().label()).green(),
                 migration.description(),
                 style(format!("({:?})", elapsed)).dim()
             );
@@ -87,3 +242,101 @@ pub async fn run(uri: &str) -> anyhow::Result<()> {
 
     Ok(())
 }
+
+pub async fn dry_run(uri: &str, script: Option<&Path>) -> anyhow::Result<()> {
+    let migrator = Migrator::new(Path::new(MIGRATION_FOLDER)).await?;
+    let mut conn = AnyConnection::connect(uri).await?;
+
+    let pending = migrator.pending(&mut conn).await?;
+
+    if pending.is_empty() {
+        println!("No pending migrations");
+
+        return Ok(());
+    }
+
+    let mut sql = String::new();
+
+    for migration in &pending {
+        sql.push_str(&format!(
+            "-- {} {}\n",
+            migration.version(),
+            migration.description()
+        ));
+        sql.push_str(migration.sql().trim_end());
+        sql.push_str("\n\n");
+    }
+
+    match script {
+        Some(path) => {
+            fs::write(path, sql).context("Failed to write migration script")?;
+
+            println!(
+                "Wrote {} pending migration(s) to {}",
+                pending.len(),
+                style(path.display()).cyan()
+            );
+        }
+
+        None => print!("{}", sql),
+    }
+
+    Ok(())
+}
+
+pub async fn revert(uri: &str, target_version: Option<i64>) -> anyhow::Result<()> {
+    let migrator = Migrator::new(Path::new(MIGRATION_FOLDER)).await?;
+    let mut conn = AnyConnection::connect(uri).await?;
+
+    conn.ensure_migrations_table().await?;
+
+    let (version, dirty) = conn.version().await?.unwrap_or((0, false));
+
+    if dirty {
+        bail!(MigrateError::Dirty(version));
+    }
+
+    let applied = migrator
+        .iter()
+        .filter(|m| m.migration_type().is_up_migration() && m.version() <= version);
+
+    // unless given a target, only revert the latest applied migration
+    let target_version = match target_version {
+        Some(target_version) => target_version,
+        None => applied
+            .clone()
+            .map(|m| m.version())
+            .filter(|v| *v < version)
+            .max()
+            .unwrap_or(0),
+    };
+
+    let reverts: Vec<_> = applied
+        .rev()
+        .filter(|m| m.version() > target_version)
+        .collect();
+
+    if reverts.is_empty() {
+        println!("No migrations to revert");
+
+        return Ok(());
+    }
+
+    // the migrator locks the database while it reverts, as it does when it runs migrations
+    let start = Instant::now();
+    migrator.undo(&mut conn, target_version).await?;
+    let elapsed = start.elapsed();
+
+    for migration in reverts {
+        println!(
+            "{}/{} {}",
+            style(migration.version()).cyan(),
+            style(MigrationType::ReversibleDown.label()).green(),
+            migration.description(),
+        );
+    }
+
+    println!("{}", style(format!("({:?})", elapsed)).dim());
+
+    Ok(())
+}
@@ -1,4 +1,5 @@
 use clap::Clap;
+use std::path::PathBuf;
 
 #[derive(Clap, Debug)]
 pub struct Opt {
@@ -67,11 +68,44 @@ pub struct MigrateOpt {
 pub enum MigrateCommand {
     /// Create a new migration with the given description,
     /// and the current time as the version.
-    Add { description: String },
+    Add {
+        description: String,
+
+        /// Create a pair of up and down migration files, so that the migration can be
+        /// reverted.
+        #[clap(short)]
+        reversible: bool,
+    },
 
     /// Run all pending migrations.
-    Run,
+    Run {
+        /// Print the SQL of each pending migration, in order, instead of running them.
+        /// The migrations table is not locked.
+        #[clap(long)]
+        dry_run: bool,
+
+        /// Write the SQL of the pending migrations to this file as a single script,
+        /// instead of running them. Implies `--dry-run`.
+        #[clap(long)]
+        script: Option<PathBuf>,
+    },
 
-    /// List all available migrations.
-    Info,
+    /// Revert the latest applied migration.
+    Revert {
+        /// Revert every migration with a version greater than this one, instead of only
+        /// the latest. Use `0` to revert all migrations.
+        #[clap(long)]
+        target_version: Option<i64>,
+    },
+
+    /// List all migrations, both local and applied, along with their status.
+    ///
+    /// The status of a migration is one of `pending`, `applied`, `dirty` (failed part way
+    /// through), `missing` (applied but no longer present locally) or `checksum mismatch`
+    /// (changed locally since it was applied).
+    Info {
+        /// Print the list as JSON; execution times are in nanoseconds.
+        #[clap(long)]
+        json: bool,
+    },
 }
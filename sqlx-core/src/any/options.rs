@@ -2,7 +2,9 @@ use crate::any::AnyConnection;
 use crate::connection::ConnectOptions;
 use crate::error::Error;
 use futures_core::future::BoxFuture;
+use log::LevelFilter;
 use std::str::FromStr;
+use std::time::Duration;
 
 #[cfg(feature = "postgres")]
 use crate::postgres::PgConnectOptions;
@@ -85,6 +87,32 @@ impl FromStr for AnyConnectOptions {
     }
 }
 
+macro_rules! delegate_to_options {
+    ($self:ident.$method:ident($($arg:ident),*)) => {
+        AnyConnectOptions(match $self.0 {
+            #[cfg(feature = "postgres")]
+            AnyConnectOptionsKind::Postgres(options) => {
+                AnyConnectOptionsKind::Postgres(options.$method($($arg),*))
+            }
+
+            #[cfg(feature = "mysql")]
+            AnyConnectOptionsKind::MySql(options) => {
+                AnyConnectOptionsKind::MySql(options.$method($($arg),*))
+            }
+
+            #[cfg(feature = "sqlite")]
+            AnyConnectOptionsKind::Sqlite(options) => {
+                AnyConnectOptionsKind::Sqlite(options.$method($($arg),*))
+            }
+
+            #[cfg(feature = "mssql")]
+            AnyConnectOptionsKind::Mssql(options) => {
+                AnyConnectOptionsKind::Mssql(options.$method($($arg),*))
+            }
+        })
+    };
+}
+
 impl ConnectOptions for AnyConnectOptions {
     type Connection = AnyConnection;
 
@@ -92,4 +120,16 @@ impl ConnectOptions for AnyConnectOptions {
     fn connect(&self) -> BoxFuture<'_, Result<AnyConnection, Error>> {
         Box::pin(AnyConnection::establish(self))
     }
+
+    fn log_statements(self, level: LevelFilter) -> Self {
+        delegate_to_options!(self.log_statements(level))
+    }
+
+    fn log_slow_statements(self, level: LevelFilter, duration: Duration) -> Self {
+        delegate_to_options!(self.log_slow_statements(level, duration))
+    }
+
+    fn log_parameters(self, enabled: bool) -> Self {
+        delegate_to_options!(self.log_parameters(enabled))
+    }
 }
@@ -48,6 +48,12 @@ impl<T> StatementCache<T> {
         self.inner.remove_lru().map(|(_, v)| v)
     }
 
+    /// Returns a mutable reference to the most recently used statement in the cache, if any.
+    #[cfg(feature = "sqlite")]
+    pub fn get_most_recent_mut(&mut self) -> Option<&mut T> {
+        self.inner.iter_mut().next_back().map(|(_, v)| v)
+    }
+
     /// Clear all cached statements from the cache.
     #[cfg(feature = "sqlite")]
     pub fn clear(&mut self) {
@@ -3,8 +3,10 @@ use crate::error::Error;
 use crate::transaction::Transaction;
 use futures_core::future::BoxFuture;
 use futures_core::Future;
+use log::LevelFilter;
 use std::fmt::Debug;
 use std::str::FromStr;
+use std::time::Duration;
 
 /// Represents a single database connection.
 pub trait Connection: Send {
@@ -108,6 +110,44 @@ pub trait Connection: Send {
     }
 }
 
+/// How the statements executed on a connection are logged.
+///
+/// Statements are logged to the `sqlx::query` target once they have completed, along with
+/// the number of rows they returned and how long they took to execute.
+#[derive(Clone, Debug)]
+pub(crate) struct LogSettings {
+    pub(crate) statements_level: LevelFilter,
+    pub(crate) slow_statements_level: LevelFilter,
+    pub(crate) slow_statements_duration: Duration,
+    pub(crate) parameters: bool,
+}
+
+impl Default for LogSettings {
+    fn default() -> Self {
+        LogSettings {
+            statements_level: LevelFilter::Debug,
+            slow_statements_level: LevelFilter::Warn,
+            slow_statements_duration: Duration::from_secs(1),
+            parameters: false,
+        }
+    }
+}
+
+impl LogSettings {
+    pub(crate) fn log_statements(&mut self, level: LevelFilter) {
+        self.statements_level = level;
+    }
+
+    pub(crate) fn log_slow_statements(&mut self, level: LevelFilter, duration: Duration) {
+        self.slow_statements_level = level;
+        self.slow_statements_duration = duration;
+    }
+
+    pub(crate) fn log_parameters(&mut self, enabled: bool) {
+        self.parameters = enabled;
+    }
+}
+
 pub trait ConnectOptions: 'static + Send + Sync + FromStr<Err = Error> + Debug {
     type Connection: Connection + ?Sized;
 
@@ -115,4 +155,28 @@ pub trait ConnectOptions: 'static + Send + Sync + FromStr<Err = Error> + Debug {
     fn connect(&self) -> BoxFuture<'_, Result<Self::Connection, Error>>
     where
         Self::Connection: Sized;
+
+    /// Sets the level at which executed statements are logged.
+    ///
+    /// The default level is [`LevelFilter::Debug`].
+    fn log_statements(self, level: LevelFilter) -> Self;
+
+    /// Sets the level at which statements that take longer than `duration` to execute
+    /// are logged, instead of the level set by [`log_statements`][Self::log_statements].
+    ///
+    /// By default, statements that take longer than 1 second are logged at
+    /// [`LevelFilter::Warn`].
+    fn log_slow_statements(self, level: LevelFilter, duration: Duration) -> Self;
+
+    /// Sets whether the bound parameters of a statement are included when it is logged.
+    ///
+    /// The values of the parameters are never logged, only their types. This is disabled
+    /// by default.
+    fn log_parameters(self, enabled: bool) -> Self;
+
+    /// Entirely disables logging of statements, slow or not.
+    fn disable_statement_logging(self) -> Self {
+        self.log_statements(LevelFilter::Off)
+            .log_slow_statements(LevelFilter::Off, Duration::default())
+    }
 }
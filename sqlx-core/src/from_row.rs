@@ -73,10 +73,126 @@ use crate::row::Row;
 /// will set the value of the field `location` to the default value of `Option<String>`,
 /// which is `None`.
 ///
+/// #### `flatten`
+///
+/// If you want to handle a field that implements [`FromRow`],
+/// you can use the `flatten` attribute to specify that you want
+/// it to use [`FromRow`] for parsing rather than the usual method.
+/// For example:
+///
+/// ```rust,ignore
+/// #[derive(sqlx::FromRow)]
+/// struct Address {
+///     country: String,
+///     city: String,
+///     road: String,
+/// }
+///
+/// #[derive(sqlx::FromRow)]
+/// struct User {
+///     id: i32,
+///     name: String,
+///     #[sqlx(flatten)]
+///     address: Address,
+/// }
+/// ```
+///
+/// Given a query such as:
+///
+/// ```sql
+/// SELECT id, name, country, city, road FROM users;
+/// ```
+///
+/// will read the columns `country`, `city` and `road` into the field `address`.
+///
+/// When the columns of the nested struct are prefixed, as is common when joining tables,
+/// the prefix can be given with `prefix`. This requires the nested struct to derive
+/// `FromRow` as well:
+///
+/// ```rust,ignore
+/// #[derive(sqlx::FromRow)]
+/// struct User {
+///     id: i32,
+///     name: String,
+///     #[sqlx(flatten, prefix = "billing_")]
+///     billing_address: Address,
+/// }
+/// ```
+///
+/// Given a query such as:
+///
+/// ```sql
+/// SELECT id, name, billing_country, billing_city, billing_road FROM users;
+/// ```
+///
+/// will read the columns `billing_country`, `billing_city` and `billing_road` into the
+/// field `billing_address`.
+///
+/// #### `try_from`
+///
+/// When the type of a field does not implement [`Decode`], but can be converted from one that
+/// does with [`TryFrom`], you can use the `try_from` attribute to name the type that the
+/// column is decoded as. For example:
+///
+/// ```rust,ignore
+/// struct UserId(i64);
+///
+/// impl TryFrom<i64> for UserId {
+///     // ..
+/// }
+///
+/// #[derive(sqlx::FromRow)]
+/// struct User {
+///     #[sqlx(try_from = "i64")]
+///     id: UserId,
+///     name: String,
+/// }
+/// ```
+///
+/// will decode the column `id` as an `i64` and convert it into a `UserId`. If the
+/// conversion fails, its error is returned as [`Error::ColumnDecode`].
+///
+/// #### `skip`
+///
+/// When a field is not read from the row at all, such as a value computed later on,
+/// you can use the `skip` attribute to set it to its [`Default`] value instead. For example:
+///
+/// ```rust,ignore
+/// #[derive(sqlx::FromRow)]
+/// struct User {
+///     id: i32,
+///     name: String,
+///     #[sqlx(skip)]
+///     permissions: Vec<String>,
+/// }
+/// ```
+///
 /// [`query_as`]: fn.query_as.html
 /// [`Row::try_get`]: trait.Row.html#method.try_get
+/// [`Decode`]: crate::decode::Decode
+/// [`TryFrom`]: std::convert::TryFrom
 pub trait FromRow<'r, R: Row>: Sized {
     fn from_row(row: &'r R) -> Result<Self, Error>;
+
+    /// Builds `Self` from the columns of `row` whose names start with `prefix`; used by
+    /// `#[sqlx(flatten, prefix = "..")]`.
+    ///
+    /// Implemented by `#[derive(FromRow)]`. Any other implementation only supports an
+    /// empty prefix.
+    #[doc(hidden)]
+    fn from_row_prefixed(row: &'r R, prefix: &str) -> Result<Self, Error> {
+        if prefix.is_empty() {
+            Self::from_row(row)
+        } else {
+            Err(Error::Decode(
+                format!(
+                    "`{}` does not support reading columns with a prefix",
+                    std::any::type_name::<Self>()
+                )
+                .into(),
+            ))
+        }
+    }
 }
 
 // implement FromRow for tuples of types that implement Decode
@@ -73,12 +73,32 @@ where
     }
 
     pub async fn read_raw(&mut self, cnt: usize) -> Result<BytesMut, Error> {
-        read_raw_into(&mut self.stream, &mut self.rbuf, cnt).await?;
+        self.fill(cnt).await?;
         let buf = self.rbuf.split_to(cnt);
 
         Ok(buf)
     }
 
+    /// Returns the next `cnt` bytes from the stream without consuming them.
+    pub async fn peek_raw(&mut self, cnt: usize) -> Result<&[u8], Error> {
+        self.fill(cnt).await?;
+
+        Ok(&self.rbuf[..cnt])
+    }
+
+    // ensures that at least `cnt` bytes are in the read buffer
+    async fn fill(&mut self, cnt: usize) -> Result<(), Error> {
+        // the read buffer may still hold the first part of a read that was interrupted
+        // by its future being dropped
+        let buffered = self.rbuf.len();
+
+        if cnt > buffered {
+            read_raw_into(&mut self.stream, &mut self.rbuf, cnt - buffered).await?;
+        }
+
+        Ok(())
+    }
+
     pub async fn read_raw_into(&mut self, buf: &mut BytesMut, cnt: usize) -> Result<(), Error> {
         read_raw_into(&mut self.stream, buf, cnt).await
     }
@@ -114,11 +134,14 @@ async fn read_raw_into<S: AsyncRead + Unpin>(
     // zero-fills the space in the read buffer
     buf.resize(offset + cnt, 0);
 
-    let mut read = offset;
-    while (offset + cnt) > read {
+    // if we return early, or this future is dropped, the space that was not read into is
+    // removed again so it is not mistaken for data
+    let mut buf = Filled { buf, len: offset };
+
+    while (offset + cnt) > buf.len {
         // read in bytes from the stream into the read buffer starting
         // from the offset we last read from
-        let n = stream.read(&mut buf[read..]).await?;
+        let n = stream.read(&mut buf.buf[buf.len..]).await?;
 
         if n == 0 {
             // a zero read when we had space in the read buffer
@@ -129,8 +152,20 @@ async fn read_raw_into<S: AsyncRead + Unpin>(
             return Err(io::Error::from(io::ErrorKind::ConnectionAborted).into());
         }
 
-        read += n;
+        buf.len += n;
     }
 
     Ok(())
 }
+
+// truncates a buffer to the length that has been read into when dropped
+struct Filled<'a> {
+    buf: &'a mut BytesMut,
+    len: usize,
+}
+
+impl Drop for Filled<'_> {
+    fn drop(&mut self) {
+        self.buf.truncate(self.len);
+    }
+}
@@ -1,45 +1,111 @@
-use std::time::Duration;
-
-pub(crate) const SLOW_QUERY_THRESHOLD: Duration = Duration::from_secs(1);
-
-/// Logs the query and execution time of a statement as it runs.
-macro_rules! log_execution {
-    ( $query:expr, $block:expr ) => {{
-        // TODO: Log bound parameters
-        let query_string = $query.query_string();
-        let timer = std::time::Instant::now();
-        let result = $block;
-        let elapsed = timer.elapsed();
-        if elapsed >= crate::logging::SLOW_QUERY_THRESHOLD {
-            log::warn!(
-                target: "sqlx::query",
-                "{} ..., elapsed: {:.3?}\n\n{}\n",
-                crate::logging::parse_query_summary(query_string),
-                elapsed,
-                sqlformat::format(
-                    query_string,
-                    &sqlformat::QueryParams::None,
-                    sqlformat::FormatOptions::default()
-                )
-            );
+use std::fmt::{Display, Write};
+use std::time::Instant;
+
+use crate::connection::LogSettings;
+
+/// Logs the query, its bound parameters (if enabled) and execution time of a statement once
+/// it has run; that is, when the logger is dropped.
+pub(crate) struct QueryLogger<'q> {
+    sql: &'q str,
+    parameters: Option<String>,
+    rows: u64,
+    start: Instant,
+    settings: LogSettings,
+}
+
+impl<'q> QueryLogger<'q> {
+    pub(crate) fn new(sql: &'q str, settings: LogSettings) -> Self {
+        Self {
+            sql,
+            parameters: None,
+            rows: 0,
+            start: Instant::now(),
+            settings,
+        }
+    }
+
+    /// Records the bound parameters of the statement.
+    ///
+    /// Values are never logged; each parameter is described by its type alone. Nothing is
+    /// recorded unless parameter logging was enabled on the connection options.
+    pub(crate) fn parameters<I>(&mut self, parameters: I)
+    where
+        I: IntoIterator,
+        I::Item: Display,
+    {
+        if !self.settings.parameters {
+            return;
+        }
+
+        let mut s = String::from("[");
+
+        for (i, ty) in parameters.into_iter().enumerate() {
+            if i > 0 {
+                s.push_str(", ");
+            }
+
+            let _ = write!(s, "<{}>", ty);
+        }
+
+        s.push(']');
+
+        self.parameters = Some(s);
+    }
+
+    pub(crate) fn increment_rows(&mut self) {
+        self.rows += 1;
+    }
+
+    fn finish(&self) {
+        let elapsed = self.start.elapsed();
+
+        let level = if elapsed >= self.settings.slow_statements_duration {
+            self.settings.slow_statements_level
         } else {
-            log::debug!(
-                target: "sqlx::query",
-                "{} ..., elapsed: {:.3?}\n\n{}\n",
-                crate::logging::parse_query_summary(query_string),
-                elapsed,
-                sqlformat::format(
-                    query_string,
-                    &sqlformat::QueryParams::None,
-                    sqlformat::FormatOptions::default()
-                )
+            self.settings.statements_level
+        };
+
+        if let Some(level) = level
+            .to_level()
+            .filter(|level| log::log_enabled!(target: "sqlx::query", *level))
+        {
+            let summary = parse_query_summary(self.sql);
+
+            let parameters = match &self.parameters {
+                Some(parameters) => format!(", parameters: {}", parameters),
+                None => String::new(),
+            };
+
+            log::logger().log(
+                &log::Record::builder()
+                    .args(format_args!(
+                        "{} ...; rows: {}, elapsed: {:.3?}{}\n\n{}\n",
+                        summary,
+                        self.rows,
+                        elapsed,
+                        parameters,
+                        sqlformat::format(
+                            self.sql,
+                            &sqlformat::QueryParams::None,
+                            sqlformat::FormatOptions::default()
+                        )
+                    ))
+                    .level(level)
+                    .module_path_static(Some("sqlx::query"))
+                    .target("sqlx::query")
+                    .build(),
             );
         }
-        result
-    }};
+    }
 }
 
-pub(crate) fn parse_query_summary(query: &str) -> String {
+impl Drop for QueryLogger<'_> {
+    fn drop(&mut self) {
+        self.finish();
+    }
+}
+
+fn parse_query_summary(query: &str) -> String {
     // For now, just take the first 3 words
     query
         .split_whitespace()
@@ -47,3 +113,109 @@ pub(crate) fn parse_query_summary(query: &str) -> String {
         .collect::<Vec<&str>>()
         .join(" ")
 }
+
+#[cfg(test)]
+mod tests {
+    use std::cell::RefCell;
+    use std::time::Duration;
+
+    use log::{Level, LevelFilter, Log, Metadata, Record};
+
+    use super::QueryLogger;
+    use crate::connection::LogSettings;
+
+    // records are kept per thread as tests run in parallel
+    thread_local! {
+        static RECORDS: RefCell<Vec<(Level, String)>> = RefCell::new(Vec::new());
+    }
+
+    struct CapturingLogger;
+
+    impl Log for CapturingLogger {
+        fn enabled(&self, _: &Metadata<'_>) -> bool {
+            true
+        }
+
+        fn log(&self, record: &Record<'_>) {
+            RECORDS.with(|records| {
+                records
+                    .borrow_mut()
+                    .push((record.level(), record.args().to_string()))
+            });
+        }
+
+        fn flush(&self) {}
+    }
+
+    static LOGGER: CapturingLogger = CapturingLogger;
+
+    fn capture(f: impl FnOnce()) -> Vec<(Level, String)> {
+        // fails if the logger was already set by another test
+        let _ = log::set_logger(&LOGGER);
+        log::set_max_level(LevelFilter::Trace);
+
+        RECORDS.with(|records| records.borrow_mut().clear());
+        f();
+        RECORDS.with(|records| records.borrow_mut().drain(..).collect())
+    }
+
+    #[test]
+    fn it_logs_statements_at_the_configured_level() {
+        let mut settings = LogSettings::default();
+        settings.log_statements(LevelFilter::Info);
+        settings.log_slow_statements(LevelFilter::Warn, Duration::from_secs(3600));
+
+        let records = capture(|| drop(QueryLogger::new("SELECT 1", settings.clone())));
+
+        assert_eq!(records.len(), 1);
+        assert_eq!(records[0].0, Level::Info);
+        assert!(records[0].1.starts_with("SELECT 1 ...; rows: 0"));
+
+        settings.log_statements(LevelFilter::Off);
+
+        let records = capture(|| drop(QueryLogger::new("SELECT 1", settings)));
+
+        assert!(records.is_empty());
+    }
+
+    #[test]
+    fn it_logs_slow_statements_at_the_configured_level() {
+        let mut settings = LogSettings::default();
+        settings.log_statements(LevelFilter::Info);
+        settings.log_slow_statements(LevelFilter::Error, Duration::from_millis(10));
+
+        let records = capture(|| {
+            let _logger = QueryLogger::new("SELECT pg_sleep(1)", settings);
+            std::thread::sleep(Duration::from_millis(20));
+        });
+
+        assert_eq!(records.len(), 1);
+        assert_eq!(records[0].0, Level::Error);
+    }
+
+    #[test]
+    fn it_logs_the_types_of_parameters_only_when_enabled() {
+        let mut settings = LogSettings::default();
+        settings.log_statements(LevelFilter::Info);
+
+        let records = capture(|| {
+            let mut logger = QueryLogger::new("SELECT $1, $2", settings.clone());
+            logger.parameters(&["INT4", "TEXT"]);
+        });
+
+        assert!(!records[0].1.contains("parameters"));
+
+        settings.log_parameters(true);
+
+        let records = capture(|| {
+            let mut logger = QueryLogger::new("SELECT $1, $2", settings);
+            logger.parameters(&["INT4", "TEXT"]);
+            logger.increment_rows();
+        });
+
+        assert!(records[0]
+            .1
+            .starts_with("SELECT $1, $2 ...; rows: 1, elapsed: "));
+        assert!(records[0].1.contains(", parameters: [<INT4>, <TEXT>]\n"));
+    }
+}
@@ -20,4 +20,7 @@ pub enum MigrateError {
         "migration {0} is partially applied; fix and remove row from `_sqlx_migrations` table"
     )]
     Dirty(i64),
+
+    #[error("migration {0} cannot be reverted as it has no down migration")]
+    Irreversible(i64),
 }
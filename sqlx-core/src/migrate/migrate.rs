@@ -1,5 +1,5 @@
 use crate::error::Error;
-use crate::migrate::{MigrateError, Migration};
+use crate::migrate::{AppliedMigration, MigrateError, Migration};
 use futures_core::future::BoxFuture;
 use std::time::Duration;
 
@@ -23,10 +23,18 @@ pub trait Migrate {
     // will create or migrate it if needed
     fn ensure_migrations_table(&mut self) -> BoxFuture<'_, Result<(), MigrateError>>;
 
+    // Return whether the [_migrations] table exists, without creating it.
+    fn migrations_table_exists(&mut self) -> BoxFuture<'_, Result<bool, MigrateError>>;
+
     // Return the current version and if the database is "dirty".
     // "dirty" means there is a partially applied migration that failed.
     fn version(&mut self) -> BoxFuture<'_, Result<Option<(i64, bool)>, MigrateError>>;
 
+    // Return every migration recorded in the [_migrations] table, ordered by version.
+    fn list_applied_migrations(
+        &mut self,
+    ) -> BoxFuture<'_, Result<Vec<AppliedMigration>, MigrateError>>;
+
     // Should acquire a database lock so that only one migration process
     // can run at a time. [`Migrate`] will call this function before applying
     // any migrations.
@@ -50,4 +58,12 @@ pub trait Migrate {
         &'e mut self,
         migration: &'m Migration,
     ) -> BoxFuture<'m, Result<Duration, MigrateError>>;
+
+    // run the SQL of a down migration in a DDL transaction
+    // delete the row of the migration it reverts from the [_migrations] table on completion
+    // returns the time taking to run the migration SQL
+    fn revert<'e: 'm, 'm>(
+        &'e mut self,
+        migration: &'m Migration,
+    ) -> BoxFuture<'m, Result<Duration, MigrateError>>;
 }
@@ -1,11 +1,24 @@
 use std::borrow::Cow;
+use std::time::Duration;
+
+use crate::migrate::MigrationType;
 
 #[derive(Debug, Clone)]
 pub struct Migration {
-    pub(crate) version: i64,
-    pub(crate) description: Cow<'static, str>,
-    pub(crate) sql: Cow<'static, str>,
-    pub(crate) checksum: Cow<'static, [u8]>,
+    // NOTE: the fields are public so that `migrate!()` can construct a migration
+    //       in a `static`
+    #[doc(hidden)]
+    pub version: i64,
+    #[doc(hidden)]
+    pub description: Cow<'static, str>,
+    #[doc(hidden)]
+    pub migration_type: MigrationType,
+    #[doc(hidden)]
+    pub sql: Cow<'static, str>,
+    #[doc(hidden)]
+    pub checksum: Cow<'static, [u8]>,
+    #[doc(hidden)]
+    pub no_tx: bool,
 }
 
 impl Migration {
@@ -16,4 +29,87 @@ impl Migration {
     pub fn description(&self) -> &str {
         &*self.description
     }
+
+    pub fn migration_type(&self) -> MigrationType {
+        self.migration_type
+    }
+
+    pub fn sql(&self) -> &str {
+        &self.sql
+    }
+
+    /// Returns `true` if the script must not be run in a transaction.
+    ///
+    /// This is the case when the first line of the script that is not blank is
+    /// `-- no-transaction`; needed for statements such as `CREATE INDEX CONCURRENTLY`
+    /// in PostgreSQL, where the statements of the script are then run one at a time.
+    pub fn no_tx(&self) -> bool {
+        self.no_tx
+    }
+
+    // NOTE: public so that `migrate!()` reads the directive in the same way
+    #[doc(hidden)]
+    pub fn parse_no_tx(sql: &str) -> bool {
+        // the script may start with a byte order mark, and have `\r\n` line endings
+        sql.trim_start_matches('\u{feff}')
+            .lines()
+            .map(str::trim)
+            .find(|line| !line.is_empty())
+            .map_or(false, |line| line == "-- no-transaction")
+    }
+}
+
+/// A migration as recorded in the migrations table of a database.
+#[derive(Debug, Clone)]
+pub struct AppliedMigration {
+    pub version: i64,
+    pub description: String,
+
+    /// When the migration was applied, as formatted by the database.
+    pub installed_on: String,
+
+    /// `false` if the migration failed part way through, leaving the database dirty.
+    pub success: bool,
+
+    pub checksum: Vec<u8>,
+    pub execution_time: Duration,
+}
+
+// the columns of the migrations table, as selected by each driver:
+// `version, description, installed_on, success, checksum, execution_time`
+pub(crate) type AppliedMigrationRow = (i64, String, String, bool, Vec<u8>, i64);
+
+impl AppliedMigration {
+    pub(crate) fn from_row(row: AppliedMigrationRow) -> Self {
+        let (version, description, installed_on, success, checksum, execution_time) = row;
+
+        Self {
+            version,
+            description,
+            installed_on,
+            success,
+            checksum,
+            execution_time: Duration::from_nanos(execution_time as u64),
+        }
+    }
+}
+
+#[test]
+fn it_parses_the_no_transaction_directive() {
+    assert!(Migration::parse_no_tx(
+        "-- no-transaction\nCREATE INDEX ..."
+    ));
+    assert!(Migration::parse_no_tx("\u{feff}-- no-transaction\n"));
+    assert!(Migration::parse_no_tx(
+        "\r\n  -- no-transaction  \r\nCREATE INDEX ..."
+    ));
+    assert!(Migration::parse_no_tx("\n\n-- no-transaction"));
+
+    assert!(!Migration::parse_no_tx(""));
+    assert!(!Migration::parse_no_tx(
+        "CREATE INDEX ...;\n-- no-transaction\n"
+    ));
+    assert!(!Migration::parse_no_tx(
+        "-- no-transactions are fine here\n"
+    ));
 }
@@ -0,0 +1,73 @@
+/// The kind of a [`Migration`][crate::migrate::Migration], as determined by the name of
+/// its file.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum MigrationType {
+    /// A migration that cannot be reverted; `<VERSION>_<DESCRIPTION>.sql`.
+    Simple,
+
+    /// The script that applies a reversible migration; `<VERSION>_<DESCRIPTION>.up.sql`.
+    ///
+    /// Expected to be paired with a [`ReversibleDown`][Self::ReversibleDown] migration of
+    /// the same version.
+    ReversibleUp,
+
+    /// The script that reverts a reversible migration; `<VERSION>_<DESCRIPTION>.down.sql`.
+    ReversibleDown,
+}
+
+impl MigrationType {
+    /// Determines the type of a migration, and its description, from the name of its file
+    /// (excluding the `<VERSION>_` prefix).
+    ///
+    /// Returns `None` if the file is not a migration.
+    pub fn from_filename(filename: &str) -> Option<(Self, &str)> {
+        if let Some(description) = filename.strip_suffix(".up.sql") {
+            Some((MigrationType::ReversibleUp, description))
+        } else if let Some(description) = filename.strip_suffix(".down.sql") {
+            Some((MigrationType::ReversibleDown, description))
+        } else if let Some(description) = filename.strip_suffix(".sql") {
+            Some((MigrationType::Simple, description))
+        } else {
+            None
+        }
+    }
+
+    /// The verb used to describe running a migration of this type.
+    pub fn label(&self) -> &'static str {
+        match self {
+            MigrationType::Simple => "migrate",
+            MigrationType::ReversibleUp => "migrate",
+            MigrationType::ReversibleDown => "revert",
+        }
+    }
+
+    /// Returns `true` if running the migration moves the database forwards.
+    pub fn is_up_migration(&self) -> bool {
+        !self.is_down_migration()
+    }
+
+    /// Returns `true` if running the migration reverts an earlier migration.
+    pub fn is_down_migration(&self) -> bool {
+        matches!(self, MigrationType::ReversibleDown)
+    }
+}
+
+#[test]
+fn test_migration_type_from_filename() {
+    assert_eq!(
+        MigrationType::from_filename("create_users.sql"),
+        Some((MigrationType::Simple, "create_users"))
+    );
+
+    assert_eq!(
+        MigrationType::from_filename("create_users.up.sql"),
+        Some((MigrationType::ReversibleUp, "create_users"))
+    );
+
+    assert_eq!(
+        MigrationType::from_filename("create_users.down.sql"),
+        Some((MigrationType::ReversibleDown, "create_users"))
+    );
+
+    assert_eq!(MigrationType::from_filename("README.md"), None);
+}
@@ -1,16 +1,22 @@
 use crate::acquire::Acquire;
 use crate::migrate::{Migrate, MigrateError, Migration, MigrationSource};
+use std::borrow::Cow;
 use std::ops::Deref;
 use std::slice;
 
 #[derive(Debug)]
 pub struct Migrator {
-    migrations: Vec<Migration>,
+    // NOTE: public so that `migrate!()` can construct a migrator in a `static`
+    #[doc(hidden)]
+    pub migrations: Cow<'static, [Migration]>,
 }
 
 impl Migrator {
     /// Creates a new instance with the given source.
     ///
+    /// The migrations are read when this is called. To embed the migrations in the binary
+    /// instead, see the `sqlx::migrate!()` macro.
+    ///
     /// # Examples
     ///
     /// ```rust,no_run
@@ -31,7 +37,7 @@ impl Migrator {
         S: MigrationSource<'s>,
     {
         Ok(Self {
-            migrations: source.resolve().await.map_err(MigrateError::Source)?,
+            migrations: Cow::Owned(source.resolve().await.map_err(MigrateError::Source)?),
         })
     }
 
@@ -40,6 +46,36 @@ impl Migrator {
         self.migrations.iter()
     }
 
+    /// Get the migrations that [`run`][Self::run] would apply, in the order in which they
+    /// would be applied.
+    ///
+    /// Nothing is executed: the database is not locked and the migrations table is not
+    /// created; if it does not exist, every migration is pending. Like `run`, this returns
+    /// [`MigrateError::Dirty`] if an earlier migration failed part way through.
+    pub async fn pending<'a, A>(&self, migrator: A) -> Result<Vec<&Migration>, MigrateError>
+    where
+        A: Acquire<'a>,
+        <A::Connection as Deref>::Target: Migrate,
+    {
+        let mut conn = migrator.acquire().await?;
+
+        // a database without the migrations table has no migrations applied
+        let (version, dirty) = if conn.migrations_table_exists().await? {
+            conn.version().await?.unwrap_or((0, false))
+        } else {
+            (0, false)
+        };
+
+        if dirty {
+            return Err(MigrateError::Dirty(version));
+        }
+
+        Ok(self
+            .iter()
+            .filter(|m| m.migration_type().is_up_migration() && m.version() > version)
+            .collect())
+    }
+
     /// Run any pending migrations against the database; and, validate previously applied migrations
     /// against the current migration source to detect accidental changes in previously-applied migrations.
     pub async fn run<'a, A>(&self, migrator: A) -> Result<(), MigrateError>
@@ -63,6 +99,10 @@ impl Migrator {
         }
 
         for migration in self.iter() {
+            if migration.migration_type().is_down_migration() {
+                continue;
+            }
+
             if migration.version() > version {
                 conn.apply(migration).await?;
             } else {
@@ -76,4 +116,64 @@ impl Migrator {
 
         Ok(())
     }
+
+    /// Revert every applied migration with a version greater than `target`, newest first.
+    ///
+    /// Each reverted migration must have a down migration; if any of them does not, nothing
+    /// is reverted and [`MigrateError::Irreversible`] is returned; if any of them is missing
+    /// from the resolved migrations, [`MigrateError::VersionMissing`] is returned instead.
+    /// Pass a `target` of `0` to revert every migration.
+    pub async fn undo<'a, A>(&self, migrator: A, target: i64) -> Result<(), MigrateError>
+    where
+        A: Acquire<'a>,
+        <A::Connection as Deref>::Target: Migrate,
+    {
+        let mut conn = migrator.acquire().await?;
+
+        // lock the database for exclusive access by the migrator
+        conn.lock().await?;
+
+        // creates [_migrations] table only if needed
+        // eventually this will likely migrate previous versions of the table
+        conn.ensure_migrations_table().await?;
+
+        let (version, dirty) = conn.version().await?.unwrap_or((0, false));
+
+        if dirty {
+            return Err(MigrateError::Dirty(version));
+        }
+
+        // only the migrations recorded as applied are reverted; the resolved migrations may
+        // include some that were added later, with a lower version, and never applied
+        let mut applied = conn.list_applied_migrations().await?;
+        applied.sort_by_key(|m| std::cmp::Reverse(m.version));
+
+        let mut reverts = Vec::new();
+
+        for applied in applied.iter().filter(|m| m.version > target) {
+            if !self.iter().any(|m| m.version() == applied.version) {
+                return Err(MigrateError::VersionMissing(applied.version));
+            }
+
+            reverts.push(
+                self.find_down(applied.version)
+                    .ok_or(MigrateError::Irreversible(applied.version))?,
+            );
+        }
+
+        for migration in reverts {
+            conn.revert(migration).await?;
+        }
+
+        // unlock the migrator to allow other migrators to run
+        conn.unlock().await?;
+
+        Ok(())
+    }
+
+    // the down migration that reverts the migration of the given version
+    fn find_down(&self, version: i64) -> Option<&Migration> {
+        self.iter()
+            .find(|m| m.version() == version && m.migration_type().is_down_migration())
+    }
 }
@@ -1,11 +1,14 @@
 mod error;
 mod migrate;
 mod migration;
+mod migration_type;
 mod migrator;
 mod source;
 
 pub use error::MigrateError;
 pub use migrate::{Migrate, MigrateDatabase};
-pub use migration::Migration;
+pub(crate) use migration::AppliedMigrationRow;
+pub use migration::{AppliedMigration, Migration};
+pub use migration_type::MigrationType;
 pub use migrator::Migrator;
 pub use source::MigrationSource;
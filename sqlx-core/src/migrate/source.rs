@@ -1,5 +1,5 @@
 use crate::error::BoxDynError;
-use crate::migrate::Migration;
+use crate::migrate::{Migration, MigrationType};
 use futures_core::future::BoxFuture;
 use futures_util::TryStreamExt;
 use sha2::{Digest, Sha384};
@@ -29,33 +29,41 @@ impl<'s> MigrationSource<'s> for &'s Path {
 
                 let parts = file_name.splitn(2, '_').collect::<Vec<_>>();
 
-                if parts.len() != 2 || !parts[1].ends_with(".sql") {
-                    // not of the format: <VERSION>_<DESCRIPTION>.sql; ignore
-                    continue;
-                }
+                let (migration_type, description) = match parts
+                    .get(1)
+                    .and_then(|name| MigrationType::from_filename(name))
+                {
+                    Some(it) => it,
+
+                    // not of the format: <VERSION>_<DESCRIPTION>[.up|.down].sql; ignore
+                    None => continue,
+                };
 
                 let version: i64 = parts[0].parse()?;
 
-                // remove the `.sql` and replace `_` with ` `
-                let description = parts[1]
-                    .trim_end_matches(".sql")
-                    .replace('_', " ")
-                    .to_owned();
+                // replace `_` with ` `
+                let description = description.replace('_', " ");
 
                 let sql = fs::read_to_string(&entry.path()).await?;
 
                 let checksum = Vec::from(Sha384::digest(sql.as_bytes()).as_slice());
 
+                // a migration may opt out of running in a transaction with a header line
+                let no_tx = Migration::parse_no_tx(&sql);
+
                 migrations.push(Migration {
                     version,
                     description: Cow::Owned(description),
+                    migration_type,
                     sql: Cow::Owned(sql),
                     checksum: Cow::Owned(checksum),
+                    no_tx,
                 })
             }
 
-            // ensure that we are sorted by `VERSION ASC`
-            migrations.sort_by_key(|m| m.version);
+            // ensure that we are sorted by `VERSION ASC`; with the up migration of each
+            // reversible pair before its down migration
+            migrations.sort_by_key(|m| (m.version, m.migration_type.is_down_migration()));
 
             Ok(migrations)
         })
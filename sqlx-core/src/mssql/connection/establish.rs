@@ -1,11 +1,12 @@
 use crate::common::StatementCache;
 use crate::error::Error;
 use crate::io::Decode;
-use crate::mssql::connection::stream::MssqlStream;
+use crate::mssql::connection::stream::{MssqlStream, PACKET_SIZE};
+use crate::mssql::connection::tls::{self, Encryption};
 use crate::mssql::protocol::login::Login7;
 use crate::mssql::protocol::message::Message;
 use crate::mssql::protocol::packet::PacketType;
-use crate::mssql::protocol::pre_login::{Encrypt, PreLogin, Version};
+use crate::mssql::protocol::pre_login::{PreLogin, Version};
 use crate::mssql::{MssqlConnectOptions, MssqlConnection};
 
 impl MssqlConnection {
@@ -15,14 +16,13 @@ impl MssqlConnection {
         // Send PRELOGIN to set up the context for login. The server should immediately
         // respond with a PRELOGIN message of its own.
 
-        // TODO: Encryption
         // TODO: Send the version of SQLx over
 
         stream.write_packet(
             PacketType::PreLogin,
             PreLogin {
                 version: Version::default(),
-                encryption: Encrypt::NOT_SUPPORTED,
+                encryption: tls::requested(options),
 
                 ..Default::default()
             },
@@ -31,7 +31,16 @@ impl MssqlConnection {
         stream.flush().await?;
 
         let (_, packet) = stream.recv_packet().await?;
-        let _ = PreLogin::decode(packet)?;
+        let pre_login = PreLogin::decode(packet)?;
+
+        // if either side wants encryption, we now perform the TLS handshake; the password in
+        // LOGIN7 is encrypted even when the rest of the connection will not be
+
+        let encryption = tls::negotiate(options, pre_login.encryption)?;
+
+        if encryption != Encryption::None {
+            tls::upgrade(&mut stream, options).await?;
+        }
 
         // LOGIN7 defines the authentication rules for use between client and server
 
@@ -42,7 +51,7 @@ impl MssqlConnection {
                 version: 0x74000004, // SQL Server 2012 - SQL Server 2019
                 client_program_version: 0,
                 client_pid: 0,
-                packet_size: 4096,
+                packet_size: PACKET_SIZE as u32,
                 hostname: "",
                 username: &options.username,
                 password: options.password.as_deref().unwrap_or_default(),
@@ -57,6 +66,10 @@ impl MssqlConnection {
 
         stream.flush().await?;
 
+        if encryption == Encryption::LoginOnly {
+            tls::downgrade(&mut stream)?;
+        }
+
         loop {
             // NOTE: we should receive an [Error] message if something goes wrong, otherwise,
             //       all messages are mostly informational (ENVCHANGE, INFO, LOGINACK)
@@ -82,6 +95,7 @@ impl MssqlConnection {
         Ok(Self {
             stream,
             cache_statement: StatementCache::new(1024),
+            log_settings: options.log_settings.clone(),
         })
     }
 }
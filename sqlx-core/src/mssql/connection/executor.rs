@@ -1,6 +1,7 @@
 use crate::describe::Describe;
 use crate::error::Error;
 use crate::executor::{Execute, Executor};
+use crate::logging::QueryLogger;
 use crate::mssql::connection::prepare::prepare;
 use crate::mssql::protocol::col_meta_data::Flags;
 use crate::mssql::protocol::done::Status;
@@ -79,6 +80,18 @@ impl<'c> Executor<'c> for &'c mut MssqlConnection {
         let arguments = query.take_arguments();
 
         Box::pin(try_stream! {
+            let mut logger = QueryLogger::new(sql, self.log_settings.clone());
+
+            if let Some(arguments) = &arguments {
+                // declarations are of the form `@p1 int,@p2 nvarchar(10),...`
+                logger.parameters(
+                    arguments
+                        .declarations
+                        .split(",@")
+                        .filter_map(|declaration| declaration.splitn(2, ' ').nth(1)),
+                );
+            }
+
             self.run(sql, arguments).await?;
 
             loop {
@@ -86,6 +99,8 @@ impl<'c> Executor<'c> for &'c mut MssqlConnection {
 
                 match message {
                     Message::Row(row) => {
+                        logger.increment_rows();
+
                         let columns = Arc::clone(&self.stream.columns);
                         let column_names = Arc::clone(&self.stream.column_names);
 
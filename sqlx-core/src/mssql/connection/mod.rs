@@ -1,5 +1,5 @@
 use crate::common::StatementCache;
-use crate::connection::Connection;
+use crate::connection::{Connection, LogSettings};
 use crate::error::Error;
 use crate::executor::Executor;
 use crate::mssql::connection::stream::MssqlStream;
@@ -16,10 +16,12 @@ mod establish;
 mod executor;
 mod prepare;
 mod stream;
+mod tls;
 
 pub struct MssqlConnection {
     pub(crate) stream: MssqlStream,
     pub(crate) cache_statement: StatementCache<Arc<MssqlStatementMetadata>>,
+    log_settings: LogSettings,
 }
 
 impl Debug for MssqlConnection {
@@ -6,6 +6,7 @@ use sqlx_rt::TcpStream;
 use crate::error::Error;
 use crate::ext::ustr::UStr;
 use crate::io::{BufStream, Encode};
+use crate::mssql::connection::tls::TlsPreloginWrapper;
 use crate::mssql::protocol::col_meta_data::ColMetaData;
 use crate::mssql::protocol::done::{Done, Status as DoneStatus};
 use crate::mssql::protocol::env_change::EnvChange;
@@ -23,8 +24,11 @@ use crate::net::MaybeTlsStream;
 use hashbrown::HashMap;
 use std::sync::Arc;
 
+// the packet size requested at login; no packet we send may be larger than this
+pub(crate) const PACKET_SIZE: usize = 4096;
+
 pub(crate) struct MssqlStream {
-    inner: BufStream<MaybeTlsStream<TcpStream>>,
+    inner: BufStream<MaybeTlsStream<TlsPreloginWrapper<TcpStream>>>,
 
     // how many Done (or Error) we are currently waiting for
     pub(crate) pending_done_count: usize,
@@ -45,9 +49,9 @@ pub(crate) struct MssqlStream {
 
 impl MssqlStream {
     pub(super) async fn connect(options: &MssqlConnectOptions) -> Result<Self, Error> {
-        let inner = BufStream::new(MaybeTlsStream::Raw(
+        let inner = BufStream::new(MaybeTlsStream::Raw(TlsPreloginWrapper::new(
             TcpStream::connect((&*options.host, options.port)).await?,
-        ));
+        )));
 
         Ok(Self {
             inner,
@@ -60,33 +64,52 @@ impl MssqlStream {
         })
     }
 
-    // writes the packet out to the write buffer
-    // will (eventually) handle packet chunking
+    // writes the message out to the write buffer, split into as many packets as needed
     pub(crate) fn write_packet<'en, T: Encode<'en>>(&mut self, ty: PacketType, payload: T) {
-        // TODO: Support packet chunking for large packet sizes
-        //       We likely need to double-buffer the writes so we know to chunk
-
-        // write out the packet header, leaving room for setting the packet length later
-
-        let mut len_offset = 0;
-
-        self.inner.write_with(
-            PacketHeader {
-                r#type: ty,
-                status: Status::END_OF_MESSAGE,
-                length: 0,
-                server_process_id: 0,
-                packet_id: 1,
-            },
-            &mut len_offset,
-        );
-
-        // write out the payload
-        self.inner.write(payload);
-
-        // overwrite the packet length now that we know it
-        let len = self.inner.wbuf.len();
-        self.inner.wbuf[len_offset..(len_offset + 2)].copy_from_slice(&(len as u16).to_be_bytes());
+        let mut buf = Vec::new();
+        payload.encode(&mut buf);
+
+        // an empty message is still sent as a single packet
+        let mut chunks = buf.chunks(PACKET_SIZE - 8).peekable();
+        let mut packet_id: u8 = 1;
+
+        loop {
+            let chunk = chunks.next().unwrap_or_default();
+            let is_last = chunks.peek().is_none();
+
+            // write out the packet header, leaving room for setting the packet length later
+
+            let mut len_offset = 0;
+            let start = self.inner.wbuf.len();
+
+            self.inner.write_with(
+                PacketHeader {
+                    r#type: ty,
+                    status: if is_last {
+                        Status::END_OF_MESSAGE
+                    } else {
+                        Status::NORMAL
+                    },
+                    length: 0,
+                    server_process_id: 0,
+                    packet_id,
+                },
+                &mut len_offset,
+            );
+
+            self.inner.wbuf.extend_from_slice(chunk);
+
+            // overwrite the packet length now that we know it
+            let len = self.inner.wbuf.len() - start;
+            self.inner.wbuf[len_offset..(len_offset + 2)]
+                .copy_from_slice(&(len as u16).to_be_bytes());
+
+            if is_last {
+                break;
+            }
+
+            packet_id = packet_id.wrapping_add(1);
+        }
     }
 
     // receive the next packet from the database
@@ -222,7 +245,7 @@ impl MssqlStream {
 }
 
 impl Deref for MssqlStream {
-    type Target = BufStream<MaybeTlsStream<TcpStream>>;
+    type Target = BufStream<MaybeTlsStream<TlsPreloginWrapper<TcpStream>>>;
 
     fn deref(&self) -> &Self::Target {
         &self.inner
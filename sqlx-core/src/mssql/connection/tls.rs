@@ -0,0 +1,304 @@
+use std::io;
+use std::ops::{Deref, DerefMut};
+use std::pin::Pin;
+use std::task::{Context, Poll};
+
+use futures_util::ready;
+use sqlx_rt::{
+    fs,
+    native_tls::{Certificate, TlsConnector},
+    AsyncRead, AsyncWrite,
+};
+
+use crate::error::Error;
+use crate::io::Encode;
+use crate::mssql::connection::stream::MssqlStream;
+use crate::mssql::protocol::packet::{PacketHeader, PacketType, Status};
+use crate::mssql::protocol::pre_login::Encrypt;
+use crate::mssql::MssqlConnectOptions;
+use crate::net::MaybeTlsStream;
+
+/// What is encrypted, as agreed on by the client and server in PRELOGIN.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub(super) enum Encryption {
+    // nothing, not even the password
+    None,
+
+    // only the LOGIN7 message
+    LoginOnly,
+
+    // everything from LOGIN7 on
+    Full,
+}
+
+// the encryption we ask for in PRELOGIN
+pub(super) fn requested(options: &MssqlConnectOptions) -> Encrypt {
+    if options.encrypt {
+        Encrypt::ON
+    } else {
+        // we can still encrypt; the server decides if it is only the login
+        Encrypt::OFF
+    }
+}
+
+// https://docs.microsoft.com/en-us/openspecs/windows_protocols/ms-tds/60f56408-0188-4cd5-8b90-25c6f2423868
+pub(super) fn negotiate(
+    options: &MssqlConnectOptions,
+    server: Encrypt,
+) -> Result<Encryption, Error> {
+    let server = server & !Encrypt::CLIENT_CERT;
+
+    if server == Encrypt::NOT_SUPPORTED {
+        return if options.encrypt {
+            Err(Error::Tls("server does not support encryption".into()))
+        } else {
+            Ok(Encryption::None)
+        };
+    }
+
+    if options.encrypt || server == Encrypt::ON || server == Encrypt::REQUIRED {
+        Ok(Encryption::Full)
+    } else {
+        Ok(Encryption::LoginOnly)
+    }
+}
+
+pub(super) async fn upgrade(
+    stream: &mut MssqlStream,
+    options: &MssqlConnectOptions,
+) -> Result<(), Error> {
+    // the certificate is only checked when encryption was asked for, as SQL Server falls
+    // back to a self-signed certificate when none is configured
+    let accept_invalid_certs = options.trust_server_certificate || !options.encrypt;
+
+    let mut builder = TlsConnector::builder();
+    builder
+        .danger_accept_invalid_certs(accept_invalid_certs)
+        .danger_accept_invalid_hostnames(accept_invalid_certs);
+
+    if !accept_invalid_certs {
+        if let Some(ca) = &options.ssl_root_cert {
+            let data = fs::read(ca).await?;
+            let cert = Certificate::from_pem(&data).map_err(Error::tls)?;
+
+            builder.add_root_certificate(cert);
+        }
+    }
+
+    #[cfg(not(feature = "runtime-async-std-native-tls"))]
+    let connector = builder.build().map_err(Error::tls)?;
+
+    #[cfg(feature = "runtime-async-std-native-tls")]
+    let connector = builder;
+
+    // the TLS handshake is carried in the payload of PRELOGIN packets
+    stream.start_handshake();
+    stream.upgrade(&options.host, connector.into()).await?;
+    stream.finish_handshake();
+
+    Ok(())
+}
+
+// after the LOGIN7 message is sent with login-only encryption, the server continues
+// without TLS and so do we
+pub(super) fn downgrade(stream: &mut MssqlStream) -> Result<(), Error> {
+    let raw = stream
+        .take_stream()
+        .ok_or_else(|| Error::Io(io::ErrorKind::ConnectionAborted.into()))?;
+
+    ***stream = MaybeTlsStream::Raw(TlsPreloginWrapper::new(raw));
+
+    Ok(())
+}
+
+/// A stream that, while a TLS handshake is pending, wraps what is written in and unwraps what
+/// is read from TDS PRELOGIN packets. Otherwise it passes reads and writes straight through.
+pub(crate) struct TlsPreloginWrapper<S> {
+    // only taken to drop TLS after a login-only encryption
+    stream: Option<S>,
+
+    pending_handshake: bool,
+
+    // the header of the packet being read and how much of it has been read
+    header_buf: [u8; 8],
+    header_pos: usize,
+
+    // how much of the payload of the packet being read is left
+    read_remaining: usize,
+
+    // the packet being written, starting with room for its header
+    wr_buf: Vec<u8>,
+    header_written: bool,
+}
+
+impl<S> TlsPreloginWrapper<S> {
+    pub(crate) fn new(stream: S) -> Self {
+        Self {
+            stream: Some(stream),
+            pending_handshake: false,
+            header_buf: [0; 8],
+            header_pos: 0,
+            read_remaining: 0,
+            wr_buf: Vec::new(),
+            header_written: false,
+        }
+    }
+
+    pub(crate) fn start_handshake(&mut self) {
+        self.pending_handshake = true;
+    }
+
+    pub(crate) fn finish_handshake(&mut self) {
+        self.pending_handshake = false;
+    }
+
+    pub(crate) fn take_stream(&mut self) -> Option<S> {
+        self.stream.take()
+    }
+}
+
+fn get_stream<S: Unpin>(stream: &mut Option<S>) -> io::Result<Pin<&mut S>> {
+    stream
+        .as_mut()
+        .map(Pin::new)
+        .ok_or_else(|| io::ErrorKind::ConnectionAborted.into())
+}
+
+impl<S> AsyncRead for TlsPreloginWrapper<S>
+where
+    S: AsyncRead + AsyncWrite + Unpin,
+{
+    fn poll_read(
+        self: Pin<&mut Self>,
+        cx: &mut Context<'_>,
+        buf: &mut [u8],
+    ) -> Poll<io::Result<usize>> {
+        let this = self.get_mut();
+
+        if !this.pending_handshake {
+            return get_stream(&mut this.stream)?.poll_read(cx, buf);
+        }
+
+        while this.read_remaining == 0 {
+            while this.header_pos < this.header_buf.len() {
+                let n = ready!(get_stream(&mut this.stream)?
+                    .poll_read(cx, &mut this.header_buf[this.header_pos..]))?;
+
+                if n == 0 {
+                    return Poll::Ready(Ok(0));
+                }
+
+                this.header_pos += n;
+            }
+
+            // the length of the packet includes its header
+            let len = u16::from_be_bytes([this.header_buf[2], this.header_buf[3]]) as usize;
+
+            this.read_remaining = len.checked_sub(this.header_buf.len()).ok_or_else(|| {
+                io::Error::new(
+                    io::ErrorKind::InvalidData,
+                    "received a PRELOGIN packet with an invalid length",
+                )
+            })?;
+
+            this.header_pos = 0;
+        }
+
+        let max = buf.len().min(this.read_remaining);
+        let n = ready!(get_stream(&mut this.stream)?.poll_read(cx, &mut buf[..max]))?;
+
+        this.read_remaining -= n;
+
+        Poll::Ready(Ok(n))
+    }
+}
+
+impl<S> AsyncWrite for TlsPreloginWrapper<S>
+where
+    S: AsyncRead + AsyncWrite + Unpin,
+{
+    fn poll_write(
+        self: Pin<&mut Self>,
+        cx: &mut Context<'_>,
+        buf: &[u8],
+    ) -> Poll<io::Result<usize>> {
+        let this = self.get_mut();
+
+        if !this.pending_handshake {
+            return get_stream(&mut this.stream)?.poll_write(cx, buf);
+        }
+
+        // everything written until the next flush is sent as one packet
+        if this.wr_buf.is_empty() {
+            this.wr_buf.resize(8, 0);
+        }
+
+        this.wr_buf.extend_from_slice(buf);
+
+        Poll::Ready(Ok(buf.len()))
+    }
+
+    fn poll_flush(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<io::Result<()>> {
+        let this = self.get_mut();
+
+        if this.pending_handshake && !this.wr_buf.is_empty() {
+            if !this.header_written {
+                let mut header = Vec::with_capacity(8);
+
+                PacketHeader {
+                    r#type: PacketType::PreLogin,
+                    status: Status::END_OF_MESSAGE,
+                    length: this.wr_buf.len() as u16,
+                    server_process_id: 0,
+                    packet_id: 1,
+                }
+                .encode_with(&mut header, &mut 0);
+
+                this.wr_buf[..8].copy_from_slice(&header);
+                this.header_written = true;
+            }
+
+            while !this.wr_buf.is_empty() {
+                let n = ready!(get_stream(&mut this.stream)?.poll_write(cx, &this.wr_buf))?;
+
+                if n == 0 {
+                    return Poll::Ready(Err(io::ErrorKind::WriteZero.into()));
+                }
+
+                this.wr_buf.drain(..n);
+            }
+
+            this.header_written = false;
+        }
+
+        get_stream(&mut this.stream)?.poll_flush(cx)
+    }
+
+    #[cfg(any(
+        feature = "runtime-actix-native-tls",
+        feature = "runtime-tokio-native-tls"
+    ))]
+    fn poll_shutdown(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<io::Result<()>> {
+        get_stream(&mut self.get_mut().stream)?.poll_shutdown(cx)
+    }
+
+    #[cfg(feature = "runtime-async-std-native-tls")]
+    fn poll_close(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<io::Result<()>> {
+        get_stream(&mut self.get_mut().stream)?.poll_close(cx)
+    }
+}
+
+impl<S> Deref for TlsPreloginWrapper<S> {
+    type Target = S;
+
+    fn deref(&self) -> &Self::Target {
+        // the stream is only taken while it is being replaced
+        self.stream.as_ref().expect("stream was taken")
+    }
+}
+
+impl<S> DerefMut for TlsPreloginWrapper<S> {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        self.stream.as_mut().expect("stream was taken")
+    }
+}
@@ -1,3 +1,7 @@
+use std::path::{Path, PathBuf};
+
+use crate::connection::LogSettings;
+
 mod connect;
 mod parse;
 
@@ -8,6 +12,10 @@ pub struct MssqlConnectOptions {
     pub(crate) username: String,
     pub(crate) database: String,
     pub(crate) password: Option<String>,
+    pub(crate) encrypt: bool,
+    pub(crate) trust_server_certificate: bool,
+    pub(crate) ssl_root_cert: Option<PathBuf>,
+    pub(crate) log_settings: LogSettings,
 }
 
 impl Default for MssqlConnectOptions {
@@ -24,6 +32,10 @@ impl MssqlConnectOptions {
             database: String::from("master"),
             username: String::from("sa"),
             password: None,
+            encrypt: false,
+            trust_server_certificate: false,
+            ssl_root_cert: None,
+            log_settings: Default::default(),
         }
     }
 
@@ -51,4 +63,47 @@ impl MssqlConnectOptions {
         self.database = database.to_owned();
         self
     }
+
+    /// Sets whether the connection must be encrypted.
+    ///
+    /// By default, only the login is encrypted, unless the server requires encryption
+    /// for the whole connection. If this is set and the server does not support encryption,
+    /// connecting will fail.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use sqlx_core::mssql::MssqlConnectOptions;
+    /// let options = MssqlConnectOptions::new()
+    ///     .encrypt(true);
+    /// ```
+    pub fn encrypt(mut self, encrypt: bool) -> Self {
+        self.encrypt = encrypt;
+        self
+    }
+
+    /// Sets whether the server's certificate is trusted without being verified.
+    ///
+    /// The certificate is only verified when [`encrypt`](#method.encrypt) is set.
+    pub fn trust_server_certificate(mut self, trust: bool) -> Self {
+        self.trust_server_certificate = trust;
+        self
+    }
+
+    /// Sets the name of a file containing SSL certificate authority (CA) certificate(s).
+    /// If the file exists, the server's certificate will be verified to be signed by
+    /// one of these authorities.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use sqlx_core::mssql::MssqlConnectOptions;
+    /// let options = MssqlConnectOptions::new()
+    ///     .encrypt(true)
+    ///     .ssl_root_cert("./ca-certificate.crt");
+    /// ```
+    pub fn ssl_root_cert(mut self, cert: impl AsRef<Path>) -> Self {
+        self.ssl_root_cert = Some(cert.as_ref().to_path_buf());
+        self
+    }
 }
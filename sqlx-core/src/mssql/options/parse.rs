@@ -32,6 +32,39 @@ impl FromStr for MssqlConnectOptions {
             options = options.database(path);
         }
 
+        for (key, value) in url.query_pairs().into_iter() {
+            match &*key {
+                "encrypt" => {
+                    options = options.encrypt(value.parse().map_err(Error::config)?);
+                }
+
+                "trust-server-certificate" | "trustServerCertificate" => {
+                    options =
+                        options.trust_server_certificate(value.parse().map_err(Error::config)?);
+                }
+
+                "sslrootcert" | "ssl-root-cert" | "ssl-ca" => {
+                    options = options.ssl_root_cert(&*value);
+                }
+
+                _ => {}
+            }
+        }
+
         Ok(options)
     }
 }
+
+#[test]
+fn it_parses_encryption_from_the_query() {
+    let uri = "mssql://sa@localhost/master?encrypt=true&trust-server-certificate=true";
+    let options = MssqlConnectOptions::from_str(uri).unwrap();
+
+    assert!(options.encrypt);
+    assert!(options.trust_server_certificate);
+    assert!(options.ssl_root_cert.is_none());
+
+    let uri = "mssql://sa@localhost/master?encrypt=yes";
+
+    assert!(MssqlConnectOptions::from_str(uri).is_err());
+}
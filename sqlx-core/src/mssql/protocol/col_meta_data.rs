@@ -4,7 +4,7 @@ use bytes::{Buf, Bytes};
 use crate::error::Error;
 use crate::ext::ustr::UStr;
 use crate::mssql::io::MssqlBufExt;
-use crate::mssql::protocol::type_info::TypeInfo;
+use crate::mssql::protocol::type_info::{DataType, TypeInfo};
 use crate::mssql::MssqlColumn;
 use hashbrown::HashMap;
 
@@ -118,6 +118,18 @@ impl ColumnData {
         let type_info = TypeInfo::get(buf)?;
 
         // TODO: table_name
+        //       it is only sent for TEXT, NTEXT and IMAGE columns; skip over it for now
+        if matches!(
+            type_info.ty,
+            DataType::Text | DataType::NText | DataType::Image
+        ) {
+            let num_parts = buf.get_u8();
+
+            for _ in 0..num_parts {
+                let _ = buf.get_us_varchar()?;
+            }
+        }
+
         // TODO: crypto_meta_data
 
         let name = buf.get_b_varchar()?;
@@ -243,7 +243,10 @@ bitflags! {
     /// wire encryption to be used.
     #[derive(Default)]
     pub(crate) struct Encrypt: u8 {
-        /// Encryption is available but on.
+        /// Encryption is available but off.
+        const OFF = 0x00;
+
+        /// Encryption is available and on.
         const ON = 0x01;
 
         /// Encryption is not available.
@@ -1,11 +1,22 @@
 use bitflags::bitflags;
-use bytes::{Buf, Bytes};
+use bytes::{Buf, Bytes, BytesMut};
 use encoding_rs::Encoding;
 
 use crate::encode::{Encode, IsNull};
 use crate::error::Error;
+use crate::mssql::io::MssqlBufExt;
 use crate::mssql::Mssql;
 
+// the size given to variable-length types declared with `(max)`; their values are sent as
+// partially length-prefixed (PLP) data
+pub(crate) const MAX_SIZE: u32 = 0xFFFF;
+
+// the largest size of a variable-length type that can be sent without `(max)`
+pub(crate) const MAX_FIXED_SIZE: u32 = 8000;
+
+// https://docs.microsoft.com/en-us/openspecs/windows_protocols/ms-tds/3f983fde-0509-485a-8c40-a9fa6679a828
+const PLP_NULL: u64 = 0xFFFF_FFFF_FFFF_FFFF;
+
 bitflags! {
     #[cfg_attr(feature = "offline", derive(serde::Serialize, serde::Deserialize))]
     pub(crate) struct CollationFlags: u8 {
@@ -110,9 +121,15 @@ impl TypeInfo {
 
     pub(crate) fn encoding(&self) -> Result<&'static Encoding, Error> {
         match self.ty {
-            DataType::NChar | DataType::NVarChar => Ok(encoding_rs::UTF_16LE),
+            DataType::NChar | DataType::NVarChar | DataType::NText | DataType::Xml => {
+                Ok(encoding_rs::UTF_16LE)
+            }
 
-            DataType::VarChar | DataType::Char | DataType::BigChar | DataType::BigVarChar => {
+            DataType::VarChar
+            | DataType::Char
+            | DataType::BigChar
+            | DataType::BigVarChar
+            | DataType::Text => {
                 // unwrap: impossible to unwrap here, collation will be set
                 Ok(match self.collation.unwrap().locale {
                     // This is the Western encoding for Windows. It is an extension of ISO-8859-1,
@@ -228,6 +245,32 @@ impl TypeInfo {
                 }
             }
 
+            DataType::Text | DataType::NText => {
+                let size = buf.get_u32_le();
+                let collation = Collation::get(buf);
+
+                Self {
+                    ty,
+                    size,
+                    collation: Some(collation),
+                    scale: 0,
+                    precision: 0,
+                }
+            }
+
+            DataType::Image => Self::new(ty, buf.get_u32_le()),
+
+            DataType::Xml => {
+                // [SCHEMA_PRESENT] the schema collection is informational only
+                if buf.get_u8() != 0 {
+                    let _db_name = buf.get_b_varchar()?;
+                    let _owning_schema = buf.get_b_varchar()?;
+                    let _xml_schema_collection = buf.get_us_varchar()?;
+                }
+
+                Self::new(ty, MAX_SIZE)
+            }
+
             _ => {
                 return Err(err_protocol!("unsupported data type {:?}", ty));
             }
@@ -293,6 +336,11 @@ impl TypeInfo {
                 }
             }
 
+            DataType::Xml => {
+                // [SCHEMA_PRESENT]
+                buf.push(0);
+            }
+
             _ => {
                 unimplemented!("unsupported data type {:?}", self.ty);
             }
@@ -303,7 +351,24 @@ impl TypeInfo {
         matches!(self.ty, DataType::Null)
     }
 
+    // `(max)` types and XML send their values in chunks, as partially length-prefixed data
+    pub(crate) fn is_plp(&self) -> bool {
+        match self.ty {
+            DataType::Xml => true,
+
+            DataType::BigVarBinary | DataType::BigVarChar | DataType::NVarChar => {
+                self.size == MAX_SIZE
+            }
+
+            _ => false,
+        }
+    }
+
     pub(crate) fn get_value(&self, buf: &mut Bytes) -> Option<Bytes> {
+        if self.is_plp() {
+            return get_plp_value(buf);
+        }
+
         match self.ty {
             DataType::Null
             | DataType::TinyInt
@@ -368,7 +433,22 @@ impl TypeInfo {
                 }
             }
 
-            DataType::Text | DataType::Image | DataType::NText | DataType::Variant => {
+            DataType::Text | DataType::Image | DataType::NText => {
+                // a NULL value has no text pointer
+                let text_ptr_len = buf.get_u8();
+
+                if text_ptr_len == 0 {
+                    None
+                } else {
+                    // skip the text pointer and its 8-byte timestamp
+                    buf.advance(text_ptr_len as usize + 8);
+
+                    let size = buf.get_u32_le();
+                    Some(buf.split_to(size as usize))
+                }
+            }
+
+            DataType::Variant => {
                 let size = buf.get_u32_le();
 
                 if size == 0xFFFF_FFFF {
@@ -381,6 +461,10 @@ impl TypeInfo {
     }
 
     pub(crate) fn put_value<'q, T: Encode<'q, Mssql>>(&self, buf: &mut Vec<u8>, value: T) {
+        if self.is_plp() {
+            return self.put_plp_value(buf, value);
+        }
+
         match self.ty {
             DataType::Null
             | DataType::TinyInt
@@ -482,6 +566,34 @@ impl TypeInfo {
         buf[offset..(offset + 4)].copy_from_slice(&size.to_le_bytes());
     }
 
+    pub(crate) fn put_plp_value<'q, T: Encode<'q, Mssql>>(&self, buf: &mut Vec<u8>, value: T) {
+        // the total length, followed by the value in one chunk
+        let offset = buf.len();
+        buf.extend(&0_u64.to_le_bytes());
+        buf.extend(&0_u32.to_le_bytes());
+
+        if let IsNull::Yes = value.encode(buf) {
+            buf.truncate(offset);
+            buf.extend(&PLP_NULL.to_le_bytes());
+
+            return;
+        }
+
+        let size = buf.len() - offset - 12;
+
+        buf[offset..(offset + 8)].copy_from_slice(&(size as u64).to_le_bytes());
+
+        if size == 0 {
+            // an empty chunk would be read as the terminator
+            buf.truncate(offset + 8);
+        } else {
+            buf[(offset + 8)..(offset + 12)].copy_from_slice(&(size as u32).to_le_bytes());
+        }
+
+        // PLP_TERMINATOR
+        buf.extend(&0_u32.to_le_bytes());
+    }
+
     pub(crate) fn name(&self) -> &'static str {
         match self.ty {
             DataType::Null => "NULL",
@@ -508,12 +620,49 @@ impl TypeInfo {
                 _ => unreachable!("invalid size {} for float"),
             },
 
+            DataType::Bit | DataType::BitN => "BIT",
+
             DataType::VarChar => "VARCHAR",
             DataType::NVarChar => "NVARCHAR",
             DataType::BigVarChar => "BIGVARCHAR",
             DataType::Char => "CHAR",
             DataType::BigChar => "BIGCHAR",
             DataType::NChar => "NCHAR",
+            DataType::Text => "TEXT",
+            DataType::NText => "NTEXT",
+            DataType::Xml => "XML",
+
+            DataType::Binary | DataType::BigBinary => "BINARY",
+            DataType::VarBinary | DataType::BigVarBinary => "VARBINARY",
+            DataType::Image => "IMAGE",
+
+            DataType::Guid => "UNIQUEIDENTIFIER",
+
+            DataType::Decimal | DataType::DecimalN => "DECIMAL",
+            DataType::Numeric | DataType::NumericN => "NUMERIC",
+            DataType::Money => "MONEY",
+            DataType::SmallMoney => "SMALLMONEY",
+
+            DataType::MoneyN => match self.size {
+                4 => "SMALLMONEY",
+                8 => "MONEY",
+
+                _ => unreachable!("invalid size {} for money", self.size),
+            },
+
+            DataType::DateN => "DATE",
+            DataType::TimeN => "TIME",
+            DataType::DateTime2N => "DATETIME2",
+            DataType::DateTimeOffsetN => "DATETIMEOFFSET",
+            DataType::DateTime => "DATETIME",
+            DataType::SmallDateTime => "SMALLDATETIME",
+
+            DataType::DateTimeN => match self.size {
+                4 => "SMALLDATETIME",
+                8 => "DATETIME",
+
+                _ => unreachable!("invalid size {} for datetime", self.size),
+            },
 
             _ => unimplemented!("name: unsupported data type {:?}", self.ty),
         }
@@ -564,23 +713,118 @@ impl TypeInfo {
                     _ => unreachable!(),
                 });
 
-                // size
-                if self.size < 8000 && self.size > 0 {
-                    s.push_str("(");
-                    let _ = itoa::fmt(&mut *s, self.size);
-                    s.push_str(")");
-                } else {
-                    s.push_str("(max)");
-                }
+                // size, given in characters for the national types
+                let size = match self.ty {
+                    DataType::NVarChar | DataType::NChar => self.size / 2,
+                    _ => self.size,
+                };
+
+                self.fmt_size(s, size);
+            }
+
+            DataType::VarBinary
+            | DataType::BigVarBinary
+            | DataType::Binary
+            | DataType::BigBinary => {
+                s.push_str(match self.ty {
+                    DataType::VarBinary | DataType::BigVarBinary => "varbinary",
+                    _ => "binary",
+                });
+
+                self.fmt_size(s, self.size);
             }
 
             DataType::BitN => {
                 s.push_str("bit");
             }
 
+            DataType::Text => s.push_str("text"),
+            DataType::NText => s.push_str("ntext"),
+            DataType::Image => s.push_str("image"),
+            DataType::Xml => s.push_str("xml"),
+            DataType::Guid => s.push_str("uniqueidentifier"),
+
+            DataType::Decimal | DataType::DecimalN | DataType::Numeric | DataType::NumericN => {
+                s.push_str(match self.ty {
+                    DataType::Decimal | DataType::DecimalN => "decimal(",
+                    _ => "numeric(",
+                });
+
+                let _ = itoa::fmt(&mut *s, self.precision);
+                s.push(',');
+                let _ = itoa::fmt(&mut *s, self.scale);
+                s.push(')');
+            }
+
+            DataType::Money => s.push_str("money"),
+            DataType::SmallMoney => s.push_str("smallmoney"),
+
+            DataType::MoneyN => s.push_str(match self.size {
+                4 => "smallmoney",
+                8 => "money",
+
+                _ => unreachable!("invalid size {} for money", self.size),
+            }),
+
+            DataType::DateN => s.push_str("date"),
+            DataType::DateTime => s.push_str("datetime"),
+            DataType::SmallDateTime => s.push_str("smalldatetime"),
+
+            DataType::DateTimeN => s.push_str(match self.size {
+                4 => "smalldatetime",
+                8 => "datetime",
+
+                _ => unreachable!("invalid size {} for datetime", self.size),
+            }),
+
+            DataType::TimeN | DataType::DateTime2N | DataType::DateTimeOffsetN => {
+                s.push_str(match self.ty {
+                    DataType::TimeN => "time(",
+                    DataType::DateTime2N => "datetime2(",
+                    _ => "datetimeoffset(",
+                });
+
+                let _ = itoa::fmt(&mut *s, self.scale);
+                s.push(')');
+            }
+
             _ => unimplemented!("fmt: unsupported data type {:?}", self.ty),
         }
     }
+
+    fn fmt_size(&self, s: &mut String, size: u32) {
+        if self.size == MAX_SIZE || size == 0 {
+            s.push_str("(max)");
+        } else {
+            s.push('(');
+            let _ = itoa::fmt(&mut *s, size);
+            s.push(')');
+        }
+    }
+}
+
+// reads a PLP value: the total length followed by chunks of the value, up to an empty chunk
+fn get_plp_value(buf: &mut Bytes) -> Option<Bytes> {
+    let len = buf.get_u64_le();
+
+    if len == PLP_NULL {
+        return None;
+    }
+
+    // the length may also be unknown (0xFFFF_FFFF_FFFF_FFFE) so only use it as a hint
+    let mut value = BytesMut::with_capacity(len.min(MAX_FIXED_SIZE as u64) as usize);
+
+    loop {
+        let chunk_len = buf.get_u32_le() as usize;
+
+        if chunk_len == 0 {
+            break;
+        }
+
+        value.extend_from_slice(&buf.split_to(chunk_len));
+    }
+
+    Some(value.freeze())
 }
 
 impl DataType {
@@ -660,3 +904,40 @@ impl Collation {
         buf.push(self.sort);
     }
 }
+
+#[test]
+fn test_get_plp_value() {
+    let ty = TypeInfo::new(DataType::BigVarBinary, MAX_SIZE);
+
+    #[rustfmt::skip]
+    let mut buf = Bytes::from_static(&[
+        // total length of 5, in two chunks
+        0x05, 0, 0, 0, 0, 0, 0, 0,
+        0x02, 0, 0, 0, b'h', b'e',
+        0x03, 0, 0, 0, b'l', b'l', b'o',
+        0, 0, 0, 0,
+        // NULL
+        0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF,
+    ]);
+
+    assert_eq!(ty.get_value(&mut buf).as_deref(), Some(&b"hello"[..]));
+    assert_eq!(ty.get_value(&mut buf), None);
+    assert!(buf.is_empty());
+}
+
+#[test]
+fn test_put_plp_value() {
+    let ty = TypeInfo::new(DataType::BigVarBinary, MAX_SIZE);
+
+    let mut buf = Vec::new();
+    ty.put_value(&mut buf, &b"hello"[..]);
+    ty.put_value(&mut buf, &b""[..]);
+    ty.put_value(&mut buf, None::<&[u8]>);
+
+    let mut buf = Bytes::from(buf);
+
+    assert_eq!(ty.get_value(&mut buf).as_deref(), Some(&b"hello"[..]));
+    assert_eq!(ty.get_value(&mut buf).as_deref(), Some(&b""[..]));
+    assert_eq!(ty.get_value(&mut buf), None);
+    assert!(buf.is_empty());
+}
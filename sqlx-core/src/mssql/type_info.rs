@@ -7,6 +7,33 @@ use crate::type_info::TypeInfo;
 #[cfg_attr(feature = "offline", derive(serde::Serialize, serde::Deserialize))]
 pub struct MssqlTypeInfo(pub(crate) ProtocolTypeInfo);
 
+impl MssqlTypeInfo {
+    #[doc(hidden)]
+    pub fn __type_feature_gate(&self) -> Option<&'static str> {
+        match self.0.ty {
+            DataType::DateN
+            | DataType::TimeN
+            | DataType::DateTime2N
+            | DataType::DateTimeOffsetN
+            | DataType::DateTimeN
+            | DataType::DateTime
+            | DataType::SmallDateTime => Some("time"),
+
+            DataType::DecimalN
+            | DataType::NumericN
+            | DataType::Decimal
+            | DataType::Numeric
+            | DataType::MoneyN
+            | DataType::Money
+            | DataType::SmallMoney => Some("bigdecimal"),
+
+            DataType::Guid => Some("uuid"),
+
+            _ => None,
+        }
+    }
+}
+
 impl TypeInfo for MssqlTypeInfo {
     fn is_null(&self) -> bool {
         matches!(self.0.ty, DataType::Null)
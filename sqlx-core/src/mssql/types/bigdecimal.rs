@@ -0,0 +1,65 @@
+use bigdecimal::BigDecimal;
+use num_bigint::{BigInt, Sign};
+
+use crate::decode::Decode;
+use crate::encode::{Encode, IsNull};
+use crate::error::BoxDynError;
+use crate::mssql::types::numeric::{self, MssqlNumeric};
+use crate::mssql::{Mssql, MssqlTypeInfo, MssqlValueRef};
+use crate::types::Type;
+
+impl Type<Mssql> for BigDecimal {
+    fn type_info() -> MssqlTypeInfo {
+        numeric::type_info(0)
+    }
+
+    fn compatible(ty: &MssqlTypeInfo) -> bool {
+        numeric::compatible(ty)
+    }
+}
+
+impl Encode<'_, Mssql> for BigDecimal {
+    fn produces(&self) -> Option<MssqlTypeInfo> {
+        Some(numeric::type_info(scale(self) as u8))
+    }
+
+    fn encode_by_ref(&self, buf: &mut Vec<u8>) -> IsNull {
+        // DECIMAL has no negative scale so multiply out any trailing zeros
+        let (integer, _) = self.with_scale(scale(self)).into_bigint_and_exponent();
+        let (sign, magnitude) = integer.to_bytes_le();
+
+        numeric::encode(buf, sign == Sign::Minus, &magnitude);
+
+        IsNull::No
+    }
+}
+
+impl Decode<'_, Mssql> for BigDecimal {
+    fn decode(value: MssqlValueRef<'_>) -> Result<Self, BoxDynError> {
+        Ok(match numeric::decode(&value)? {
+            MssqlNumeric::Decimal {
+                negative,
+                magnitude,
+                scale,
+            } => {
+                let sign = if negative { Sign::Minus } else { Sign::Plus };
+
+                BigDecimal::new(BigInt::from_bytes_le(sign, magnitude), scale as i64)
+            }
+
+            MssqlNumeric::Money(value) => BigDecimal::new(value.into(), 4),
+        })
+    }
+}
+
+fn scale(decimal: &BigDecimal) -> i64 {
+    let (_, exp) = decimal.as_bigint_and_exponent();
+    let scale = exp.max(0);
+
+    assert!(
+        scale <= 38,
+        "BigDecimal scale too great for MSSQL DECIMAL type"
+    );
+
+    scale
+}
@@ -0,0 +1,74 @@
+use crate::decode::Decode;
+use crate::encode::{Encode, IsNull};
+use crate::error::BoxDynError;
+use crate::mssql::protocol::type_info::{DataType, TypeInfo, MAX_FIXED_SIZE, MAX_SIZE};
+use crate::mssql::{Mssql, MssqlTypeInfo, MssqlValueRef};
+use crate::types::Type;
+
+impl Type<Mssql> for [u8] {
+    fn type_info() -> MssqlTypeInfo {
+        MssqlTypeInfo(TypeInfo::new(DataType::BigVarBinary, MAX_SIZE))
+    }
+
+    fn compatible(ty: &MssqlTypeInfo) -> bool {
+        matches!(
+            ty.0.ty,
+            DataType::BigVarBinary
+                | DataType::VarBinary
+                | DataType::BigBinary
+                | DataType::Binary
+                | DataType::Image
+        )
+    }
+}
+
+impl Type<Mssql> for Vec<u8> {
+    fn type_info() -> MssqlTypeInfo {
+        <[u8] as Type<Mssql>>::type_info()
+    }
+
+    fn compatible(ty: &MssqlTypeInfo) -> bool {
+        <[u8] as Type<Mssql>>::compatible(ty)
+    }
+}
+
+impl Encode<'_, Mssql> for &'_ [u8] {
+    fn produces(&self) -> Option<MssqlTypeInfo> {
+        // values that do not fit in a `varbinary(8000)` are sent as `varbinary(max)`
+        let size = match self.len() as u32 {
+            0 => 1,
+            len if len > MAX_FIXED_SIZE => MAX_SIZE,
+            len => len,
+        };
+
+        Some(MssqlTypeInfo(TypeInfo::new(DataType::BigVarBinary, size)))
+    }
+
+    fn encode_by_ref(&self, buf: &mut Vec<u8>) -> IsNull {
+        buf.extend_from_slice(self);
+
+        IsNull::No
+    }
+}
+
+impl Encode<'_, Mssql> for Vec<u8> {
+    fn produces(&self) -> Option<MssqlTypeInfo> {
+        <&[u8] as Encode<Mssql>>::produces(&self.as_slice())
+    }
+
+    fn encode_by_ref(&self, buf: &mut Vec<u8>) -> IsNull {
+        <&[u8] as Encode<Mssql>>::encode_by_ref(&self.as_slice(), buf)
+    }
+}
+
+impl<'r> Decode<'r, Mssql> for &'r [u8] {
+    fn decode(value: MssqlValueRef<'r>) -> Result<Self, BoxDynError> {
+        value.as_bytes()
+    }
+}
+
+impl Decode<'_, Mssql> for Vec<u8> {
+    fn decode(value: MssqlValueRef<'_>) -> Result<Self, BoxDynError> {
+        <&[u8] as Decode<Mssql>>::decode(value).map(ToOwned::to_owned)
+    }
+}
@@ -0,0 +1,222 @@
+use chrono::{
+    DateTime, Datelike, FixedOffset, NaiveDate, NaiveDateTime, NaiveTime, Offset, TimeZone,
+    Timelike, Utc,
+};
+
+use crate::decode::Decode;
+use crate::encode::{Encode, IsNull};
+use crate::error::BoxDynError;
+use crate::mssql::protocol::type_info::DataType;
+use crate::mssql::types::date_time::{self, DateTimeParts};
+use crate::mssql::{Mssql, MssqlTypeInfo, MssqlValueRef};
+use crate::types::Type;
+
+impl Type<Mssql> for NaiveDate {
+    fn type_info() -> MssqlTypeInfo {
+        date_time::type_info(DataType::DateN)
+    }
+
+    fn compatible(ty: &MssqlTypeInfo) -> bool {
+        matches!(ty.0.ty, DataType::DateN)
+    }
+}
+
+/// ### Panics
+/// If the date is outside of the range of DATE, 0001-01-01 to 9999-12-31.
+impl Encode<'_, Mssql> for NaiveDate {
+    fn encode_by_ref(&self, buf: &mut Vec<u8>) -> IsNull {
+        date_time::encode_date(buf, days(self));
+
+        IsNull::No
+    }
+}
+
+impl Decode<'_, Mssql> for NaiveDate {
+    fn decode(value: MssqlValueRef<'_>) -> Result<Self, BoxDynError> {
+        to_date(date_time::decode(&value)?.days)
+    }
+}
+
+impl Type<Mssql> for NaiveTime {
+    fn type_info() -> MssqlTypeInfo {
+        date_time::type_info(DataType::TimeN)
+    }
+
+    fn compatible(ty: &MssqlTypeInfo) -> bool {
+        matches!(ty.0.ty, DataType::TimeN)
+    }
+}
+
+impl Encode<'_, Mssql> for NaiveTime {
+    fn encode_by_ref(&self, buf: &mut Vec<u8>) -> IsNull {
+        date_time::encode_time(buf, nanos(self));
+
+        IsNull::No
+    }
+}
+
+impl Decode<'_, Mssql> for NaiveTime {
+    fn decode(value: MssqlValueRef<'_>) -> Result<Self, BoxDynError> {
+        to_time(date_time::decode(&value)?.nanos)
+    }
+}
+
+impl Type<Mssql> for NaiveDateTime {
+    fn type_info() -> MssqlTypeInfo {
+        date_time::type_info(DataType::DateTime2N)
+    }
+
+    fn compatible(ty: &MssqlTypeInfo) -> bool {
+        date_time::is_date_time(ty)
+    }
+}
+
+/// ### Panics
+/// If the date is outside of the range of DATE, 0001-01-01 to 9999-12-31.
+impl Encode<'_, Mssql> for NaiveDateTime {
+    fn encode_by_ref(&self, buf: &mut Vec<u8>) -> IsNull {
+        let days = days(&self.date());
+
+        date_time::encode_time(buf, nanos(&self.time()));
+        date_time::encode_date(buf, days);
+
+        IsNull::No
+    }
+}
+
+impl Decode<'_, Mssql> for NaiveDateTime {
+    fn decode(value: MssqlValueRef<'_>) -> Result<Self, BoxDynError> {
+        to_date_time(&date_time::decode(&value)?)
+    }
+}
+
+impl Type<Mssql> for DateTime<FixedOffset> {
+    fn type_info() -> MssqlTypeInfo {
+        date_time::type_info(DataType::DateTimeOffsetN)
+    }
+
+    fn compatible(ty: &MssqlTypeInfo) -> bool {
+        matches!(ty.0.ty, DataType::DateTimeOffsetN)
+    }
+}
+
+/// ### Panics
+/// If the date in UTC is outside of the range of DATE, 0001-01-01 to 9999-12-31.
+impl Encode<'_, Mssql> for DateTime<FixedOffset> {
+    fn encode_by_ref(&self, buf: &mut Vec<u8>) -> IsNull {
+        let utc = self.naive_utc();
+        let days = days(&utc.date());
+
+        date_time::encode_time(buf, nanos(&utc.time()));
+        date_time::encode_date(buf, days);
+        date_time::encode_offset(buf, (self.offset().local_minus_utc() / 60) as i16);
+
+        IsNull::No
+    }
+}
+
+impl Decode<'_, Mssql> for DateTime<FixedOffset> {
+    fn decode(value: MssqlValueRef<'_>) -> Result<Self, BoxDynError> {
+        let parts = date_time::decode(&value)?;
+        let utc = to_date_time(&parts)?;
+
+        Ok(FixedOffset::east(parts.offset as i32 * 60).from_utc_datetime(&utc))
+    }
+}
+
+impl Type<Mssql> for DateTime<Utc> {
+    fn type_info() -> MssqlTypeInfo {
+        <DateTime<FixedOffset> as Type<Mssql>>::type_info()
+    }
+
+    fn compatible(ty: &MssqlTypeInfo) -> bool {
+        <DateTime<FixedOffset> as Type<Mssql>>::compatible(ty)
+    }
+}
+
+/// ### Panics
+/// If the date is outside of the range of DATE, 0001-01-01 to 9999-12-31.
+impl Encode<'_, Mssql> for DateTime<Utc> {
+    fn encode_by_ref(&self, buf: &mut Vec<u8>) -> IsNull {
+        Encode::<Mssql>::encode(self.with_timezone(&Utc.fix()), buf)
+    }
+}
+
+impl Decode<'_, Mssql> for DateTime<Utc> {
+    fn decode(value: MssqlValueRef<'_>) -> Result<Self, BoxDynError> {
+        let parts = date_time::decode(&value)?;
+
+        Ok(DateTime::from_utc(to_date_time(&parts)?, Utc))
+    }
+}
+
+fn days(date: &NaiveDate) -> i64 {
+    // the day of 0001-01-01 is 1
+    date_time::checked_days(date.num_days_from_ce() as i64 - 1)
+}
+
+fn nanos(time: &NaiveTime) -> u64 {
+    time.num_seconds_from_midnight() as u64 * 1_000_000_000 + time.nanosecond() as u64
+}
+
+fn to_date(days: i64) -> Result<NaiveDate, BoxDynError> {
+    NaiveDate::from_num_days_from_ce_opt(days as i32 + 1)
+        .ok_or_else(|| format!("date out of range: {} days from 0001-01-01", days).into())
+}
+
+fn to_time(nanos: u64) -> Result<NaiveTime, BoxDynError> {
+    NaiveTime::from_num_seconds_from_midnight_opt(
+        (nanos / 1_000_000_000) as u32,
+        (nanos % 1_000_000_000) as u32,
+    )
+    .ok_or_else(|| format!("time out of range: {} nanoseconds from midnight", nanos).into())
+}
+
+fn to_date_time(parts: &DateTimeParts) -> Result<NaiveDateTime, BoxDynError> {
+    Ok(to_date(parts.days)?.and_time(to_time(parts.nanos)?))
+}
+
+#[test]
+fn test_decode_date_time() {
+    use crate::mssql::protocol::type_info::TypeInfo;
+    use bytes::Bytes;
+
+    let decode = |ty: DataType, data: &'static [u8]| {
+        let data = Bytes::from_static(data);
+        let value = MssqlValueRef {
+            type_info: MssqlTypeInfo(TypeInfo::new(ty, data.len() as u32)),
+            data: Some(&data),
+        };
+
+        <NaiveDateTime as Decode<Mssql>>::decode(value).unwrap()
+    };
+
+    // 1 day after 1900-01-01 and 300 1/300ths of a second after midnight
+    assert_eq!(
+        decode(DataType::DateTime, &[1, 0, 0, 0, 0x2c, 0x01, 0, 0]),
+        NaiveDate::from_ymd(1900, 1, 2).and_hms(0, 0, 1)
+    );
+
+    // 2 days after 1900-01-01 and 90 minutes after midnight
+    assert_eq!(
+        decode(DataType::DateTimeN, &[2, 0, 90, 0]),
+        NaiveDate::from_ymd(1900, 1, 3).and_hms(1, 30, 0)
+    );
+
+    let date_time = NaiveDate::from_ymd(2020, 7, 14).and_hms_nano(12, 34, 56, 789_012_300);
+    let mut buf = Vec::new();
+    let _ = Encode::<Mssql>::encode(date_time, &mut buf);
+
+    assert_eq!(buf.len(), 8);
+
+    let data = Bytes::from(buf);
+    let value = MssqlValueRef {
+        type_info: <NaiveDateTime as Type<Mssql>>::type_info(),
+        data: Some(&data),
+    };
+
+    assert_eq!(
+        <NaiveDateTime as Decode<Mssql>>::decode(value).unwrap(),
+        date_time
+    );
+}
@@ -0,0 +1,175 @@
+use byteorder::{ByteOrder, LittleEndian};
+
+use crate::error::BoxDynError;
+use crate::mssql::protocol::type_info::{DataType, TypeInfo};
+use crate::mssql::{MssqlTypeInfo, MssqlValueRef};
+
+// the wire formats of the date and time types shared by the `chrono` and `time` integrations
+// https://docs.microsoft.com/en-us/openspecs/windows_protocols/ms-tds/786f5b8a-f87d-4980-9070-b9b7274c681d
+
+// days from 0001-01-01 (the epoch of DATE) to 1900-01-01 (the epoch of DATETIME)
+const DAYS_TO_1900: i64 = 693_595;
+
+// days from 0001-01-01 to 9999-12-31, the latest date that can be stored
+const MAX_DAYS: i64 = 3_652_058;
+
+const NANOS_PER_SECOND: u64 = 1_000_000_000;
+
+// we always send fractional seconds at the highest precision, in units of 100ns
+const SCALE: u8 = 7;
+
+/// A date and time as sent over the wire.
+pub(crate) struct DateTimeParts {
+    // days since 0001-01-01
+    pub(crate) days: i64,
+
+    // nanoseconds since midnight
+    pub(crate) nanos: u64,
+
+    // minutes east of UTC; `days` and `nanos` are in UTC when this is set
+    pub(crate) offset: i16,
+}
+
+pub(crate) fn type_info(ty: DataType) -> MssqlTypeInfo {
+    let size = match ty {
+        DataType::DateN => 3,
+        DataType::TimeN => 5,
+        DataType::DateTime2N => 8,
+        DataType::DateTimeOffsetN => 10,
+
+        _ => unreachable!("unexpected date or time type {:?}", ty),
+    };
+
+    MssqlTypeInfo(TypeInfo {
+        ty,
+        size,
+        scale: if ty == DataType::DateN { 0 } else { SCALE },
+        precision: 0,
+        collation: None,
+    })
+}
+
+pub(crate) fn is_date_time(ty: &MssqlTypeInfo) -> bool {
+    matches!(
+        ty.0.ty,
+        DataType::DateTime2N | DataType::DateTimeN | DataType::DateTime | DataType::SmallDateTime
+    )
+}
+
+// `Encode` cannot return an error, so a date that cannot be stored panics; this is called
+// before anything is written to the buffer
+pub(crate) fn checked_days(days: i64) -> i64 {
+    if !(0..=MAX_DAYS).contains(&days) {
+        panic!(
+            "date out of range for MSSQL: {} days from 0001-01-01, DATE stores 0001-01-01 to \
+             9999-12-31",
+            days
+        );
+    }
+
+    days
+}
+
+pub(crate) fn encode_date(buf: &mut Vec<u8>, days: i64) {
+    buf.extend_from_slice(&days.to_le_bytes()[..3]);
+}
+
+pub(crate) fn encode_time(buf: &mut Vec<u8>, nanos: u64) {
+    buf.extend_from_slice(&(nanos / 100).to_le_bytes()[..5]);
+}
+
+pub(crate) fn encode_offset(buf: &mut Vec<u8>, minutes: i16) {
+    buf.extend_from_slice(&minutes.to_le_bytes());
+}
+
+pub(crate) fn decode(value: &MssqlValueRef<'_>) -> Result<DateTimeParts, BoxDynError> {
+    let ty = &value.type_info.0;
+    let buf = value.as_bytes()?;
+
+    let parts = match (ty.ty, buf.len()) {
+        (DataType::DateN, 3) => DateTimeParts {
+            days: decode_date(buf),
+            nanos: 0,
+            offset: 0,
+        },
+
+        (DataType::TimeN, 3..=5) => DateTimeParts {
+            days: 0,
+            nanos: decode_time(buf, ty.scale)?,
+            offset: 0,
+        },
+
+        (DataType::DateTime2N, 6..=8) => {
+            let (time, date) = buf.split_at(buf.len() - 3);
+
+            DateTimeParts {
+                days: decode_date(date),
+                nanos: decode_time(time, ty.scale)?,
+                offset: 0,
+            }
+        }
+
+        (DataType::DateTimeOffsetN, 8..=10) => {
+            let (time, rest) = buf.split_at(buf.len() - 5);
+            let (date, offset) = rest.split_at(3);
+
+            DateTimeParts {
+                days: decode_date(date),
+                nanos: decode_time(time, ty.scale)?,
+                offset: LittleEndian::read_i16(offset),
+            }
+        }
+
+        // DATETIME is the days since 1900-01-01 and the 1/300ths of a second since midnight
+        (DataType::DateTime, 8) | (DataType::DateTimeN, 8) => {
+            let days = LittleEndian::read_i32(&buf[..4]) as i64;
+            let ticks = LittleEndian::read_u32(&buf[4..]) as u64;
+
+            DateTimeParts {
+                days: DAYS_TO_1900 + days,
+                nanos: ticks * NANOS_PER_SECOND / 300,
+                offset: 0,
+            }
+        }
+
+        // SMALLDATETIME is the days since 1900-01-01 and the minutes since midnight
+        (DataType::SmallDateTime, 4) | (DataType::DateTimeN, 4) => {
+            let days = LittleEndian::read_u16(&buf[..2]) as i64;
+            let minutes = LittleEndian::read_u16(&buf[2..]) as u64;
+
+            DateTimeParts {
+                days: DAYS_TO_1900 + days,
+                nanos: minutes * 60 * NANOS_PER_SECOND,
+                offset: 0,
+            }
+        }
+
+        (_, len) => {
+            return Err(format!("unexpected {} bytes for {}", len, ty.name()).into());
+        }
+    };
+
+    Ok(parts)
+}
+
+fn decode_date(buf: &[u8]) -> i64 {
+    LittleEndian::read_u24(buf) as i64
+}
+
+// TIME(n) is the number of 10^-n second units since midnight
+fn decode_time(buf: &[u8], scale: u8) -> Result<u64, BoxDynError> {
+    if scale > SCALE {
+        return Err(format!("invalid scale {} for TIME", scale).into());
+    }
+
+    let mut units = [0; 8];
+    units[..buf.len()].copy_from_slice(buf);
+
+    Ok(u64::from_le_bytes(units) * 10_u64.pow(9 - scale as u32))
+}
+
+#[test]
+#[should_panic(expected = "date out of range for MSSQL")]
+fn test_checked_days_out_of_range() {
+    checked_days(MAX_DAYS + 1);
+}
@@ -0,0 +1,70 @@
+use rust_decimal::Decimal;
+
+use crate::decode::Decode;
+use crate::encode::{Encode, IsNull};
+use crate::error::BoxDynError;
+use crate::mssql::types::numeric::{self, MssqlNumeric};
+use crate::mssql::{Mssql, MssqlTypeInfo, MssqlValueRef};
+use crate::types::Type;
+
+const MAX_SCALE: u8 = 28;
+
+impl Type<Mssql> for Decimal {
+    fn type_info() -> MssqlTypeInfo {
+        numeric::type_info(0)
+    }
+
+    fn compatible(ty: &MssqlTypeInfo) -> bool {
+        numeric::compatible(ty)
+    }
+}
+
+impl Encode<'_, Mssql> for Decimal {
+    fn produces(&self) -> Option<MssqlTypeInfo> {
+        Some(numeric::type_info(self.scale() as u8))
+    }
+
+    fn encode_by_ref(&self, buf: &mut Vec<u8>) -> IsNull {
+        // the 96-bit magnitude is stored in the last 12 bytes, after the flags
+        let bytes = self.serialize();
+        numeric::encode(buf, self.is_sign_negative(), &bytes[4..]);
+
+        IsNull::No
+    }
+}
+
+impl Decode<'_, Mssql> for Decimal {
+    fn decode(value: MssqlValueRef<'_>) -> Result<Self, BoxDynError> {
+        match numeric::decode(&value)? {
+            MssqlNumeric::Decimal {
+                negative,
+                magnitude,
+                scale,
+            } => {
+                let mut bytes = [0; 16];
+                bytes[..magnitude.len()].copy_from_slice(magnitude);
+                let unscaled = u128::from_le_bytes(bytes);
+
+                // a `Decimal` has a 96-bit magnitude and a scale of up to 28
+                if unscaled >> 96 != 0 || scale > MAX_SCALE {
+                    return Err(format!(
+                        "DECIMAL value with {} bytes and a scale of {} is too large for Decimal",
+                        magnitude.len(),
+                        scale
+                    )
+                    .into());
+                }
+
+                Ok(Decimal::from_parts(
+                    unscaled as u32,
+                    (unscaled >> 32) as u32,
+                    (unscaled >> 64) as u32,
+                    negative,
+                    scale as u32,
+                ))
+            }
+
+            MssqlNumeric::Money(value) => Ok(Decimal::new(value, 4)),
+        }
+    }
+}
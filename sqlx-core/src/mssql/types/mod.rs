@@ -1,12 +1,104 @@
+//! Conversions between Rust and **MSSQL** types.
+//!
+//! # Types
+//!
+//! | Rust type                             | MSSQL type(s)                                        |
+//! |---------------------------------------|------------------------------------------------------|
+//! | `bool`                                | BIT                                                  |
+//! | `i8`                                  | TINYINT                                              |
+//! | `i16`                                 | SMALLINT                                             |
+//! | `i32`                                 | INT                                                  |
+//! | `i64`                                 | BIGINT                                               |
+//! | `f32`                                 | REAL                                                 |
+//! | `f64`                                 | FLOAT                                                |
+//! | `&str`, `String`                      | NVARCHAR, NCHAR, VARCHAR, CHAR, NTEXT, TEXT, XML     |
+//! | `&[u8]`, `Vec<u8>`                    | VARBINARY, BINARY, IMAGE                             |
+//!
+//! Strings and byte arrays that are too long for `NVARCHAR(4000)` and `VARBINARY(8000)` are
+//! sent as `NVARCHAR(MAX)` and `VARBINARY(MAX)`.
+//!
+//! ### [`chrono`](https://crates.io/crates/chrono)
+//!
+//! Requires the `chrono` Cargo feature flag.
+//!
+//! | Rust type                             | MSSQL type(s)                                        |
+//! |---------------------------------------|------------------------------------------------------|
+//! | `chrono::DateTime<Utc>`               | DATETIMEOFFSET                                       |
+//! | `chrono::DateTime<FixedOffset>`       | DATETIMEOFFSET                                       |
+//! | `chrono::NaiveDateTime`               | DATETIME2, DATETIME, SMALLDATETIME                   |
+//! | `chrono::NaiveDate`                   | DATE                                                 |
+//! | `chrono::NaiveTime`                   | TIME                                                 |
+//!
+//! ### [`time`](https://crates.io/crates/time)
+//!
+//! Requires the `time` Cargo feature flag.
+//!
+//! | Rust type                             | MSSQL type(s)                                        |
+//! |---------------------------------------|------------------------------------------------------|
+//! | `time::PrimitiveDateTime`             | DATETIME2, DATETIME, SMALLDATETIME                   |
+//! | `time::OffsetDateTime`                | DATETIMEOFFSET                                       |
+//! | `time::Date`                          | DATE                                                 |
+//! | `time::Time`                          | TIME                                                 |
+//!
+//! ### [`bigdecimal`](https://crates.io/crates/bigdecimal)
+//! Requires the `bigdecimal` Cargo feature flag.
+//!
+//! | Rust type                             | MSSQL type(s)                                        |
+//! |---------------------------------------|------------------------------------------------------|
+//! | `bigdecimal::BigDecimal`              | DECIMAL, NUMERIC, MONEY, SMALLMONEY                  |
+//!
+//! ### [`decimal`](https://crates.io/crates/rust_decimal)
+//! Requires the `decimal` Cargo feature flag.
+//!
+//! | Rust type                             | MSSQL type(s)                                        |
+//! |---------------------------------------|------------------------------------------------------|
+//! | `rust_decimal::Decimal`               | DECIMAL, NUMERIC, MONEY, SMALLMONEY                  |
+//!
+//! ### [`uuid`](https://crates.io/crates/uuid)
+//!
+//! Requires the `uuid` Cargo feature flag.
+//!
+//! | Rust type                             | MSSQL type(s)                                        |
+//! |---------------------------------------|------------------------------------------------------|
+//! | `uuid::Uuid`                          | UNIQUEIDENTIFIER                                     |
+//!
+//! # Nullable
+//!
+//! In addition, `Option<T>` is supported where `T` implements `Type`. An `Option<T>` represents
+//! a potentially `NULL` value from MSSQL.
+//!
+
 use crate::encode::{Encode, IsNull};
 use crate::mssql::protocol::type_info::{DataType, TypeInfo};
 use crate::mssql::{Mssql, MssqlTypeInfo};
 
 mod bool;
+mod bytes;
 mod float;
 mod int;
 mod str;
 
+#[cfg(feature = "bigdecimal")]
+mod bigdecimal;
+
+#[cfg(feature = "decimal")]
+mod decimal;
+
+#[cfg(any(feature = "decimal", feature = "bigdecimal"))]
+mod numeric;
+
+#[cfg(feature = "chrono")]
+mod chrono;
+
+#[cfg(feature = "time")]
+mod time;
+
+#[cfg(any(feature = "chrono", feature = "time"))]
+mod date_time;
+
+#[cfg(feature = "uuid")]
+mod uuid;
+
 impl<'q, T: 'q + Encode<'q, Mssql>> Encode<'q, Mssql> for Option<T> {
     fn encode(self, buf: &mut Vec<u8>) -> IsNull {
         if let Some(v) = self {
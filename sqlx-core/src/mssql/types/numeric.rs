@@ -0,0 +1,92 @@
+use byteorder::{ByteOrder, LittleEndian};
+
+use crate::error::BoxDynError;
+use crate::mssql::protocol::type_info::{DataType, TypeInfo};
+use crate::mssql::{MssqlTypeInfo, MssqlValueRef};
+
+// the wire formats of the exact numeric types shared by the `rust_decimal` and `bigdecimal`
+// integrations
+// https://docs.microsoft.com/en-us/openspecs/windows_protocols/ms-tds/5e02042c-a741-4b9c-9ba4-0e4b0b6c4e6b
+
+// the greatest precision of DECIMAL, which stores the magnitude in 16 bytes
+const MAX_PRECISION: u8 = 38;
+const MAX_MAGNITUDE_SIZE: usize = 16;
+
+/// An exact numeric as sent over the wire.
+pub(crate) enum MssqlNumeric<'r> {
+    // DECIMAL and NUMERIC; the unscaled value is the little-endian magnitude
+    Decimal {
+        negative: bool,
+        magnitude: &'r [u8],
+        scale: u8,
+    },
+
+    // MONEY and SMALLMONEY; the value in ten-thousandths
+    Money(i64),
+}
+
+pub(crate) fn type_info(scale: u8) -> MssqlTypeInfo {
+    MssqlTypeInfo(TypeInfo {
+        ty: DataType::DecimalN,
+        size: 1 + MAX_MAGNITUDE_SIZE as u32,
+        scale,
+        precision: MAX_PRECISION,
+        collation: None,
+    })
+}
+
+pub(crate) fn compatible(ty: &MssqlTypeInfo) -> bool {
+    matches!(
+        ty.0.ty,
+        DataType::DecimalN
+            | DataType::NumericN
+            | DataType::Decimal
+            | DataType::Numeric
+            | DataType::MoneyN
+            | DataType::Money
+            | DataType::SmallMoney
+    )
+}
+
+// encodes a value as a `decimal(38, scale)`, the scale being given by `type_info`
+pub(crate) fn encode(buf: &mut Vec<u8>, negative: bool, magnitude: &[u8]) {
+    assert!(
+        magnitude.len() <= MAX_MAGNITUDE_SIZE,
+        "magnitude too great for MSSQL DECIMAL type"
+    );
+
+    buf.push(if negative { 0 } else { 1 });
+    buf.extend_from_slice(magnitude);
+    buf.resize(buf.len() + MAX_MAGNITUDE_SIZE - magnitude.len(), 0);
+}
+
+pub(crate) fn decode<'r>(value: &MssqlValueRef<'r>) -> Result<MssqlNumeric<'r>, BoxDynError> {
+    let ty = &value.type_info.0;
+    let buf = value.as_bytes()?;
+
+    match (ty.ty, buf.len()) {
+        (DataType::DecimalN, 5..=17)
+        | (DataType::NumericN, 5..=17)
+        | (DataType::Decimal, 5..=17)
+        | (DataType::Numeric, 5..=17) => Ok(MssqlNumeric::Decimal {
+            // the sign is 1 for a positive value and 0 for a negative one
+            negative: buf[0] == 0,
+            magnitude: &buf[1..],
+            scale: ty.scale,
+        }),
+
+        // MONEY is sent as the high 4 bytes followed by the low 4 bytes
+        (DataType::Money, 8) | (DataType::MoneyN, 8) => {
+            let high = LittleEndian::read_i32(&buf[..4]) as i64;
+            let low = LittleEndian::read_u32(&buf[4..]) as i64;
+
+            Ok(MssqlNumeric::Money((high << 32) | low))
+        }
+
+        (DataType::SmallMoney, 4) | (DataType::MoneyN, 4) => {
+            Ok(MssqlNumeric::Money(LittleEndian::read_i32(buf) as i64))
+        }
+
+        (_, len) => Err(format!("unexpected {} bytes for {}", len, ty.name()).into()),
+    }
+}
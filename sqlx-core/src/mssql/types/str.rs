@@ -2,7 +2,9 @@ use crate::decode::Decode;
 use crate::encode::{Encode, IsNull};
 use crate::error::BoxDynError;
 use crate::mssql::io::MssqlBufMutExt;
-use crate::mssql::protocol::type_info::{Collation, CollationFlags, DataType, TypeInfo};
+use crate::mssql::protocol::type_info::{
+    Collation, CollationFlags, DataType, TypeInfo, MAX_FIXED_SIZE, MAX_SIZE,
+};
 use crate::mssql::{Mssql, MssqlTypeInfo, MssqlValueRef};
 use crate::types::Type;
 
@@ -20,6 +22,9 @@ impl Type<Mssql> for str {
                 | DataType::VarChar
                 | DataType::BigChar
                 | DataType::Char
+                | DataType::NText
+                | DataType::Text
+                | DataType::Xml
         )
     }
 }
@@ -36,10 +41,17 @@ impl Type<Mssql> for String {
 
 impl Encode<'_, Mssql> for &'_ str {
     fn produces(&self) -> Option<MssqlTypeInfo> {
-        // an empty string needs to be encoded as `nvarchar(2)`
+        // an empty string needs to be encoded as `nvarchar(1)` and a string that
+        // does not fit in a `nvarchar(4000)` as `nvarchar(max)`
+        let size = match (self.len() * 2) as u32 {
+            0 => 2,
+            size if size > MAX_FIXED_SIZE => MAX_SIZE,
+            size => size,
+        };
+
         Some(MssqlTypeInfo(TypeInfo {
             ty: DataType::NVarChar,
-            size: ((self.len() * 2) as u32).max(2),
+            size,
             scale: 0,
             precision: 0,
             collation: Some(Collation {
@@ -0,0 +1,166 @@
+use std::convert::TryFrom;
+
+use time::{date, Date, OffsetDateTime, PrimitiveDateTime, Time, UtcOffset};
+
+use crate::decode::Decode;
+use crate::encode::{Encode, IsNull};
+use crate::error::BoxDynError;
+use crate::mssql::protocol::type_info::DataType;
+use crate::mssql::types::date_time::{self, DateTimeParts};
+use crate::mssql::{Mssql, MssqlTypeInfo, MssqlValueRef};
+use crate::types::Type;
+
+// the epoch of DATE
+const EPOCH: Date = date!(0001 - 01 - 01);
+
+impl Type<Mssql> for Date {
+    fn type_info() -> MssqlTypeInfo {
+        date_time::type_info(DataType::DateN)
+    }
+
+    fn compatible(ty: &MssqlTypeInfo) -> bool {
+        matches!(ty.0.ty, DataType::DateN)
+    }
+}
+
+/// ### Panics
+/// If the date is outside of the range of DATE, 0001-01-01 to 9999-12-31.
+impl Encode<'_, Mssql> for Date {
+    fn encode_by_ref(&self, buf: &mut Vec<u8>) -> IsNull {
+        date_time::encode_date(buf, days(*self));
+
+        IsNull::No
+    }
+}
+
+impl Decode<'_, Mssql> for Date {
+    fn decode(value: MssqlValueRef<'_>) -> Result<Self, BoxDynError> {
+        Ok(to_date(date_time::decode(&value)?.days))
+    }
+}
+
+impl Type<Mssql> for Time {
+    fn type_info() -> MssqlTypeInfo {
+        date_time::type_info(DataType::TimeN)
+    }
+
+    fn compatible(ty: &MssqlTypeInfo) -> bool {
+        matches!(ty.0.ty, DataType::TimeN)
+    }
+}
+
+impl Encode<'_, Mssql> for Time {
+    fn encode_by_ref(&self, buf: &mut Vec<u8>) -> IsNull {
+        date_time::encode_time(buf, nanos(*self));
+
+        IsNull::No
+    }
+}
+
+impl Decode<'_, Mssql> for Time {
+    fn decode(value: MssqlValueRef<'_>) -> Result<Self, BoxDynError> {
+        to_time(date_time::decode(&value)?.nanos)
+    }
+}
+
+impl Type<Mssql> for PrimitiveDateTime {
+    fn type_info() -> MssqlTypeInfo {
+        date_time::type_info(DataType::DateTime2N)
+    }
+
+    fn compatible(ty: &MssqlTypeInfo) -> bool {
+        date_time::is_date_time(ty)
+    }
+}
+
+/// ### Panics
+/// If the date is outside of the range of DATE, 0001-01-01 to 9999-12-31.
+impl Encode<'_, Mssql> for PrimitiveDateTime {
+    fn encode_by_ref(&self, buf: &mut Vec<u8>) -> IsNull {
+        let days = days(self.date());
+
+        date_time::encode_time(buf, nanos(self.time()));
+        date_time::encode_date(buf, days);
+
+        IsNull::No
+    }
+}
+
+impl Decode<'_, Mssql> for PrimitiveDateTime {
+    fn decode(value: MssqlValueRef<'_>) -> Result<Self, BoxDynError> {
+        to_date_time(&date_time::decode(&value)?)
+    }
+}
+
+impl Type<Mssql> for OffsetDateTime {
+    fn type_info() -> MssqlTypeInfo {
+        date_time::type_info(DataType::DateTimeOffsetN)
+    }
+
+    fn compatible(ty: &MssqlTypeInfo) -> bool {
+        matches!(ty.0.ty, DataType::DateTimeOffsetN)
+    }
+}
+
+/// ### Panics
+/// If the date in UTC is outside of the range of DATE, 0001-01-01 to 9999-12-31.
+impl Encode<'_, Mssql> for OffsetDateTime {
+    fn encode_by_ref(&self, buf: &mut Vec<u8>) -> IsNull {
+        let utc = self.to_offset(UtcOffset::UTC);
+        let days = days(utc.date());
+
+        date_time::encode_time(buf, nanos(utc.time()));
+        date_time::encode_date(buf, days);
+        date_time::encode_offset(buf, self.offset().as_minutes());
+
+        IsNull::No
+    }
+}
+
+impl Decode<'_, Mssql> for OffsetDateTime {
+    fn decode(value: MssqlValueRef<'_>) -> Result<Self, BoxDynError> {
+        let parts = date_time::decode(&value)?;
+        let utc = to_date_time(&parts)?.assume_utc();
+
+        Ok(utc.to_offset(UtcOffset::minutes(parts.offset)))
+    }
+}
+
+fn days(date: Date) -> i64 {
+    date_time::checked_days(date.julian_day() - EPOCH.julian_day())
+}
+
+fn nanos(time: Time) -> u64 {
+    let seconds = time.hour() as u64 * 3600 + time.minute() as u64 * 60 + time.second() as u64;
+
+    seconds * 1_000_000_000 + time.nanosecond() as u64
+}
+
+fn to_date(days: i64) -> Date {
+    Date::from_julian_day(EPOCH.julian_day() + days)
+}
+
+fn to_time(nanos: u64) -> Result<Time, BoxDynError> {
+    let seconds = nanos / 1_000_000_000;
+
+    Ok(Time::try_from_hms_nano(
+        u8::try_from(seconds / 3600)?,
+        (seconds / 60 % 60) as u8,
+        (seconds % 60) as u8,
+        (nanos % 1_000_000_000) as u32,
+    )?)
+}
+
+fn to_date_time(parts: &DateTimeParts) -> Result<PrimitiveDateTime, BoxDynError> {
+    Ok(PrimitiveDateTime::new(
+        to_date(parts.days),
+        to_time(parts.nanos)?,
+    ))
+}
+
+#[test]
+fn test_days() {
+    assert_eq!(days(EPOCH), 0);
+    assert_eq!(days(date!(1900 - 01 - 01)), 693_595);
+    assert_eq!(to_date(3_652_058), date!(9999 - 12 - 31));
+}
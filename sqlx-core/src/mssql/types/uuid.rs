@@ -0,0 +1,57 @@
+use uuid::Uuid;
+
+use crate::decode::Decode;
+use crate::encode::{Encode, IsNull};
+use crate::error::BoxDynError;
+use crate::mssql::protocol::type_info::{DataType, TypeInfo};
+use crate::mssql::{Mssql, MssqlTypeInfo, MssqlValueRef};
+use crate::types::Type;
+
+impl Type<Mssql> for Uuid {
+    fn type_info() -> MssqlTypeInfo {
+        MssqlTypeInfo(TypeInfo::new(DataType::Guid, 16))
+    }
+
+    fn compatible(ty: &MssqlTypeInfo) -> bool {
+        matches!(ty.0.ty, DataType::Guid)
+    }
+}
+
+impl Encode<'_, Mssql> for Uuid {
+    fn encode_by_ref(&self, buf: &mut Vec<u8>) -> IsNull {
+        buf.extend_from_slice(&swap_bytes(self.as_bytes()));
+
+        IsNull::No
+    }
+}
+
+impl Decode<'_, Mssql> for Uuid {
+    fn decode(value: MssqlValueRef<'_>) -> Result<Self, BoxDynError> {
+        let bytes = value.as_bytes()?;
+
+        if bytes.len() != 16 {
+            return Err(format!(
+                "expected 16 bytes for UNIQUEIDENTIFIER, got {}",
+                bytes.len()
+            )
+            .into());
+        }
+
+        let mut uuid = [0; 16];
+        uuid.copy_from_slice(bytes);
+
+        Ok(Uuid::from_bytes(swap_bytes(&uuid)))
+    }
+}
+
+// a GUID is sent as a little-endian u32, two little-endian u16s and then 8 bytes as-is;
+// converting to and from the big-endian RFC 4122 layout is the same swap
+fn swap_bytes(bytes: &[u8; 16]) -> [u8; 16] {
+    let mut swapped = *bytes;
+
+    swapped[0..4].reverse();
+    swapped[4..6].reverse();
+    swapped[6..8].reverse();
+
+    swapped
+}
@@ -3,6 +3,7 @@ use crate::describe::Describe;
 use crate::error::Error;
 use crate::executor::{Execute, Executor};
 use crate::ext::ustr::UStr;
+use crate::logging::QueryLogger;
 use crate::mysql::connection::stream::Busy;
 use crate::mysql::io::MySqlBufExt;
 use crate::mysql::protocol::response::Status;
@@ -92,12 +93,16 @@ impl MySqlConnection {
         self.stream.busy = Busy::Result;
 
         Ok(Box::pin(try_stream! {
+            let mut logger = QueryLogger::new(sql, self.log_settings.clone());
+
             // make a slot for the shared column data
             // as long as a reference to a row is not held past one iteration, this enables us
             // to re-use this memory freely between result sets
             let mut columns = Arc::new(Vec::new());
 
             let (mut column_names, format, mut needs_metadata) = if let Some(arguments) = arguments {
+                logger.parameters(&arguments.types);
+
                 let (id, metadata) = self.get_or_prepare(
                     sql,
                     persistent,
@@ -183,6 +188,8 @@ impl MySqlConnection {
                         return Ok(());
                     }
 
+                    logger.increment_rows();
+
                     let row = match format {
                         MySqlValueFormat::Binary => packet.decode_with::<BinaryRow, _>(&columns)?.0,
                         MySqlValueFormat::Text => packet.decode_with::<TextRow, _>(&columns)?.0,
@@ -202,6 +209,108 @@ impl MySqlConnection {
     }
 }
 
+impl MySqlConnection {
+    /// Execute one prepared statement once for each set of arguments and return the rows
+    /// affected by each execution, in order.
+    ///
+    /// The statement is prepared (or checked out of the cache) once and every execution
+    /// is sent to the server in a single write, without waiting for the previous one to
+    /// complete. Any rows returned by the statement are discarded.
+    ///
+    /// Every execution is carried out even if an earlier one fails, in which case the first
+    /// error is returned once all of them have completed. Run the batch inside a transaction
+    /// to have it applied all-or-nothing.
+    pub async fn execute_batch<I>(
+        &mut self,
+        query: &str,
+        arguments: I,
+    ) -> Result<Vec<MySqlDone>, Error>
+    where
+        I: IntoIterator<Item = MySqlArguments>,
+    {
+        let arguments: Vec<MySqlArguments> = arguments.into_iter().collect();
+
+        if arguments.is_empty() {
+            return Ok(Vec::new());
+        }
+
+        self.stream.wait_until_ready().await?;
+
+        let (id, _) = self.get_or_prepare(query, true).await?;
+
+        let mut loggers = Vec::with_capacity(arguments.len());
+
+        // https://dev.mysql.com/doc/internals/en/com-stmt-execute.html
+        for arguments in &arguments {
+            let mut logger = QueryLogger::new(query, self.log_settings.clone());
+            logger.parameters(&arguments.types);
+            loggers.push(logger);
+
+            // each execution is a new command
+            self.stream.sequence_id = 0;
+            self.stream.write_packet(StatementExecute {
+                statement: id,
+                arguments,
+            });
+        }
+
+        // if we return early or are dropped, the responses that have not been received are
+        // skipped when the connection is next made ready
+        self.stream.pending_responses = arguments.len();
+        self.stream.flush().await?;
+
+        let mut done = Vec::with_capacity(arguments.len());
+        let mut error = None;
+
+        // each execution is logged once its response has been received
+        for _logger in loggers {
+            self.stream.pending_responses -= 1;
+            self.stream.busy = Busy::Result;
+
+            // the response to each execution is an OK, an ERR, or a result set
+            match self.stream.recv_packet().await {
+                Ok(packet) if packet[0] == 0x00 => {
+                    let ok = packet.ok()?;
+
+                    done.push(MySqlDone {
+                        rows_affected: ok.affected_rows,
+                        last_insert_id: ok.last_insert_id,
+                    });
+
+                    if ok.status.contains(Status::SERVER_MORE_RESULTS_EXISTS) {
+                        self.stream.skip_response().await?;
+                    }
+                }
+
+                Ok(packet) => {
+                    // a result set; the rows are of no use to us
+                    self.stream.busy = Busy::Row;
+                    self.stream.skip_result_metadata(packet).await?;
+                    self.stream.skip_response().await?;
+
+                    done.push(MySqlDone {
+                        rows_affected: 0,
+                        last_insert_id: 0,
+                    });
+                }
+
+                Err(e @ Error::Database(_)) => {
+                    error.get_or_insert(e);
+                }
+
+                Err(e) => return Err(e),
+            }
+
+            self.stream.busy = Busy::NotBusy;
+        }
+
+        match error {
+            Some(error) => Err(error),
+            None => Ok(done),
+        }
+    }
+}
+
 impl<'c> Executor<'c> for &'c mut MySqlConnection {
     type Database = MySql;
 
@@ -17,6 +17,11 @@ pub struct MySqlStream {
     pub(super) capabilities: Capabilities,
     pub(crate) sequence_id: u8,
     pub(crate) busy: Busy,
+
+    // the number of responses still to be received, after the current one, for commands that
+    // were sent without waiting for the previous response; see `MySqlConnection::execute_batch`
+    pub(crate) pending_responses: usize,
+
     pub(crate) charset: CharSet,
     pub(crate) collation: Collation,
 }
@@ -66,6 +71,7 @@ impl MySqlStream {
 
         Ok(Self {
             busy: Busy::NotBusy,
+            pending_responses: 0,
             capabilities,
             server_version: (0, 0, 0),
             sequence_id: 0,
@@ -80,6 +86,25 @@ impl MySqlStream {
             self.stream.flush().await?;
         }
 
+        self.skip_response().await?;
+
+        // nobody is waiting for the responses to the rest of a batch that was abandoned,
+        // including any error they carry
+        while self.pending_responses > 0 {
+            self.pending_responses -= 1;
+            self.busy = Busy::Result;
+
+            match self.skip_response().await {
+                Ok(()) | Err(Error::Database(_)) => {}
+                Err(error) => return Err(error),
+            }
+        }
+
+        Ok(())
+    }
+
+    // receive what is left of the response to the current command
+    pub(crate) async fn skip_response(&mut self) -> Result<(), Error> {
         while self.busy != Busy::NotBusy {
             while self.busy == Busy::Row {
                 let packet = self.recv_packet().await?;
@@ -181,7 +206,10 @@ impl MySqlStream {
         }
     }
 
-    async fn skip_result_metadata(&mut self, mut packet: Packet<Bytes>) -> Result<(), Error> {
+    pub(super) async fn skip_result_metadata(
+        &mut self,
+        mut packet: Packet<Bytes>,
+    ) -> Result<(), Error> {
         let num_columns: u64 = packet.get_uint_lenenc(); // column count
 
         for _ in 0..num_columns {
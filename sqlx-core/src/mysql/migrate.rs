@@ -2,7 +2,7 @@ use crate::connection::ConnectOptions;
 use crate::error::Error;
 use crate::executor::Executor;
 use crate::migrate::MigrateError;
-use crate::migrate::Migration;
+use crate::migrate::{AppliedMigration, AppliedMigrationRow, Migration};
 use crate::migrate::{Migrate, MigrateDatabase};
 use crate::mysql::{MySql, MySqlConnectOptions, MySqlConnection};
 use crate::query::query;
@@ -97,6 +97,22 @@ CREATE TABLE IF NOT EXISTS _sqlx_migrations (
         })
     }
 
+    fn migrations_table_exists(&mut self) -> BoxFuture<'_, Result<bool, MigrateError>> {
+        Box::pin(async move {
+            // language=MySQL
+            let count: i64 = query_scalar(
+                r#"
+    SELECT COUNT(*) FROM information_schema.tables
+    WHERE table_schema = DATABASE() AND table_name = '_sqlx_migrations'
+                "#,
+            )
+            .fetch_one(self)
+            .await?;
+
+            Ok(count > 0)
+        })
+    }
+
     fn version(&mut self) -> BoxFuture<'_, Result<Option<(i64, bool)>, MigrateError>> {
         Box::pin(async move {
             // language=SQL
@@ -110,6 +126,25 @@ CREATE TABLE IF NOT EXISTS _sqlx_migrations (
         })
     }
 
+    fn list_applied_migrations(
+        &mut self,
+    ) -> BoxFuture<'_, Result<Vec<AppliedMigration>, MigrateError>> {
+        Box::pin(async move {
+            // language=MySQL
+            let rows: Vec<AppliedMigrationRow> = query_as(
+                r#"
+    SELECT version, description, CAST(installed_on AS CHAR), success, checksum, execution_time
+    FROM _sqlx_migrations
+    ORDER BY version
+                "#,
+            )
+            .fetch_all(self)
+            .await?;
+
+            Ok(rows.into_iter().map(AppliedMigration::from_row).collect())
+        })
+    }
+
     fn lock(&mut self) -> BoxFuture<'_, Result<(), MigrateError>> {
         Box::pin(async move {
             let database_name = current_database(self).await?;
@@ -175,6 +210,8 @@ CREATE TABLE IF NOT EXISTS _sqlx_migrations (
         migration: &'m Migration,
     ) -> BoxFuture<'m, Result<Duration, MigrateError>> {
         Box::pin(async move {
+            // MySQL does not have transactional DDL; every migration runs outside of a
+            // transaction, regardless of `-- no-transaction`
             let start = Instant::now();
 
             let res = self.execute(&*migration.sql).await;
@@ -201,6 +238,36 @@ CREATE TABLE IF NOT EXISTS _sqlx_migrations (
             Ok(elapsed)
         })
     }
+
+    fn revert<'e: 'm, 'm>(
+        &'e mut self,
+        migration: &'m Migration,
+    ) -> BoxFuture<'m, Result<Duration, MigrateError>> {
+        Box::pin(async move {
+            // MySQL does not have transactional DDL; if the down migration fails part way
+            // through, the migration is left marked as dirty
+
+            // language=MySQL
+            let _ = query("UPDATE _sqlx_migrations SET success = FALSE WHERE version = ?")
+                .bind(migration.version)
+                .execute(&mut *self)
+                .await?;
+
+            let start = Instant::now();
+
+            let _ = self.execute(&*migration.sql).await?;
+
+            let elapsed = start.elapsed();
+
+            // language=MySQL
+            let _ = query("DELETE FROM _sqlx_migrations WHERE version = ?")
+                .bind(migration.version)
+                .execute(self)
+                .await?;
+
+            Ok(elapsed)
+        })
+    }
 }
 
 async fn current_database(conn: &mut MySqlConnection) -> Result<String, MigrateError> {
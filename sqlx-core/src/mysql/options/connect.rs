@@ -3,6 +3,8 @@ use crate::error::Error;
 use crate::executor::Executor;
 use crate::mysql::{MySqlConnectOptions, MySqlConnection};
 use futures_core::future::BoxFuture;
+use log::LevelFilter;
+use std::time::Duration;
 
 impl ConnectOptions for MySqlConnectOptions {
     type Connection = MySqlConnection;
@@ -53,4 +55,19 @@ impl ConnectOptions for MySqlConnectOptions {
             Ok(conn)
         })
     }
+
+    fn log_statements(mut self, level: LevelFilter) -> Self {
+        self.log_settings.log_statements(level);
+        self
+    }
+
+    fn log_slow_statements(mut self, level: LevelFilter, duration: Duration) -> Self {
+        self.log_settings.log_slow_statements(level, duration);
+        self
+    }
+
+    fn log_parameters(mut self, enabled: bool) -> Self {
+        self.log_settings.log_parameters(enabled);
+        self
+    }
 }
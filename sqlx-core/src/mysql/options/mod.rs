@@ -1,3 +1,4 @@
+use crate::connection::LogSettings;
 use std::path::{Path, PathBuf};
 
 mod connect;
@@ -63,6 +64,7 @@ pub struct MySqlConnectOptions {
     pub(crate) ssl_mode: MySqlSslMode,
     pub(crate) ssl_ca: Option<PathBuf>,
     pub(crate) statement_cache_capacity: usize,
+    pub(crate) log_settings: LogSettings,
     pub(crate) charset: String,
     pub(crate) collation: Option<String>,
 }
@@ -88,6 +90,7 @@ impl MySqlConnectOptions {
             ssl_mode: MySqlSslMode::Preferred,
             ssl_ca: None,
             statement_cache_capacity: 100,
+            log_settings: Default::default(),
         }
     }
 
@@ -189,6 +189,7 @@ impl ColumnType {
             ColumnType::Geometry => "GEOMETRY",
             ColumnType::Json => "JSON",
 
+            ColumnType::String if flags.contains(ColumnFlags::ENUM) => "ENUM",
             ColumnType::String if is_binary => "BINARY",
             ColumnType::VarChar | ColumnType::VarString if is_binary => "VARBINARY",
 
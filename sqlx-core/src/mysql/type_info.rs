@@ -30,6 +30,12 @@ impl MySqlTypeInfo {
         }
     }
 
+    // the columns of an `ENUM` are reported as strings with the `ENUM` flag set
+    #[doc(hidden)]
+    pub fn __is_enum(&self) -> bool {
+        self.r#type == ColumnType::Enum || self.flags.contains(ColumnFlags::ENUM)
+    }
+
     #[doc(hidden)]
     pub fn __type_feature_gate(&self) -> Option<&'static str> {
         match self.r#type {
@@ -77,7 +77,7 @@ impl<DB: Database> Drop for PoolConnection<DB> {
 
                         // we now consider the connection to be broken
                         // close the connection and drop from the pool
-                        let _ = live.float(&pool).into_idle().close().await;
+                        pool.close_conn(live.float(&pool).into_idle()).await;
                     } else {
                         // after we have flushed successfully, release to the pool
                         pool.release(live.float(&pool));
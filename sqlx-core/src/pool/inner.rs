@@ -1,4 +1,5 @@
 use super::connection::{Floating, Idle, Live};
+use super::metrics::{PoolMetrics, PoolMetricsSnapshot};
 use crate::connection::ConnectOptions;
 use crate::connection::Connection;
 use crate::database::Database;
@@ -13,7 +14,7 @@ use std::mem;
 use std::ptr;
 use std::sync::atomic::{AtomicBool, AtomicU32, Ordering};
 use std::sync::Arc;
-use std::time::Instant;
+use std::time::{Duration, Instant};
 
 pub(crate) struct SharedPool<DB: Database> {
     pub(super) connect_options: <DB::Connection as Connection>::Options,
@@ -22,6 +23,7 @@ pub(crate) struct SharedPool<DB: Database> {
     pub(super) size: AtomicU32,
     is_closed: AtomicBool,
     pub(super) options: PoolOptions<DB>,
+    metrics: PoolMetrics,
 }
 
 impl<DB: Database> SharedPool<DB> {
@@ -34,6 +36,14 @@ impl<DB: Database> SharedPool<DB> {
         self.idle_conns.len()
     }
 
+    pub(super) fn num_waiters(&self) -> usize {
+        self.metrics.num_waiters()
+    }
+
+    pub(super) fn metrics(&self) -> PoolMetricsSnapshot {
+        self.metrics.snapshot(self.size(), self.num_idle())
+    }
+
     pub(super) fn is_closed(&self) -> bool {
         self.is_closed.load(Ordering::Acquire)
     }
@@ -62,7 +72,11 @@ impl<DB: Database> SharedPool<DB> {
         if self.options.fair && !self.waiters.is_empty() {
             return None;
         }
-        Some(self.pop_idle()?.into_live())
+
+        let live = self.pop_idle()?.into_live();
+        self.metrics.acquired(Duration::default());
+
+        Some(live)
     }
 
     fn pop_idle(&self) -> Option<Floating<'_, Idle<DB>>> {
@@ -90,6 +104,14 @@ impl<DB: Database> SharedPool<DB> {
         }
     }
 
+    /// Close a connection that is being dropped from the pool.
+    pub(super) async fn close_conn(&self, conn: Floating<'_, Idle<DB>>) {
+        if let Err(e) = conn.close().await {
+            log::debug!("error occurred while closing a pooled connection: {}", e);
+            self.metrics.close_failed();
+        }
+    }
+
     /// Try to atomically increment the pool size for a new connection.
     ///
     /// Returns `None` if we are at max_connections or if the pool is closed.
@@ -123,6 +145,7 @@ impl<DB: Database> SharedPool<DB> {
         }
 
         let mut waker_pushed = false;
+        let _waiting = self.metrics.wait();
 
         timeout(
             deadline_as_timeout::<DB>(deadline)?,
@@ -153,6 +176,7 @@ impl<DB: Database> SharedPool<DB> {
             size: AtomicU32::new(0),
             is_closed: AtomicBool::new(false),
             options,
+            metrics: PoolMetrics::default(),
         };
 
         let pool = Arc::new(pool);
@@ -165,7 +189,24 @@ impl<DB: Database> SharedPool<DB> {
     #[allow(clippy::needless_lifetimes)]
     pub(super) async fn acquire<'s>(&'s self) -> Result<Floating<'s, Live<DB>>, Error> {
         let start = Instant::now();
-        let deadline = start + self.options.connect_timeout;
+        let result = self
+            .acquire_until(start + self.options.connect_timeout)
+            .await;
+
+        match &result {
+            Ok(_) => self.metrics.acquired(start.elapsed()),
+            Err(Error::PoolTimedOut) => self.metrics.acquire_timed_out(),
+            Err(_) => {}
+        }
+
+        result
+    }
+
+    #[allow(clippy::needless_lifetimes)]
+    async fn acquire_until<'s>(
+        &'s self,
+        deadline: Instant,
+    ) -> Result<Floating<'s, Live<DB>>, Error> {
         let mut waited = !self.options.fair;
 
         // Unless the pool has been closed ...
@@ -175,7 +216,7 @@ impl<DB: Database> SharedPool<DB> {
                 // Attempt to immediately acquire a connection. This will return Some
                 // if there is an idle connection in our channel.
                 if let Some(conn) = self.pop_idle() {
-                    if let Some(live) = check_conn(conn, &self.options).await {
+                    if let Some(live) = check_conn(conn, self).await {
                         return Ok(live);
                     }
                 }
@@ -212,6 +253,20 @@ impl<DB: Database> SharedPool<DB> {
 
         let timeout = super::deadline_as_timeout::<DB>(deadline)?;
 
+        let result = self.try_connect(timeout, guard).await;
+
+        if !matches!(result, Ok(Some(_))) {
+            self.metrics.connect_failed();
+        }
+
+        result
+    }
+
+    async fn try_connect<'s>(
+        &'s self,
+        timeout: Duration,
+        guard: DecrementSizeGuard<'s>,
+    ) -> Result<Option<Floating<'s, Live<DB>>>, Error> {
         // result here is `Result<Result<C, Error>, TimeoutError>`
         match sqlx_rt::timeout(timeout, self.connect_options.connect()).await {
             // successfully established connection
@@ -258,16 +313,17 @@ fn is_beyond_idle<DB: Database>(idle: &Idle<DB>, options: &PoolOptions<DB>) -> b
         .map_or(false, |timeout| idle.since.elapsed() > timeout)
 }
 
-async fn check_conn<'s: 'p, 'p, DB: Database>(
+async fn check_conn<'s, DB: Database>(
     mut conn: Floating<'s, Idle<DB>>,
-    options: &'p PoolOptions<DB>,
+    pool: &'s SharedPool<DB>,
 ) -> Option<Floating<'s, Live<DB>>> {
+    let options = &pool.options;
+
     // If the connection we pulled has expired, close the connection and
     // immediately create a new connection
     if is_beyond_lifetime(&conn, options) {
         // we're closing the connection either way
-        // close the connection but don't really care about the result
-        let _ = conn.close().await;
+        pool.close_conn(conn).await;
         return None;
     } else if options.test_before_acquire {
         // Check that the connection is still live
@@ -332,7 +388,7 @@ fn spawn_reaper<DB: Database>(pool: &Arc<SharedPool<DB>>) {
             }
 
             for conn in reap {
-                let _ = conn.close().await;
+                pool.close_conn(conn).await;
             }
 
             sleep(period).await;
@@ -0,0 +1,222 @@
+use std::sync::atomic::{AtomicU64, AtomicUsize, Ordering};
+use std::time::Duration;
+
+/// Upper bounds of the buckets of the acquire wait-time histogram.
+const ACQUIRE_WAIT_BUCKETS: [Duration; 12] = [
+    Duration::from_millis(1),
+    Duration::from_millis(5),
+    Duration::from_millis(10),
+    Duration::from_millis(25),
+    Duration::from_millis(50),
+    Duration::from_millis(100),
+    Duration::from_millis(250),
+    Duration::from_millis(500),
+    Duration::from_secs(1),
+    Duration::from_millis(2500),
+    Duration::from_secs(5),
+    Duration::from_secs(10),
+];
+
+/// Counters updated by the pool as it is used.
+#[derive(Default)]
+pub(super) struct PoolMetrics {
+    num_waiters: AtomicUsize,
+    acquire_count: AtomicU64,
+    acquire_timeout_count: AtomicU64,
+    // total time spent waiting, in microseconds
+    acquire_wait_sum: AtomicU64,
+    // the number of acquires in each bucket, *not* including those of the smaller buckets;
+    // the last slot counts the acquires that waited longer than the largest bucket
+    acquire_wait_buckets: [AtomicU64; 13],
+    connect_error_count: AtomicU64,
+    close_error_count: AtomicU64,
+}
+
+impl PoolMetrics {
+    pub(super) fn num_waiters(&self) -> usize {
+        self.num_waiters.load(Ordering::Acquire)
+    }
+
+    /// Marks a task as waiting for a connection until the returned guard is dropped.
+    pub(super) fn wait(&self) -> WaiterGuard<'_> {
+        self.num_waiters.fetch_add(1, Ordering::AcqRel);
+
+        WaiterGuard(&self.num_waiters)
+    }
+
+    pub(super) fn acquired(&self, waited: Duration) {
+        let bucket = ACQUIRE_WAIT_BUCKETS
+            .iter()
+            .position(|le| waited <= *le)
+            .unwrap_or(ACQUIRE_WAIT_BUCKETS.len());
+
+        self.acquire_count.fetch_add(1, Ordering::Relaxed);
+        self.acquire_wait_buckets[bucket].fetch_add(1, Ordering::Relaxed);
+        self.acquire_wait_sum
+            .fetch_add(waited.as_micros() as u64, Ordering::Relaxed);
+    }
+
+    pub(super) fn acquire_timed_out(&self) {
+        self.acquire_timeout_count.fetch_add(1, Ordering::Relaxed);
+    }
+
+    pub(super) fn connect_failed(&self) {
+        self.connect_error_count.fetch_add(1, Ordering::Relaxed);
+    }
+
+    pub(super) fn close_failed(&self) {
+        self.close_error_count.fetch_add(1, Ordering::Relaxed);
+    }
+
+    pub(super) fn snapshot(&self, size: u32, num_idle: usize) -> PoolMetricsSnapshot {
+        let mut cumulative = 0;
+        let mut buckets = Vec::with_capacity(ACQUIRE_WAIT_BUCKETS.len());
+
+        for (le, count) in ACQUIRE_WAIT_BUCKETS.iter().zip(&self.acquire_wait_buckets) {
+            cumulative += count.load(Ordering::Relaxed);
+            buckets.push((*le, cumulative));
+        }
+
+        let count = cumulative
+            + self.acquire_wait_buckets[ACQUIRE_WAIT_BUCKETS.len()].load(Ordering::Relaxed);
+
+        PoolMetricsSnapshot {
+            size,
+            num_idle,
+            num_waiters: self.num_waiters(),
+            acquire_count: self.acquire_count.load(Ordering::Relaxed),
+            acquire_timeout_count: self.acquire_timeout_count.load(Ordering::Relaxed),
+            acquire_wait: AcquireWaitHistogram {
+                buckets,
+                count,
+                sum: Duration::from_micros(self.acquire_wait_sum.load(Ordering::Relaxed)),
+            },
+            connect_error_count: self.connect_error_count.load(Ordering::Relaxed),
+            close_error_count: self.close_error_count.load(Ordering::Relaxed),
+        }
+    }
+}
+
+/// RAII guard returned by `PoolMetrics::wait()`.
+pub(super) struct WaiterGuard<'a>(&'a AtomicUsize);
+
+impl Drop for WaiterGuard<'_> {
+    fn drop(&mut self) {
+        self.0.fetch_sub(1, Ordering::AcqRel);
+    }
+}
+
+/// A point-in-time view of the state of a [`Pool`][crate::pool::Pool] and of the counters
+/// it has accumulated since it was created.
+///
+/// Returned by [`Pool::metrics`][crate::pool::Pool::metrics].
+#[derive(Debug, Clone)]
+pub struct PoolMetricsSnapshot {
+    size: u32,
+    num_idle: usize,
+    num_waiters: usize,
+    acquire_count: u64,
+    acquire_timeout_count: u64,
+    acquire_wait: AcquireWaitHistogram,
+    connect_error_count: u64,
+    close_error_count: u64,
+}
+
+impl PoolMetricsSnapshot {
+    /// The number of connections currently open, whether idle or in use.
+    pub fn size(&self) -> u32 {
+        self.size
+    }
+
+    /// The number of connections currently idle in the pool.
+    pub fn num_idle(&self) -> usize {
+        self.num_idle
+    }
+
+    /// The number of tasks currently waiting for a connection to become available.
+    pub fn num_waiters(&self) -> usize {
+        self.num_waiters
+    }
+
+    /// The total number of connections successfully acquired from the pool.
+    pub fn acquire_count(&self) -> u64 {
+        self.acquire_count
+    }
+
+    /// The total number of attempts to acquire a connection that timed out.
+    pub fn acquire_timeout_count(&self) -> u64 {
+        self.acquire_timeout_count
+    }
+
+    /// How long successful acquires had to wait for a connection.
+    pub fn acquire_wait(&self) -> &AcquireWaitHistogram {
+        &self.acquire_wait
+    }
+
+    /// The total number of failed attempts to open a new connection.
+    pub fn connect_error_count(&self) -> u64 {
+        self.connect_error_count
+    }
+
+    /// The total number of connections that returned an error when they were closed by
+    /// the pool.
+    pub fn close_error_count(&self) -> u64 {
+        self.close_error_count
+    }
+}
+
+/// A histogram of the time spent waiting to acquire a connection.
+///
+/// The buckets are cumulative, in the same way as those of a Prometheus histogram.
+#[derive(Debug, Clone)]
+pub struct AcquireWaitHistogram {
+    buckets: Vec<(Duration, u64)>,
+    count: u64,
+    sum: Duration,
+}
+
+impl AcquireWaitHistogram {
+    /// The upper bound of each bucket, paired with the number of acquires that waited
+    /// at most that long.
+    ///
+    /// Acquires that waited longer than the largest bound are only included in
+    /// [`count`][Self::count].
+    pub fn buckets(&self) -> &[(Duration, u64)] {
+        &self.buckets
+    }
+
+    /// The total number of acquires recorded.
+    pub fn count(&self) -> u64 {
+        self.count
+    }
+
+    /// The total time spent waiting by all recorded acquires.
+    pub fn sum(&self) -> Duration {
+        self.sum
+    }
+}
+
+#[test]
+fn test_acquire_wait_histogram() {
+    let metrics = PoolMetrics::default();
+
+    metrics.acquired(Duration::from_micros(500));
+    metrics.acquired(Duration::from_millis(1));
+    metrics.acquired(Duration::from_millis(30));
+    metrics.acquired(Duration::from_secs(60));
+
+    let snapshot = metrics.snapshot(2, 1);
+    let histogram = snapshot.acquire_wait();
+
+    assert_eq!(snapshot.acquire_count(), 4);
+    assert_eq!(histogram.count(), 4);
+    assert_eq!(
+        histogram.sum(),
+        Duration::from_micros(500) + Duration::from_millis(31) + Duration::from_secs(60)
+    );
+
+    assert_eq!(histogram.buckets()[0], (Duration::from_millis(1), 2));
+    assert_eq!(histogram.buckets()[3], (Duration::from_millis(25), 2));
+    assert_eq!(histogram.buckets()[4], (Duration::from_millis(50), 3));
+    assert_eq!(histogram.buckets()[11], (Duration::from_secs(10), 3));
+}
@@ -63,10 +63,12 @@ mod maybe;
 
 mod connection;
 mod inner;
+mod metrics;
 mod options;
 
 pub use self::connection::PoolConnection;
 pub(crate) use self::maybe::MaybePoolConnection;
+pub use self::metrics::{AcquireWaitHistogram, PoolMetricsSnapshot};
 pub use self::options::PoolOptions;
 
 /// An asynchronous pool of SQLx database connections.
@@ -145,6 +147,27 @@ impl<DB: Database> Pool<DB> {
     pub fn is_closed(&self) -> bool {
         self.0.is_closed()
     }
+
+    /// Returns the number of connections currently active. This includes idle connections.
+    pub fn size(&self) -> u32 {
+        self.0.size()
+    }
+
+    /// Returns the number of connections active and idle (not in use).
+    pub fn num_idle(&self) -> usize {
+        self.0.num_idle()
+    }
+
+    /// Returns the number of tasks currently waiting for a connection to become available.
+    pub fn num_waiters(&self) -> usize {
+        self.0.num_waiters()
+    }
+
+    /// Returns a snapshot of the current state of the pool and of the counters it has
+    /// accumulated since it was created; suitable for exporting as metrics.
+    pub fn metrics(&self) -> PoolMetricsSnapshot {
+        self.0.metrics()
+    }
 }
 
 /// Returns a new [Pool] tied to the same shared connection pool.
@@ -159,6 +182,7 @@ impl<DB: Database> fmt::Debug for Pool<DB> {
         fmt.debug_struct("Pool")
             .field("size", &self.0.size())
             .field("num_idle", &self.0.num_idle())
+            .field("num_waiters", &self.0.num_waiters())
             .field("is_closed", &self.0.is_closed())
             .field("options", &self.0.options)
             .finish()
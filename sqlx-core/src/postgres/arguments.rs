@@ -1,4 +1,4 @@
-use std::ops::{Deref, DerefMut};
+use std::ops::{Deref, DerefMut, Range};
 
 use crate::arguments::Arguments;
 use crate::encode::{Encode, IsNull};
@@ -98,6 +98,10 @@ impl PgArguments {
             buffer[*offset..(*offset + 4)].copy_from_slice(&oid.to_be_bytes());
         }
 
+        // a type lookup only reads as far as the row it needs; the rest of its response
+        // must be consumed before the caller sends the query these arguments are bound to
+        conn.wait_until_ready().await?;
+
         Ok(())
     }
 }
@@ -153,6 +157,31 @@ impl PgArgumentBuffer {
         self.patches.push((offset, index, Box::new(callback)));
     }
 
+    // Removes a range of bytes from the buffer, dropping any patch or type hole within it
+    // and moving those after it along with their bytes
+    pub(crate) fn remove(&mut self, range: Range<usize>) {
+        let removed = range.end - range.start;
+
+        self.buffer.drain(range.clone());
+
+        self.patches
+            .retain(|(offset, _, _)| !range.contains(offset));
+
+        self.type_holes
+            .retain(|(offset, _)| !range.contains(offset));
+
+        for offset in self
+            .patches
+            .iter_mut()
+            .map(|(offset, _, _)| offset)
+            .chain(self.type_holes.iter_mut().map(|(offset, _)| offset))
+        {
+            if *offset >= range.end {
+                *offset -= removed;
+            }
+        }
+    }
+
     // Extends the inner buffer by enough space to have an OID
     // Remembers where the OID goes and type name for the OID
     pub(crate) fn patch_type_by_name(&mut self, type_name: &UStr) {
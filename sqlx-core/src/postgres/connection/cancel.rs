@@ -0,0 +1,149 @@
+use std::io;
+use std::sync::Arc;
+
+use futures_channel::oneshot;
+
+use crate::error::Error;
+use crate::postgres::connection::{stream::PgStream, tls};
+use crate::postgres::message::CancelRequest;
+use crate::postgres::PgConnectOptions;
+
+/// A handle that can be used to cancel the query currently being executed by a
+/// [`PgConnection`][crate::postgres::PgConnection].
+///
+/// Obtained from [`PgConnection::cancel_token`][crate::postgres::PgConnection::cancel_token].
+/// The token can be cloned and sent to another task; it remains valid for as long as the
+/// connection it was obtained from is open.
+///
+/// Cancellation opens a new connection to the server and sends a `CancelRequest` with the
+/// secret key of the backend. This is a request; the server may not be able to cancel the
+/// query, for example if it has already completed. If it is canceled, the query fails
+/// with an error with the code `57014` (`query_canceled`).
+///
+/// ```rust,no_run
+/// # use sqlx_core::postgres::PgConnection;
+/// # use sqlx_core::connection::Connection;
+/// # use sqlx_core::executor::Executor;
+/// # use sqlx_core::error::Error;
+/// #
+/// # #[cfg(feature = "runtime-async-std-native-tls")]
+/// # sqlx_rt::block_on::<_, Result<(), Error>>(async move {
+/// # let mut conn = PgConnection::connect("postgres:// ...").await?;
+/// let token = conn.cancel_token();
+///
+/// sqlx_rt::spawn(async move {
+///     sqlx_rt::sleep(std::time::Duration::from_secs(1)).await;
+///     let _ = token.cancel().await;
+/// });
+///
+/// // fails with `query_canceled`
+/// let res = conn.execute("SELECT pg_sleep(10)").await;
+/// # Ok(())
+/// # }).unwrap();
+/// ```
+#[derive(Debug, Clone)]
+pub struct PgCancelToken {
+    options: Arc<PgConnectOptions>,
+
+    // identifies the backend of the connection to the server
+    process_id: u32,
+    secret_key: u32,
+}
+
+impl PgCancelToken {
+    pub(super) fn new(options: &PgConnectOptions, process_id: u32, secret_key: u32) -> Self {
+        Self {
+            options: Arc::new(options.clone()),
+            process_id,
+            secret_key,
+        }
+    }
+
+    /// Request the cancellation of the query currently being executed by the connection.
+    ///
+    /// Returns once the server has received the request. Nothing happens if the connection
+    /// is not executing a query.
+    ///
+    /// Fails with a timeout error if the request is not delivered in the time set by
+    /// [`PgConnectOptions::cancel_timeout`].
+    pub async fn cancel(&self) -> Result<(), Error> {
+        match sqlx_rt::timeout(self.options.cancel_timeout, self.send_cancel_request()).await {
+            Ok(result) => result,
+
+            Err(_) => Err(Error::Io(io::Error::new(
+                io::ErrorKind::TimedOut,
+                "timed out sending cancel request",
+            ))),
+        }
+    }
+
+    async fn send_cancel_request(&self) -> Result<(), Error> {
+        // https://www.postgresql.org/docs/current/protocol-flow.html#id-1.10.5.7.9
+
+        let mut stream = PgStream::connect(&self.options).await?;
+
+        tls::maybe_upgrade(&mut stream, &self.options).await?;
+
+        stream
+            .send(CancelRequest {
+                process_id: self.process_id,
+                secret_key: self.secret_key,
+            })
+            .await?;
+
+        // the server does not respond; it closes the connection once it has signalled
+        // the backend. Wait for that so the request has been delivered when we return.
+        let _ = stream.recv_unchecked().await;
+
+        Ok(())
+    }
+}
+
+/// Cancels the query of a connection when dropped, unless it was disarmed first.
+///
+/// Held by the stream of results of a query while the server is still processing it;
+/// dropping that stream early then cancels the query on the server.
+pub(super) struct CancelOnDrop {
+    armed: Option<(PgCancelToken, oneshot::Sender<bool>)>,
+}
+
+impl CancelOnDrop {
+    /// Returns the guard and a receiver that completes once the guard has been disarmed,
+    /// or once the guard was dropped and the cancel request it sent has been delivered
+    /// (`true`) or has failed (`false`).
+    pub(super) fn new(token: PgCancelToken) -> (Self, oneshot::Receiver<bool>) {
+        let (tx, rx) = oneshot::channel();
+
+        (
+            Self {
+                armed: Some((token, tx)),
+            },
+            rx,
+        )
+    }
+
+    /// Returns a guard that does nothing when dropped.
+    pub(super) fn disarmed() -> Self {
+        Self { armed: None }
+    }
+
+    pub(super) fn disarm(&mut self) {
+        self.armed = None;
+    }
+}
+
+impl Drop for CancelOnDrop {
+    fn drop(&mut self) {
+        if let Some((token, done)) = self.armed.take() {
+            sqlx_rt::spawn(async move {
+                let result = token.cancel().await;
+
+                if let Err(error) = &result {
+                    log::warn!("failed to cancel query: {}", error);
+                }
+
+                let _ = done.send(result.is_ok());
+            });
+        }
+    }
+}
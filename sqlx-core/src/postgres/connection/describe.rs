@@ -232,7 +232,7 @@ WHERE rngtypid = $1
         // language=SQL
         let (oid,): (u32,) = query_as(
             "
-SELECT oid FROM pg_catalog.pg_type WHERE typname ILIKE $1
+SELECT oid FROM pg_catalog.pg_type WHERE lower(typname) = lower($1)
                 ",
         )
         .bind(name)
@@ -1,19 +1,88 @@
+use std::io;
+
 use hashbrown::HashMap;
+use rand::seq::SliceRandom;
+use rand::thread_rng;
 
 use crate::common::StatementCache;
+use crate::connection::Connection;
 use crate::error::Error;
+use crate::executor::Executor;
 use crate::io::Decode;
-use crate::postgres::connection::{sasl, stream::PgStream, tls};
+use crate::postgres::connection::{sasl, stream::PgStream, tls, PgCancelToken};
 use crate::postgres::message::{
     Authentication, BackendKeyData, MessageFormat, Password, ReadyForQuery, Startup,
 };
-use crate::postgres::{PgConnectOptions, PgConnection};
+use crate::postgres::{PgConnectOptions, PgConnection, PgTargetSessionAttrs};
+use crate::row::Row;
 
 // https://www.postgresql.org/docs/current/protocol-flow.html#id-1.10.5.7.3
 // https://www.postgresql.org/docs/current/protocol-flow.html#id-1.10.5.7.11
 
 impl PgConnection {
     pub(crate) async fn establish(options: &PgConnectOptions) -> Result<Self, Error> {
+        let mut hosts = vec![(options.host.clone(), options.port)];
+        hosts.extend(options.hosts.iter().cloned());
+
+        if options.load_balance_hosts {
+            hosts.shuffle(&mut thread_rng());
+        }
+
+        let mut error = None;
+
+        // each host is tried in turn until we have a connection to a server that matches
+        // `target_session_attrs`, as libpq does
+        for (host, port) in hosts {
+            let options = PgConnectOptions {
+                host,
+                port,
+                hosts: Vec::new(),
+                ..options.clone()
+            };
+
+            error = Some(match Self::establish_with_host(&options).await {
+                Ok(mut conn) => match conn.matches(options.target_session_attrs).await {
+                    Ok(true) => return Ok(conn),
+
+                    Ok(false) => {
+                        let _ = conn.close().await;
+
+                        // NOTE: an IO error is retried by the pool, which is what we want while
+                        //       a standby is being promoted
+                        Error::Io(io::Error::new(
+                            io::ErrorKind::Other,
+                            format!(
+                                "server at {}:{} does not match target_session_attrs={:?}",
+                                options.host, options.port, options.target_session_attrs
+                            ),
+                        ))
+                    }
+
+                    Err(e) => e,
+                },
+
+                Err(e) => e,
+            });
+        }
+
+        // there is always at least one host
+        Err(error.unwrap())
+    }
+
+    async fn matches(&mut self, attrs: PgTargetSessionAttrs) -> Result<bool, Error> {
+        if attrs == PgTargetSessionAttrs::Any {
+            return Ok(true);
+        }
+
+        let read_only: String = self
+            .fetch_one("SHOW transaction_read_only")
+            .await?
+            .try_get(0)?;
+
+        Ok((read_only == "on") == (attrs == PgTargetSessionAttrs::ReadOnly))
+    }
+
+    async fn establish_with_host(options: &PgConnectOptions) -> Result<Self, Error> {
         let mut stream = PgStream::connect(options).await?;
 
         // Upgrade to TLS if we were asked to and the server supports it
@@ -22,23 +91,38 @@ impl PgConnection {
         // To begin a session, a frontend opens a connection to the server
         // and sends a startup message.
 
+        let mut params = vec![
+            // Sets the display format for date and time values,
+            // as well as the rules for interpreting ambiguous date input values.
+            ("DateStyle", "ISO, MDY"),
+            // Sets the client-side encoding (character set).
+            // <https://www.postgresql.org/docs/devel/multibyte.html#MULTIBYTE-CHARSET-SUPPORTED>
+            ("client_encoding", "UTF8"),
+            // Sets the time zone for displaying and interpreting time stamps.
+            ("TimeZone", "UTC"),
+            // Adjust postgres to return precise values for floats
+            // NOTE: This is default in postgres 12+
+            ("extra_float_digits", "3"),
+        ];
+
+        if let Some(application_name) = &options.application_name {
+            params.push(("application_name", application_name));
+        }
+
+        if let Some(options) = &options.options {
+            // Command-line arguments for the backend, as with PGOPTIONS
+            params.push(("options", options));
+        }
+
+        for (key, value) in &options.startup_params {
+            params.push((key, value));
+        }
+
         stream
             .send(Startup {
                 username: Some(&options.username),
                 database: options.database.as_deref(),
-                params: &[
-                    // Sets the display format for date and time values,
-                    // as well as the rules for interpreting ambiguous date input values.
-                    ("DateStyle", "ISO, MDY"),
-                    // Sets the client-side encoding (character set).
-                    // <https://www.postgresql.org/docs/devel/multibyte.html#MULTIBYTE-CHARSET-SUPPORTED>
-                    ("client_encoding", "UTF8"),
-                    // Sets the time zone for displaying and interpreting time stamps.
-                    ("TimeZone", "UTC"),
-                    // Adjust postgres to return precise values for floats
-                    // NOTE: This is default in postgres 12+
-                    ("extra_float_digits", "3"),
-                ],
+                params: &params,
             })
             .await?;
 
@@ -127,8 +211,10 @@ impl PgConnection {
 
         Ok(PgConnection {
             stream,
-            process_id,
-            secret_key,
+            cancel_token: PgCancelToken::new(options, process_id, secret_key),
+            cancel_on_drop: options.cancel_on_drop,
+            pending_cancel: None,
+            expect_query_canceled: false,
             transaction_status,
             transaction_depth: 0,
             pending_ready_for_query_count: 0,
@@ -136,6 +222,7 @@ impl PgConnection {
             cache_statement: StatementCache::new(options.statement_cache_capacity),
             cache_type_oid: HashMap::new(),
             cache_type_info: HashMap::new(),
+            log_settings: options.log_settings.clone(),
         })
     }
 }
@@ -1,10 +1,12 @@
 use crate::describe::Describe;
 use crate::error::Error;
 use crate::executor::{Execute, Executor};
+use crate::logging::QueryLogger;
 use crate::postgres::message::{
     self, Bind, Close, CommandComplete, DataRow, MessageFormat, ParameterDescription, Parse, Query,
     RowDescription,
 };
+use crate::postgres::pipeline::{PgPipeline, PgPipelineQuery};
 use crate::postgres::statement::PgStatementMetadata;
 use crate::postgres::type_info::PgType;
 use crate::postgres::{
@@ -190,20 +192,43 @@ impl PgConnection {
         Ok(statement)
     }
 
-    async fn run(
-        &mut self,
-        query: &str,
+    fn write_bind_execute(&mut self, statement: u32, arguments: &PgArguments, limit: u8) {
+        // bind to attach the arguments to the statement and create a portal
+        self.stream.write(Bind {
+            portal: None,
+            statement,
+            formats: &[PgValueFormat::Binary],
+            num_params: arguments.types.len() as i16,
+            params: &*arguments.buffer,
+            result_formats: &[PgValueFormat::Binary],
+        });
+
+        // executes the portal up to the passed limit
+        // the protocol-level limit acts nearly identically to the `LIMIT` in SQL
+        self.stream.write(message::Execute {
+            portal: None,
+            limit: limit.into(),
+        });
+    }
+
+    async fn run<'e, 'c: 'e, 'q: 'e>(
+        &'c mut self,
+        query: &'q str,
         arguments: Option<PgArguments>,
         limit: u8,
         persistent: bool,
         metadata_opt: Option<Arc<PgStatementMetadata>>,
-    ) -> Result<impl Stream<Item = Result<Either<PgDone, PgRow>, Error>> + '_, Error> {
+    ) -> Result<impl Stream<Item = Result<Either<PgDone, PgRow>, Error>> + 'e, Error> {
+        let mut logger = QueryLogger::new(query, self.log_settings.clone());
+
         // before we continue, wait until we are "ready" to accept more queries
         self.wait_until_ready().await?;
 
         let mut metadata: Arc<PgStatementMetadata>;
 
         let format = if let Some(mut arguments) = arguments {
+            logger.parameters(&arguments.types);
+
             // prepare the statement if this our first time executing it
             // always return the statement ID here
             let (statement, metadata_) = self
@@ -215,28 +240,12 @@ impl PgConnection {
             // patch holes created during encoding
             arguments.apply_patches(self, &metadata.parameters).await?;
 
-            // bind to attach the arguments to the statement and create a portal
-            self.stream.write(Bind {
-                portal: None,
-                statement,
-                formats: &[PgValueFormat::Binary],
-                num_params: arguments.types.len() as i16,
-                params: &*arguments.buffer,
-                result_formats: &[PgValueFormat::Binary],
-            });
-
-            // executes the portal up to the passed limit
-            // the protocol-level limit acts nearly identically to the `LIMIT` in SQL
-            self.stream.write(message::Execute {
-                portal: None,
-                limit: limit.into(),
-            });
+            self.write_bind_execute(statement, &arguments, limit);
 
             // finally, [Sync] asks postgres to process the messages that we sent and respond with
             // a [ReadyForQuery] message when it's completely done. Theoretically, we could send
             // dozens of queries before a [Sync] and postgres can handle that. Execution on the server
-            // is still serial but it would reduce round-trips. Some kind of builder pattern that is
-            // termed batching might suit this.
+            // is still serial but it would reduce round-trips. See [PgPipeline] for that.
             self.write_sync();
 
             // prepared statements are binary
@@ -255,9 +264,18 @@ impl PgConnection {
 
         self.stream.flush().await?;
 
+        let mut cancel = self.arm_cancel_on_drop();
+
         Ok(try_stream! {
             loop {
-                let message = self.stream.recv().await?;
+                let message = match self.stream.recv().await {
+                    Ok(message) => message,
+                    Err(error) => {
+                        // the server has stopped executing the query
+                        cancel.disarm();
+                        return Err(error);
+                    }
+                };
 
                 match message.format {
                     MessageFormat::BindComplete
@@ -294,6 +312,8 @@ impl PgConnection {
                     }
 
                     MessageFormat::DataRow => {
+                        logger.increment_rows();
+
                         // one of the set of rows returned by a SELECT, FETCH, etc query
                         let data: DataRow = message.decode()?;
                         let row = PgRow {
@@ -302,11 +322,18 @@ impl PgConnection {
                             metadata: Arc::clone(&metadata),
                         };
 
+                        if limit != 0 && format == PgValueFormat::Binary {
+                            // the portal is suspended once the limit is reached; the query is
+                            // not canceled when the rest of its results are not read
+                            cancel.disarm();
+                        }
+
                         r#yield!(Either::Right(row));
                     }
 
                     MessageFormat::ReadyForQuery => {
                         // processing of the query string is complete
+                        cancel.disarm();
                         self.handle_ready_for_query(message)?;
                         break;
                     }
@@ -323,6 +350,210 @@ impl PgConnection {
             Ok(())
         })
     }
+
+    pub(crate) async fn run_pipeline<'e, 'c: 'e, 'q: 'e>(
+        &'c mut self,
+        queries: Vec<PgPipelineQuery<'q>>,
+    ) -> Result<impl Stream<Item = Result<Either<PgDone, PgRow>, Error>> + 'e, Error> {
+        // each query is logged once its own results have been received
+        let mut loggers = Vec::with_capacity(queries.len());
+
+        // before we continue, wait until we are "ready" to accept more queries
+        self.wait_until_ready().await?;
+
+        // every statement must be prepared (and every argument patched) before we queue the
+        // first execution as both may need to make round-trips of their own
+        let mut prepared = Vec::with_capacity(queries.len());
+
+        for query in queries {
+            let mut arguments = query.arguments.unwrap_or_default();
+
+            let mut logger = QueryLogger::new(query.sql, self.log_settings.clone());
+            logger.parameters(&arguments.types);
+            loggers.push(logger);
+
+            let (statement, metadata) = self
+                .get_or_prepare(
+                    query.sql,
+                    &arguments.types,
+                    query.persistent,
+                    query.metadata,
+                )
+                .await?;
+
+            arguments.apply_patches(self, &metadata.parameters).await?;
+
+            prepared.push((query.sql, query.persistent, statement, metadata, arguments));
+        }
+
+        // preparing a statement late in the pipeline may have evicted (and closed) one that
+        // was prepared earlier, even if the same query was then cached again under another
+        // statement; those are prepared again, outside of the cache, and closed once the
+        // pipeline has run
+        let mut uncached = Vec::new();
+
+        if self.cache_statement.is_enabled() {
+            for (sql, persistent, statement, metadata, arguments) in &mut prepared {
+                if !*persistent {
+                    continue;
+                }
+
+                let cached = self.cache_statement.get_mut(sql).map(|(id, _)| *id);
+
+                if cached != Some(*statement) {
+                    let (id, _) =
+                        prepare(self, sql, &arguments.types, Some(Arc::clone(metadata))).await?;
+
+                    *statement = id;
+                    uncached.push(id);
+                }
+            }
+        }
+
+        let mut metadata = Vec::with_capacity(prepared.len());
+
+        for (_, _, statement, metadata_, arguments) in prepared {
+            self.write_bind_execute(statement, &arguments, 0);
+            metadata.push(metadata_);
+        }
+
+        // a single [Sync] closes the pipeline; if any query fails, the server skips the
+        // remaining queries and rolls back the implicit transaction they ran in
+        self.write_sync();
+
+        // these follow the [Sync] so they are still run if the pipeline fails; if the results
+        // are dropped, the responses are consumed when the connection is next made ready
+        for id in &uncached {
+            self.stream.write(Close::Statement(*id));
+        }
+
+        if !uncached.is_empty() {
+            self.write_sync();
+        }
+
+        self.stream.flush().await?;
+
+        let mut cancel = self.arm_cancel_on_drop();
+
+        Ok(try_stream! {
+            // index of the query whose results we are receiving
+            let mut index = 0;
+
+            let mut loggers = loggers.into_iter();
+            let mut logger = loggers.next();
+
+            loop {
+                let message = match self.stream.recv().await {
+                    Ok(message) => message,
+                    Err(error) => {
+                        // the server has stopped executing the query
+                        cancel.disarm();
+                        return Err(error);
+                    }
+                };
+
+                match message.format {
+                    MessageFormat::BindComplete => {
+                        // harmless messages to ignore
+                    }
+
+                    MessageFormat::CommandComplete => {
+                        // a SQL command completed normally
+                        let cc: CommandComplete = message.decode()?;
+
+                        r#yield!(Either::Left(PgDone {
+                            rows_affected: cc.rows_affected(),
+                        }));
+
+                        index += 1;
+                        logger = loggers.next();
+                    }
+
+                    MessageFormat::EmptyQueryResponse => {
+                        // an empty query string; still completes its entry in the pipeline
+                        r#yield!(Either::Left(PgDone { rows_affected: 0 }));
+
+                        index += 1;
+                        logger = loggers.next();
+                    }
+
+                    MessageFormat::DataRow => {
+                        if let Some(logger) = &mut logger {
+                            logger.increment_rows();
+                        }
+
+                        // one of the set of rows returned by the current query
+                        let data: DataRow = message.decode()?;
+                        let metadata = metadata.get(index).ok_or_else(|| {
+                            err_protocol!("pipeline: received more results than queries")
+                        })?;
+
+                        let row = PgRow {
+                            data,
+                            format: PgValueFormat::Binary,
+                            metadata: Arc::clone(metadata),
+                        };
+
+                        r#yield!(Either::Right(row));
+                    }
+
+                    MessageFormat::ReadyForQuery => {
+                        // processing of the pipeline is complete
+                        cancel.disarm();
+                        self.handle_ready_for_query(message)?;
+
+                        if !uncached.is_empty() {
+                            self.wait_for_close_complete(uncached.len()).await?;
+                            self.recv_ready_for_query().await?;
+                        }
+
+                        break;
+                    }
+
+                    _ => {
+                        Err(err_protocol!(
+                            "pipeline: unexpected message: {:?}",
+                            message.format
+                        ))?;
+                    }
+                }
+            }
+
+            Ok(())
+        })
+    }
+}
+
+impl PgConnection {
+    /// Execute one prepared statement once for each set of arguments and return the rows
+    /// affected by each execution, in order.
+    ///
+    /// Every execution is sent to the server in a single write, with one synchronization
+    /// point at the end (see [`PgPipeline`]). Any rows returned by the statement are discarded.
+    ///
+    /// If an execution fails, its error is returned and the remaining sets of arguments are
+    /// skipped. Outside of a transaction, the whole batch runs in one implicit transaction
+    /// which is rolled back on failure.
+    pub async fn execute_batch<I>(
+        &mut self,
+        query: &str,
+        arguments: I,
+    ) -> Result<Vec<PgDone>, Error>
+    where
+        I: IntoIterator<Item = PgArguments>,
+    {
+        let mut pipeline = PgPipeline::new();
+
+        for arguments in arguments {
+            pipeline.push((query, Some(arguments)));
+        }
+
+        if pipeline.is_empty() {
+            return Ok(Vec::new());
+        }
+
+        pipeline.execute(self).await
+    }
 }
 
 impl<'c> Executor<'c> for &'c mut PgConnection {
@@ -1,12 +1,14 @@
 use std::fmt::{self, Debug, Formatter};
+use std::io;
 use std::sync::Arc;
 
+use futures_channel::oneshot;
 use futures_core::future::BoxFuture;
 use futures_util::{FutureExt, TryFutureExt};
 use hashbrown::HashMap;
 
 use crate::common::StatementCache;
-use crate::connection::Connection;
+use crate::connection::{Connection, LogSettings};
 use crate::error::Error;
 use crate::executor::Executor;
 use crate::ext::ustr::UStr;
@@ -19,6 +21,7 @@ use crate::postgres::statement::PgStatementMetadata;
 use crate::postgres::{PgConnectOptions, PgTypeInfo, Postgres};
 use crate::transaction::Transaction;
 
+mod cancel;
 pub(crate) mod describe;
 mod establish;
 mod executor;
@@ -26,6 +29,9 @@ mod sasl;
 mod stream;
 mod tls;
 
+use cancel::CancelOnDrop;
+pub use cancel::PgCancelToken;
+
 /// A connection to a PostgreSQL database.
 pub struct PgConnection {
     // underlying TCP or UDS stream,
@@ -33,15 +39,19 @@ pub struct PgConnection {
     // wrapped in a buffered stream
     pub(crate) stream: PgStream,
 
-    // process id of this backend
+    // process id and secret key of this backend
     // used to send cancel requests
-    #[allow(dead_code)]
-    process_id: u32,
+    cancel_token: PgCancelToken,
 
-    // secret key of this backend
-    // used to send cancel requests
-    #[allow(dead_code)]
-    secret_key: u32,
+    // cancel the query being executed when its stream of results is dropped early
+    cancel_on_drop: bool,
+
+    // completes once a cancel request sent for a dropped query has been delivered
+    pending_cancel: Option<oneshot::Receiver<bool>>,
+
+    // set when a `COPY` or query was abandoned and then canceled on the server; the
+    // `query_canceled` error it fails with is discarded the next time we wait until ready
+    pub(crate) expect_query_canceled: bool,
 
     // sequence of statement IDs for use in preparing statements
     // in PostgreSQL, the statement is prepared to a user-supplied identifier
@@ -60,28 +70,91 @@ pub struct PgConnection {
     // current transaction status
     transaction_status: TransactionStatus,
     pub(crate) transaction_depth: usize,
+
+    log_settings: LogSettings,
 }
 
 impl PgConnection {
+    /// Returns a token that can be used to cancel the query currently being executed by
+    /// this connection, from another task.
+    pub fn cancel_token(&self) -> PgCancelToken {
+        self.cancel_token.clone()
+    }
+
+    // returns a guard to hold until the server has finished executing the query that was
+    // just sent; if enabled, dropping it early cancels the query
+    fn arm_cancel_on_drop(&mut self) -> CancelOnDrop {
+        if !self.cancel_on_drop {
+            return CancelOnDrop::disarmed();
+        }
+
+        let (guard, delivered) = CancelOnDrop::new(self.cancel_token.clone());
+        self.pending_cancel = Some(delivered);
+
+        guard
+    }
+
     // will return when the connection is ready for another query
-    async fn wait_until_ready(&mut self) -> Result<(), Error> {
+    pub(crate) async fn wait_until_ready(&mut self) -> Result<(), Error> {
+        if let Some(pending_cancel) = self.pending_cancel.take() {
+            // a query was canceled when its results were dropped; the request must have
+            // been delivered before we send anything else, or it could cancel the next query
+            match pending_cancel.await {
+                Ok(true) => {
+                    self.expect_query_canceled = true;
+                }
+
+                // the query may still be running, for as long as it takes; rather than wait
+                // for it, the connection is closed
+                Ok(false) => {
+                    let _ = self.stream.shutdown();
+
+                    return Err(Error::Io(io::Error::new(
+                        io::ErrorKind::Other,
+                        "connection closed after failing to cancel a dropped query",
+                    )));
+                }
+
+                // the guard was disarmed; the query had completed
+                Err(_) => {}
+            }
+        }
+
         if !self.stream.wbuf.is_empty() {
             self.stream.flush().await?;
         }
 
         while self.pending_ready_for_query_count > 0 {
-            let message = self.stream.recv().await?;
-
+            let message = match self.stream.recv().await {
+                Ok(message) => message,
+
+                // the error of a command that was canceled after it was abandoned, such as
+                // a `COPY` that was aborted by dropping [PgCopyIn]; the server will still
+                // follow it with a ReadyForQuery
+                Err(Error::Database(error))
+                    if self.expect_query_canceled && error.code().as_deref() == Some("57014") =>
+                {
+                    self.expect_query_canceled = false;
+                    continue;
+                }
+
+                Err(error) => return Err(error),
+            };
+
+            // there may be more than one [Sync] pending, as when a pipeline is followed by
+            // the [Close] of its statements
             if let MessageFormat::ReadyForQuery = message.format {
                 self.handle_ready_for_query(message)?;
-                break;
             }
         }
 
+        // the command may have completed before it could be canceled
+        self.expect_query_canceled = false;
+
         Ok(())
     }
 
-    async fn recv_ready_for_query(&mut self) -> Result<(), Error> {
+    pub(crate) async fn recv_ready_for_query(&mut self) -> Result<(), Error> {
         let r: ReadyForQuery = self
             .stream
             .recv_expect(MessageFormat::ReadyForQuery)
@@ -1,6 +1,6 @@
 use std::ops::{Deref, DerefMut};
 
-use bytes::{Buf, Bytes};
+use bytes::Buf;
 use futures_channel::mpsc::UnboundedSender;
 use futures_util::SinkExt;
 use log::Level;
@@ -73,12 +73,16 @@ impl PgStream {
     pub(crate) async fn recv_unchecked(&mut self) -> Result<Message, Error> {
         // all packets in postgres start with a 5-byte header
         // this header contains the message type and the total length of the message
-        let mut header: Bytes = self.inner.read(5).await?;
+        let mut header = self.inner.peek_raw(5).await?;
 
         let format = MessageFormat::try_from_u8(header.get_u8())?;
         let size = (header.get_u32() - 4) as usize;
 
-        let contents = self.inner.read(size).await?;
+        // the header is only consumed together with the contents; if this future is dropped
+        // while waiting for the rest of the message, the next receive starts over from it
+        let mut contents = self.inner.read_raw(5 + size).await?;
+        contents.advance(5);
+        let contents = contents.freeze();
 
         Ok(Message { format, contents })
     }
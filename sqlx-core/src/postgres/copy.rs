@@ -0,0 +1,308 @@
+use std::ops::{Deref, DerefMut};
+
+use bytes::Bytes;
+use futures_core::stream::BoxStream;
+use sqlx_rt::{AsyncRead, AsyncReadExt};
+
+use crate::error::Error;
+use crate::ext::async_stream::TryAsyncStream;
+use crate::pool::{Pool, PoolConnection};
+use crate::postgres::message::{
+    CommandComplete, CopyData, CopyDone, CopyFail, CopyResponse, MessageFormat, Query,
+};
+use crate::postgres::{PgConnection, PgValueFormat, Postgres};
+
+impl PgConnection {
+    /// Issue a `COPY FROM STDIN` statement and transition the connection to streaming data
+    /// to Postgres. This is a more efficient way to import data into Postgres as compared to
+    /// `INSERT` but requires one of a few specific data formats (text/CSV/binary).
+    ///
+    /// If `statement` is anything other than a `COPY ... FROM STDIN ...` command, an error is
+    /// returned.
+    ///
+    /// Command examples and accepted formats for `COPY` data are shown here:
+    /// https://www.postgresql.org/docs/current/sql-copy.html
+    ///
+    /// ### Note
+    /// [`PgCopyIn::finish`] or [`PgCopyIn::abort`] should be called when finished. If the
+    /// [`PgCopyIn`] is dropped instead, the `COPY` is aborted the next time the connection is used.
+    pub async fn copy_in_raw(&mut self, statement: &str) -> Result<PgCopyIn<&mut Self>, Error> {
+        PgCopyIn::begin(self, statement).await
+    }
+
+    /// Issue a `COPY TO STDOUT` statement and transition the connection to streaming data
+    /// from Postgres. This is a more efficient way to export data from Postgres but
+    /// arrives in chunks of one of a few data formats (text/CSV/binary).
+    ///
+    /// If `statement` is anything other than a `COPY ... TO STDOUT ...` command,
+    /// an error is returned.
+    ///
+    /// Note that once this process has begun, unless you read the stream to completion,
+    /// it can only be canceled in two ways:
+    ///
+    /// 1. by closing the connection, or:
+    /// 2. by using another connection to kill the server process that is sending the data as
+    ///    shown [in this StackOverflow answer](https://stackoverflow.com/a/35319598).
+    ///
+    /// If you don't read the stream to completion, the next time the connection is used it will
+    /// need to read and discard all the remaining queued data, which could take some time.
+    ///
+    /// Command examples and accepted formats for `COPY` data are shown here:
+    /// https://www.postgresql.org/docs/current/sql-copy.html
+    #[allow(clippy::needless_lifetimes)]
+    pub async fn copy_out_raw<'c>(
+        &'c mut self,
+        statement: &str,
+    ) -> Result<BoxStream<'c, Result<Bytes, Error>>, Error> {
+        pg_begin_copy_out(self, statement).await
+    }
+}
+
+impl Pool<Postgres> {
+    /// Issue a `COPY FROM STDIN` statement and begin streaming data to Postgres.
+    /// This is a more efficient way to import data into Postgres as compared to
+    /// `INSERT` but requires one of a few specific data formats (text/CSV/binary).
+    ///
+    /// A single connection will be checked out for the duration.
+    ///
+    /// If `statement` is anything other than a `COPY ... FROM STDIN ...` command, an error is
+    /// returned.
+    ///
+    /// Command examples and accepted formats for `COPY` data are shown here:
+    /// https://www.postgresql.org/docs/current/sql-copy.html
+    ///
+    /// ### Note
+    /// [`PgCopyIn::finish`] or [`PgCopyIn::abort`] should be called when finished. If the
+    /// [`PgCopyIn`] is dropped instead, the `COPY` is aborted the next time the connection is used.
+    pub async fn copy_in_raw(
+        &self,
+        statement: &str,
+    ) -> Result<PgCopyIn<PoolConnection<Postgres>>, Error> {
+        PgCopyIn::begin(self.acquire().await?, statement).await
+    }
+
+    /// Issue a `COPY TO STDOUT` statement and begin streaming data
+    /// from Postgres. This is a more efficient way to export data from Postgres but
+    /// arrives in chunks of one of a few data formats (text/CSV/binary).
+    ///
+    /// If `statement` is anything other than a `COPY ... TO STDOUT ...` command,
+    /// an error is returned.
+    ///
+    /// A single connection will be checked out for the duration.
+    ///
+    /// If you don't read the stream to completion, the next time the connection is used it will
+    /// need to read and discard all the remaining queued data, which could take some time.
+    ///
+    /// Command examples and accepted formats for `COPY` data are shown here:
+    /// https://www.postgresql.org/docs/current/sql-copy.html
+    pub async fn copy_out_raw(
+        &self,
+        statement: &str,
+    ) -> Result<BoxStream<'static, Result<Bytes, Error>>, Error> {
+        pg_begin_copy_out(self.acquire().await?, statement).await
+    }
+}
+
+/// A connection in streaming `COPY FROM STDIN` mode.
+///
+/// Created by [`PgConnection::copy_in_raw`] or [`Pool::copy_in_raw`].
+///
+/// ### Note
+/// [`PgCopyIn::finish`] or [`PgCopyIn::abort`] should be called when finished. If this is
+/// dropped instead, the `COPY` is aborted the next time the connection is used.
+#[must_use = "the `COPY` is aborted if `.finish()` is not called"]
+pub struct PgCopyIn<C: DerefMut<Target = PgConnection>> {
+    conn: Option<C>,
+    response: CopyResponse,
+}
+
+impl<C: DerefMut<Target = PgConnection>> PgCopyIn<C> {
+    async fn begin(mut conn: C, statement: &str) -> Result<Self, Error> {
+        conn.wait_until_ready().await?;
+
+        // a simple query will trigger a ReadyForQuery once the copy is complete
+        conn.stream.send(Query(statement)).await?;
+        conn.pending_ready_for_query_count += 1;
+
+        let response: CopyResponse = conn
+            .stream
+            .recv_expect(MessageFormat::CopyInResponse)
+            .await?;
+
+        Ok(PgCopyIn {
+            conn: Some(conn),
+            response,
+        })
+    }
+
+    /// Returns `true` if Postgres is expecting data in text or CSV format.
+    pub fn is_textual(&self) -> bool {
+        self.response.format == PgValueFormat::Text
+    }
+
+    /// Returns the number of columns expected in the input.
+    pub fn num_columns(&self) -> usize {
+        self.response.column_formats.len()
+    }
+
+    /// Check if a column is expecting data in text format (`true`) or binary format (`false`).
+    ///
+    /// ### Panics
+    /// If `column` is out of range according to [`.num_columns()`][Self::num_columns].
+    pub fn column_is_textual(&self, column: usize) -> bool {
+        self.response.column_formats[column] == PgValueFormat::Text
+    }
+
+    /// Send a chunk of `COPY` data.
+    ///
+    /// If you're copying data from an `AsyncRead`, maybe consider [Self::read_from] instead.
+    ///
+    /// The server does not acknowledge each chunk; if it rejects the data, the error is
+    /// returned from [`.finish()`][Self::finish].
+    pub async fn send(&mut self, data: impl Deref<Target = [u8]>) -> Result<&mut Self, Error> {
+        let conn = self.conn.as_deref_mut().expect("send: conn taken");
+
+        conn.stream.write(CopyData(data));
+        conn.stream.flush().await?;
+
+        Ok(self)
+    }
+
+    /// Copy data from `source` to the database, one chunk at a time.
+    ///
+    /// `source` will be read to the end.
+    pub async fn read_from(
+        &mut self,
+        mut source: impl AsyncRead + Unpin,
+    ) -> Result<&mut Self, Error> {
+        let conn = self.conn.as_deref_mut().expect("read_from: conn taken");
+        let mut chunk = vec![0; 8192];
+
+        loop {
+            let read = source.read(&mut chunk).await?;
+
+            if read == 0 {
+                break;
+            }
+
+            conn.stream.write(CopyData(&chunk[..read]));
+            conn.stream.flush().await?;
+        }
+
+        Ok(self)
+    }
+
+    /// Signal that the `COPY` process should be aborted and any data received should be discarded.
+    ///
+    /// The given message can be used for indicating the reason for the abort in the database logs.
+    ///
+    /// The server is expected to respond with an error, so only _unexpected_ errors are returned.
+    pub async fn abort(mut self, msg: impl Into<String>) -> Result<(), Error> {
+        let mut conn = self.conn.take().expect("abort: conn taken");
+        let message = msg.into();
+
+        conn.stream.send(CopyFail { message: &message }).await?;
+
+        match conn.stream.recv().await {
+            // the server responds to a CopyFail with an ErrorResponse with the
+            // code `query_canceled`, followed by a ReadyForQuery
+            Err(Error::Database(e)) if e.code().as_deref() == Some("57014") => {
+                conn.recv_ready_for_query().await?;
+
+                Ok(())
+            }
+
+            Err(e) => Err(e),
+
+            Ok(message) => Err(err_protocol!(
+                "expecting ErrorResponse but received {:?}",
+                message.format
+            )),
+        }
+    }
+
+    /// Signal that the `COPY` process is complete.
+    ///
+    /// The number of rows affected is returned.
+    ///
+    /// If the server rejected any of the data that was sent, the error is returned here
+    /// and the connection is left ready for the next query.
+    pub async fn finish(mut self) -> Result<u64, Error> {
+        let mut conn = self.conn.take().expect("finish: conn taken");
+
+        conn.stream.send(CopyDone).await?;
+
+        let cc: CommandComplete = conn
+            .stream
+            .recv_expect(MessageFormat::CommandComplete)
+            .await?;
+
+        conn.recv_ready_for_query().await?;
+
+        Ok(cc.rows_affected())
+    }
+}
+
+impl<C: DerefMut<Target = PgConnection>> Drop for PgCopyIn<C> {
+    fn drop(&mut self) {
+        if let Some(mut conn) = self.conn.take() {
+            // the server responds with an error and a ReadyForQuery; both are consumed
+            // when the connection is next made ready
+            conn.stream.write(CopyFail {
+                message: "PgCopyIn dropped without calling finish() or abort()",
+            });
+
+            conn.expect_query_canceled = true;
+        }
+    }
+}
+
+async fn pg_begin_copy_out<'c, C: DerefMut<Target = PgConnection> + Send + 'c>(
+    mut conn: C,
+    statement: &str,
+) -> Result<BoxStream<'c, Result<Bytes, Error>>, Error> {
+    conn.wait_until_ready().await?;
+
+    // a simple query will trigger a ReadyForQuery once the copy is complete
+    conn.stream.send(Query(statement)).await?;
+    conn.pending_ready_for_query_count += 1;
+
+    let _: CopyResponse = conn
+        .stream
+        .recv_expect(MessageFormat::CopyOutResponse)
+        .await?;
+
+    let stream: TryAsyncStream<'c, Bytes> = try_stream! {
+        loop {
+            let message = conn.stream.recv().await?;
+
+            match message.format {
+                MessageFormat::CopyData => {
+                    r#yield!(message.contents);
+                }
+
+                MessageFormat::CopyDone => {
+                    let _: CommandComplete = conn
+                        .stream
+                        .recv_expect(MessageFormat::CommandComplete)
+                        .await?;
+
+                    conn.recv_ready_for_query().await?;
+
+                    break;
+                }
+
+                _ => {
+                    Err(err_protocol!(
+                        "copy_out: unexpected message: {:?}",
+                        message.format
+                    ))?;
+                }
+            }
+        }
+
+        Ok(())
+    };
+
+    Ok(Box::pin(stream))
+}
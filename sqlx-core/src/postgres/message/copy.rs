@@ -0,0 +1,130 @@
+use std::ops::Deref;
+
+use bytes::{Buf, Bytes};
+
+use crate::error::Error;
+use crate::io::{BufMutExt, Decode, Encode};
+use crate::postgres::io::PgBufMutExt;
+use crate::postgres::PgValueFormat;
+
+/// The backend is ready to receive (`CopyInResponse`) or about to send (`CopyOutResponse`)
+/// the data of a `COPY` command.
+#[derive(Debug)]
+pub struct CopyResponse {
+    /// The overall format of the copy; text (`0`) or binary (`1`).
+    pub format: PgValueFormat,
+
+    /// The format of each column. All of these must be text if the overall
+    /// format is text.
+    pub column_formats: Vec<PgValueFormat>,
+}
+
+impl Decode<'_> for CopyResponse {
+    fn decode_with(mut buf: Bytes, _: ()) -> Result<Self, Error> {
+        let format = decode_format(buf.get_i8() as i16)?;
+
+        let cnt = buf.get_i16();
+        let mut column_formats = Vec::with_capacity(cnt.max(0) as usize);
+
+        for _ in 0..cnt {
+            column_formats.push(decode_format(buf.get_i16())?);
+        }
+
+        Ok(Self {
+            format,
+            column_formats,
+        })
+    }
+}
+
+fn decode_format(code: i16) -> Result<PgValueFormat, Error> {
+    match code {
+        0 => Ok(PgValueFormat::Text),
+        1 => Ok(PgValueFormat::Binary),
+
+        _ => Err(err_protocol!("unknown copy format code: {}", code)),
+    }
+}
+
+/// A chunk of `COPY` data; sent in either direction.
+#[derive(Debug)]
+pub struct CopyData<B>(pub B);
+
+impl<B: Deref<Target = [u8]>> Encode<'_> for CopyData<B> {
+    fn encode_with(&self, buf: &mut Vec<u8>, _: ()) {
+        buf.reserve(self.0.len() + 5);
+        buf.push(b'd');
+
+        buf.put_length_prefixed(|buf| {
+            buf.extend_from_slice(&self.0);
+        });
+    }
+}
+
+/// Marks the successful end of `COPY` data; sent in either direction.
+#[derive(Debug)]
+pub struct CopyDone;
+
+impl Encode<'_> for CopyDone {
+    fn encode_with(&self, buf: &mut Vec<u8>, _: ()) {
+        buf.push(b'c');
+        buf.extend(&4_i32.to_be_bytes());
+    }
+}
+
+/// Aborts a `COPY FROM STDIN` operation, causing the backend to fail the command with
+/// the given message.
+#[derive(Debug)]
+pub struct CopyFail<'a> {
+    pub message: &'a str,
+}
+
+impl Encode<'_> for CopyFail<'_> {
+    fn encode_with(&self, buf: &mut Vec<u8>, _: ()) {
+        buf.push(b'f');
+
+        buf.put_length_prefixed(|buf| {
+            buf.put_str_nul(self.message);
+        });
+    }
+}
+
+#[test]
+fn test_decode_copy_response() {
+    const DATA: &[u8] = b"\x00\x00\x02\x00\x00\x00\x00";
+
+    let m = CopyResponse::decode(Bytes::from_static(DATA)).unwrap();
+
+    assert_eq!(m.format, PgValueFormat::Text);
+    assert_eq!(m.column_formats, vec![PgValueFormat::Text; 2]);
+}
+
+#[test]
+fn test_decode_binary_copy_response() {
+    const DATA: &[u8] = b"\x01\x00\x01\x00\x01";
+
+    let m = CopyResponse::decode(Bytes::from_static(DATA)).unwrap();
+
+    assert_eq!(m.format, PgValueFormat::Binary);
+    assert_eq!(m.column_formats, vec![PgValueFormat::Binary]);
+}
+
+#[test]
+fn test_encode_copy_data() {
+    const EXPECTED: &[u8] = b"d\0\0\0\x0c1\tHello\n";
+
+    let mut buf = Vec::new();
+    CopyData(&b"1\tHello\n"[..]).encode(&mut buf);
+
+    assert_eq!(buf, EXPECTED);
+}
+
+#[test]
+fn test_encode_copy_fail() {
+    const EXPECTED: &[u8] = b"f\0\0\0\x0aabort\0";
+
+    let mut buf = Vec::new();
+    CopyFail { message: "abort" }.encode(&mut buf);
+
+    assert_eq!(buf, EXPECTED);
+}
@@ -6,8 +6,10 @@ use crate::io::Decode;
 mod authentication;
 mod backend_key_data;
 mod bind;
+mod cancel_request;
 mod close;
 mod command_complete;
+mod copy;
 mod data_row;
 mod describe;
 mod execute;
@@ -29,8 +31,10 @@ mod terminate;
 pub use authentication::{Authentication, AuthenticationSasl};
 pub use backend_key_data::BackendKeyData;
 pub use bind::Bind;
+pub use cancel_request::CancelRequest;
 pub use close::Close;
 pub use command_complete::CommandComplete;
+pub use copy::{CopyData, CopyDone, CopyFail, CopyResponse};
 pub use data_row::DataRow;
 pub use describe::Describe;
 pub use execute::Execute;
@@ -57,6 +61,10 @@ pub enum MessageFormat {
     BindComplete,
     CloseComplete,
     CommandComplete,
+    CopyData,
+    CopyDone,
+    CopyInResponse,
+    CopyOutResponse,
     DataRow,
     EmptyQueryResponse,
     ErrorResponse,
@@ -97,6 +105,8 @@ impl MessageFormat {
             b'3' => MessageFormat::CloseComplete,
             b'C' => MessageFormat::CommandComplete,
             b'D' => MessageFormat::DataRow,
+            b'G' => MessageFormat::CopyInResponse,
+            b'H' => MessageFormat::CopyOutResponse,
             b'E' => MessageFormat::ErrorResponse,
             b'I' => MessageFormat::EmptyQueryResponse,
             b'A' => MessageFormat::NotificationResponse,
@@ -106,6 +116,8 @@ impl MessageFormat {
             b'S' => MessageFormat::ParameterStatus,
             b'T' => MessageFormat::RowDescription,
             b'Z' => MessageFormat::ReadyForQuery,
+            b'c' => MessageFormat::CopyDone,
+            b'd' => MessageFormat::CopyData,
             b'n' => MessageFormat::NoData,
             b's' => MessageFormat::PortalSuspended,
             b't' => MessageFormat::ParameterDescription,
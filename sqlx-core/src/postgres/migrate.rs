@@ -2,7 +2,7 @@ use crate::connection::{ConnectOptions, Connection};
 use crate::error::Error;
 use crate::executor::Executor;
 use crate::migrate::MigrateError;
-use crate::migrate::Migration;
+use crate::migrate::{AppliedMigration, AppliedMigrationRow, Migration};
 use crate::migrate::{Migrate, MigrateDatabase};
 use crate::postgres::{PgConnectOptions, PgConnection, Postgres};
 use crate::query::query;
@@ -107,6 +107,17 @@ CREATE TABLE IF NOT EXISTS _sqlx_migrations (
         })
     }
 
+    fn migrations_table_exists(&mut self) -> BoxFuture<'_, Result<bool, MigrateError>> {
+        Box::pin(async move {
+            // language=SQL
+            let exists: bool = query_scalar("SELECT to_regclass('_sqlx_migrations') IS NOT NULL")
+                .fetch_one(self)
+                .await?;
+
+            Ok(exists)
+        })
+    }
+
     fn version(&mut self) -> BoxFuture<'_, Result<Option<(i64, bool)>, MigrateError>> {
         Box::pin(async move {
             // language=SQL
@@ -120,6 +131,25 @@ CREATE TABLE IF NOT EXISTS _sqlx_migrations (
         })
     }
 
+    fn list_applied_migrations(
+        &mut self,
+    ) -> BoxFuture<'_, Result<Vec<AppliedMigration>, MigrateError>> {
+        Box::pin(async move {
+            // language=SQL
+            let rows: Vec<AppliedMigrationRow> = query_as(
+                r#"
+    SELECT version, description, installed_on::TEXT, success, checksum, execution_time
+    FROM _sqlx_migrations
+    ORDER BY version
+                "#,
+            )
+            .fetch_all(self)
+            .await?;
+
+            Ok(rows.into_iter().map(AppliedMigration::from_row).collect())
+        })
+    }
+
     fn lock(&mut self) -> BoxFuture<'_, Result<(), MigrateError>> {
         Box::pin(async move {
             let database_name = current_database(self).await?;
@@ -185,12 +215,22 @@ CREATE TABLE IF NOT EXISTS _sqlx_migrations (
         migration: &'m Migration,
     ) -> BoxFuture<'m, Result<Duration, MigrateError>> {
         Box::pin(async move {
-            let mut tx = self.begin().await?;
             let start = Instant::now();
 
-            let _ = tx.execute(&*migration.sql).await?;
+            let res = if migration.no_tx {
+                // a failure is recorded below as the script may have been partially applied;
+                // this leaves the database dirty
+                execute_each_statement(self, &migration.sql).await
+            } else {
+                let mut tx = self.begin().await?;
 
-            tx.commit().await?;
+                let _ = tx.execute(&*migration.sql).await?;
+
+                // nothing is recorded if the transaction fails to commit, as it was rolled back
+                tx.commit().await?;
+
+                Ok(())
+            };
 
             let elapsed = start.elapsed();
 
@@ -198,19 +238,197 @@ CREATE TABLE IF NOT EXISTS _sqlx_migrations (
             let _ = query(
                 r#"
     INSERT INTO _sqlx_migrations ( version, description, success, checksum, execution_time )
-    VALUES ( $1, $2, TRUE, $3, $4 )
+    VALUES ( $1, $2, $3, $4, $5 )
                 "#,
             )
             .bind(migration.version)
             .bind(&*migration.description)
+            .bind(res.is_ok())
             .bind(&*migration.checksum)
             .bind(elapsed.as_nanos() as i64)
             .execute(self)
             .await?;
 
+            res?;
+
             Ok(elapsed)
         })
     }
+
+    fn revert<'e: 'm, 'm>(
+        &'e mut self,
+        migration: &'m Migration,
+    ) -> BoxFuture<'m, Result<Duration, MigrateError>> {
+        Box::pin(async move {
+            if migration.no_tx {
+                // if the script fails part way through, the migration is left marked as dirty
+
+                // language=SQL
+                let _ = query("UPDATE _sqlx_migrations SET success = FALSE WHERE version = $1")
+                    .bind(migration.version)
+                    .execute(&mut *self)
+                    .await?;
+
+                let start = Instant::now();
+
+                execute_each_statement(self, &migration.sql).await?;
+
+                let elapsed = start.elapsed();
+
+                // language=SQL
+                let _ = query("DELETE FROM _sqlx_migrations WHERE version = $1")
+                    .bind(migration.version)
+                    .execute(self)
+                    .await?;
+
+                return Ok(elapsed);
+            }
+
+            let mut tx = self.begin().await?;
+            let start = Instant::now();
+
+            let _ = tx.execute(&*migration.sql).await?;
+
+            // language=SQL
+            let _ = query("DELETE FROM _sqlx_migrations WHERE version = $1")
+                .bind(migration.version)
+                .execute(&mut tx)
+                .await?;
+
+            tx.commit().await?;
+
+            Ok(start.elapsed())
+        })
+    }
+}
+
+// a script sent as one query runs in an implicit transaction, which statements such as
+// `CREATE INDEX CONCURRENTLY` refuse to run in; its statements are sent one at a time instead
+async fn execute_each_statement(conn: &mut PgConnection, sql: &str) -> Result<(), Error> {
+    for statement in split_statements(sql) {
+        let _ = conn.execute(statement).await?;
+    }
+
+    Ok(())
+}
+
+// splits a script at each `;` that is not in a string, quoted identifier or comment;
+// statements that are only comments and whitespace are left out
+fn split_statements(sql: &str) -> Vec<&str> {
+    let bytes = sql.as_bytes();
+    let mut statements = Vec::new();
+
+    let mut start = 0;
+    let mut has_code = false;
+    let mut i = 0;
+
+    while i < bytes.len() {
+        match bytes[i] {
+            b';' => {
+                if has_code {
+                    statements.push(&sql[start..=i]);
+                }
+
+                start = i + 1;
+                has_code = false;
+                i += 1;
+                continue;
+            }
+
+            // -- a comment to the end of the line
+            b'-' if bytes.get(i + 1) == Some(&b'-') => {
+                i = sql[i..].find('\n').map_or(bytes.len(), |end| i + end);
+                continue;
+            }
+
+            // /* a block comment, which may be nested */
+            b'/' if bytes.get(i + 1) == Some(&b'*') => {
+                let mut depth = 0;
+
+                while i < bytes.len() {
+                    if bytes[i..].starts_with(b"/*") {
+                        depth += 1;
+                        i += 2;
+                    } else if bytes[i..].starts_with(b"*/") {
+                        depth -= 1;
+                        i += 2;
+
+                        if depth == 0 {
+                            break;
+                        }
+                    } else {
+                        i += 1;
+                    }
+                }
+
+                continue;
+            }
+
+            // 'a string', where a quote is escaped as '', or as \' in an E'escape string'
+            b'\'' => {
+                let escapes = i > 0
+                    && (bytes[i - 1] == b'E' || bytes[i - 1] == b'e')
+                    && (i < 2 || !is_identifier_byte(bytes[i - 2]));
+
+                i += 1;
+
+                while i < bytes.len() {
+                    match bytes[i] {
+                        b'\\' if escapes => i += 2,
+                        b'\'' if bytes.get(i + 1) == Some(&b'\'') => i += 2,
+                        b'\'' => break,
+                        _ => i += 1,
+                    }
+                }
+            }
+
+            // "a quoted identifier"
+            b'"' => {
+                i = sql[i + 1..]
+                    .find('"')
+                    .map_or(bytes.len(), |end| i + 1 + end);
+            }
+
+            // $tag$ a dollar-quoted string $tag$
+            b'$' if i == 0 || !is_identifier_byte(bytes[i - 1]) => {
+                let tag_len = sql[i + 1..]
+                    .find(|c: char| !(c.is_ascii_alphanumeric() || c == '_'))
+                    .filter(|&len| {
+                        bytes.get(i + 1 + len) == Some(&b'$')
+                            && !sql[i + 1..].starts_with(|c: char| c.is_ascii_digit())
+                    });
+
+                if let Some(tag_len) = tag_len {
+                    let tag = &sql[i..i + tag_len + 2];
+                    let body = i + tag.len();
+
+                    i = sql[body..]
+                        .find(tag)
+                        .map_or(bytes.len(), |end| body + end + tag.len() - 1);
+                }
+            }
+
+            b if b.is_ascii_whitespace() => {
+                i += 1;
+                continue;
+            }
+
+            _ => {}
+        }
+
+        has_code = true;
+        i += 1;
+    }
+
+    if has_code {
+        statements.push(&sql[start..]);
+    }
+
+    statements
+}
+
+fn is_identifier_byte(b: u8) -> bool {
+    b.is_ascii_alphanumeric() || b == b'_' || b == b'$' || b >= 0x80
 }
 
 async fn current_database(conn: &mut PgConnection) -> Result<String, MigrateError> {
@@ -225,3 +443,44 @@ fn generate_lock_id(database_name: &str) -> i64 {
     // 0x3d32ad9e chosen by fair dice roll
     0x3d32ad9e * (crc32::checksum_ieee(database_name.as_bytes()) as i64)
 }
+
+#[test]
+fn it_splits_statements() {
+    assert_eq!(
+        split_statements(
+            "-- no-transaction\n\nCREATE INDEX CONCURRENTLY a ON t (x);\n\
+             CREATE INDEX CONCURRENTLY b ON t (y);\n-- done\n"
+        ),
+        [
+            "-- no-transaction\n\nCREATE INDEX CONCURRENTLY a ON t (x);",
+            "\nCREATE INDEX CONCURRENTLY b ON t (y);"
+        ]
+    );
+
+    assert_eq!(split_statements("SELECT 1"), ["SELECT 1"]);
+    assert_eq!(split_statements("  ;\n/* ; */ -- ;\n"), Vec::<&str>::new());
+
+    assert_eq!(
+        split_statements("SELECT ';', E'\\';', \"a;b\" FROM t; SELECT 2"),
+        ["SELECT ';', E'\\';', \"a;b\" FROM t;", " SELECT 2"]
+    );
+
+    assert_eq!(
+        split_statements("SELECT 'it''s; here'; /* a /* nested; */ comment */ SELECT $1;"),
+        [
+            "SELECT 'it''s; here';",
+            " /* a /* nested; */ comment */ SELECT $1;"
+        ]
+    );
+
+    assert_eq!(
+        split_statements(
+            "CREATE FUNCTION f() RETURNS int AS $body$ SELECT 1; $body$ LANGUAGE sql; \
+             DO $$ BEGIN PERFORM 1; END $$;"
+        ),
+        [
+            "CREATE FUNCTION f() RETURNS int AS $body$ SELECT 1; $body$ LANGUAGE sql;",
+            " DO $$ BEGIN PERFORM 1; END $$;"
+        ]
+    );
+}
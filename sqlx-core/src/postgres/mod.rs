@@ -3,6 +3,7 @@
 mod arguments;
 mod column;
 mod connection;
+mod copy;
 mod database;
 mod done;
 mod error;
@@ -10,6 +11,7 @@ mod io;
 mod listener;
 mod message;
 mod options;
+mod pipeline;
 mod row;
 mod statement;
 mod transaction;
@@ -22,13 +24,15 @@ mod migrate;
 
 pub use arguments::{PgArgumentBuffer, PgArguments};
 pub use column::PgColumn;
-pub use connection::PgConnection;
+pub use connection::{PgCancelToken, PgConnection};
+pub use copy::PgCopyIn;
 pub use database::Postgres;
 pub use done::PgDone;
 pub use error::{PgDatabaseError, PgErrorPosition};
 pub use listener::{PgListener, PgNotification};
 pub use message::PgSeverity;
-pub use options::{PgConnectOptions, PgSslMode};
+pub use options::{PgConnectOptions, PgSslMode, PgTargetSessionAttrs};
+pub use pipeline::PgPipeline;
 pub use row::PgRow;
 pub use statement::PgStatement;
 pub use transaction::PgTransactionManager;
@@ -2,6 +2,8 @@ use crate::connection::ConnectOptions;
 use crate::error::Error;
 use crate::postgres::{PgConnectOptions, PgConnection};
 use futures_core::future::BoxFuture;
+use log::LevelFilter;
+use std::time::Duration;
 
 impl ConnectOptions for PgConnectOptions {
     type Connection = PgConnection;
@@ -12,4 +14,19 @@ impl ConnectOptions for PgConnectOptions {
     {
         Box::pin(PgConnection::establish(self))
     }
+
+    fn log_statements(mut self, level: LevelFilter) -> Self {
+        self.log_settings.log_statements(level);
+        self
+    }
+
+    fn log_slow_statements(mut self, level: LevelFilter, duration: Duration) -> Self {
+        self.log_settings.log_slow_statements(level, duration);
+        self
+    }
+
+    fn log_parameters(mut self, enabled: bool) -> Self {
+        self.log_settings.log_parameters(enabled);
+        self
+    }
 }
@@ -0,0 +1,42 @@
+use crate::error::Error;
+use std::str::FromStr;
+
+/// Options for the kind of server session that is acceptable when connecting.
+///
+/// It is used by the
+/// [`target_session_attrs`](struct.PgConnectOptions.html#method.target_session_attrs) method.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PgTargetSessionAttrs {
+    /// Any successful connection is acceptable.
+    Any,
+
+    /// The session must accept read-write transactions by default, as on a primary server.
+    ReadWrite,
+
+    /// The session must not accept read-write transactions by default, as on a hot standby.
+    ReadOnly,
+}
+
+impl Default for PgTargetSessionAttrs {
+    fn default() -> Self {
+        PgTargetSessionAttrs::Any
+    }
+}
+
+impl FromStr for PgTargetSessionAttrs {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self, Error> {
+        Ok(match &*s.to_ascii_lowercase() {
+            "any" => PgTargetSessionAttrs::Any,
+            "read-write" => PgTargetSessionAttrs::ReadWrite,
+            "read-only" => PgTargetSessionAttrs::ReadOnly,
+
+            _ => {
+                return Err(Error::Configuration(
+                    format!("unknown value {:?} for `target_session_attrs`", s).into(),
+                ));
+            }
+        })
+    }
+}
@@ -0,0 +1,141 @@
+use std::sync::Arc;
+
+use either::Either;
+use futures_core::stream::BoxStream;
+use futures_util::{pin_mut, TryStreamExt};
+
+use crate::error::Error;
+use crate::executor::Execute;
+use crate::postgres::statement::PgStatementMetadata;
+use crate::postgres::{PgArguments, PgConnection, PgDone, PgRow, Postgres};
+
+/// A sequence of queries that are sent to PostgreSQL in a single write and
+/// executed in order.
+///
+/// Issuing several independent queries one after another costs a round-trip each. A pipeline
+/// queues them using the extended query protocol, flushes them all at once and then reads each
+/// result set back, in the order the queries were pushed.
+///
+/// Each query is executed as a prepared statement; a query string may therefore only contain
+/// a single SQL statement. Statements that have not been prepared on the connection yet still
+/// need a round-trip to be prepared before the pipeline is sent.
+///
+/// The pipeline ends with a single synchronization point. This means that if any query fails,
+/// the queries after it are skipped and the error is returned. Outside of a transaction, all
+/// of the queries run in one implicit transaction which is rolled back on failure.
+///
+/// ```rust,no_run
+/// # use sqlx_core::postgres::{PgConnection, PgPipeline};
+/// # use sqlx_core::connection::Connection;
+/// # use sqlx_core::error::Error;
+/// #
+/// # #[cfg(feature = "runtime-async-std-native-tls")]
+/// # sqlx_rt::block_on::<_, Result<(), Error>>(async move {
+/// # let mut conn = PgConnection::connect("postgres:// ...").await?;
+/// let mut pipeline = PgPipeline::new();
+///
+/// pipeline
+///     .push(sqlx_core::query::query("SELECT * FROM users WHERE id = $1").bind(1_i32))
+///     .push(sqlx_core::query::query("SELECT * FROM orders WHERE user_id = $1").bind(1_i32));
+///
+/// let results = pipeline.fetch_all(&mut conn).await?;
+///
+/// let (users, orders) = (&results[0], &results[1]);
+/// # Ok(())
+/// # }).unwrap();
+/// ```
+#[derive(Default)]
+pub struct PgPipeline<'q> {
+    queries: Vec<PgPipelineQuery<'q>>,
+}
+
+pub(crate) struct PgPipelineQuery<'q> {
+    pub(crate) sql: &'q str,
+    pub(crate) arguments: Option<PgArguments>,
+    pub(crate) persistent: bool,
+    pub(crate) metadata: Option<Arc<PgStatementMetadata>>,
+}
+
+impl<'q> PgPipeline<'q> {
+    /// Creates a new, empty pipeline.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Appends a query to the end of the pipeline.
+    pub fn push<E>(&mut self, mut query: E) -> &mut Self
+    where
+        E: Execute<'q, Postgres>,
+    {
+        self.queries.push(PgPipelineQuery {
+            sql: query.sql(),
+            metadata: query.statement().map(|s| Arc::clone(&s.metadata)),
+            arguments: query.take_arguments(),
+            persistent: query.persistent(),
+        });
+
+        self
+    }
+
+    /// Returns the number of queries in the pipeline.
+    pub fn len(&self) -> usize {
+        self.queries.len()
+    }
+
+    /// Returns `true` if no queries have been pushed to the pipeline.
+    pub fn is_empty(&self) -> bool {
+        self.queries.is_empty()
+    }
+
+    /// Execute the pipeline and return the generated results as a stream.
+    ///
+    /// The rows of each query are followed by exactly one [`PgDone`], which marks the end of
+    /// its result set.
+    pub fn fetch_many<'c>(
+        self,
+        conn: &'c mut PgConnection,
+    ) -> BoxStream<'c, Result<Either<PgDone, PgRow>, Error>>
+    where
+        'q: 'c,
+    {
+        let queries = self.queries;
+
+        Box::pin(try_stream! {
+            let s = conn.run_pipeline(queries).await?;
+            pin_mut!(s);
+
+            while let Some(v) = s.try_next().await? {
+                r#yield!(v);
+            }
+
+            Ok(())
+        })
+    }
+
+    /// Execute the pipeline and return the number of rows affected by each query,
+    /// in order.
+    pub async fn execute(self, conn: &mut PgConnection) -> Result<Vec<PgDone>, Error> {
+        self.fetch_many(conn)
+            .try_filter_map(|step| async move { Ok(step.left()) })
+            .try_collect()
+            .await
+    }
+
+    /// Execute the pipeline and return the rows of each query, in order.
+    pub async fn fetch_all(self, conn: &mut PgConnection) -> Result<Vec<Vec<PgRow>>, Error> {
+        let mut results = Vec::with_capacity(self.len());
+        let mut rows = Vec::new();
+
+        let s = self.fetch_many(conn);
+        pin_mut!(s);
+
+        while let Some(step) = s.try_next().await? {
+            match step {
+                Either::Left(_) => results.push(std::mem::take(&mut rows)),
+                Either::Right(row) => rows.push(row),
+            }
+        }
+
+        Ok(results)
+    }
+}
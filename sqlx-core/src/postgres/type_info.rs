@@ -162,6 +162,36 @@ impl PgTypeInfo {
         self.0.kind()
     }
 
+    // Returns the element type if this is an array type
+    //
+    // A type that has only been declared by name is taken to be an array if the name starts
+    // with an underscore; that is how Postgres names the array type of every type it creates.
+    pub(crate) fn array_element(&self) -> Option<PgTypeInfo> {
+        match &self.0 {
+            PgType::DeclareWithName(name) => name
+                .strip_prefix('_')
+                .map(|name| PgTypeInfo(PgType::DeclareWithName(UStr::new(name)))),
+
+            PgType::DeclareWithOid(_) => None,
+
+            ty => match ty.kind() {
+                PgTypeKind::Array(element) => Some(element.clone()),
+                _ => None,
+            },
+        }
+    }
+
+    // Returns true if this is the `citext` type of the extension of the same name
+    //
+    // Extension types have no fixed OID so the type is recognized by its name.
+    pub(crate) fn is_citext(&self) -> bool {
+        match &self.0 {
+            PgType::Custom(ty) => ty.name.eq_ignore_ascii_case("citext"),
+            PgType::DeclareWithName(name) => name.eq_ignore_ascii_case("citext"),
+            _ => false,
+        }
+    }
+
     #[doc(hidden)]
     pub fn __type_feature_gate(&self) -> Option<&'static str> {
         if [
@@ -197,6 +227,24 @@ impl PgTypeInfo {
         .contains(self)
         {
             Some("ipnetwork")
+        } else if [
+            PgTypeInfo::MACADDR,
+            PgTypeInfo::MACADDR8,
+            PgTypeInfo::MACADDR_ARRAY,
+            PgTypeInfo::MACADDR8_ARRAY,
+        ]
+        .contains(self)
+        {
+            Some("mac_address")
+        } else if [
+            PgTypeInfo::BIT,
+            PgTypeInfo::VARBIT,
+            PgTypeInfo::BIT_ARRAY,
+            PgTypeInfo::VARBIT_ARRAY,
+        ]
+        .contains(self)
+        {
+            Some("bit-vec")
         } else if [PgTypeInfo::NUMERIC, PgTypeInfo::NUMERIC_ARRAY].contains(self) {
             Some("bigdecimal")
         } else {
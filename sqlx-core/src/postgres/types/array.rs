@@ -7,6 +7,145 @@ use crate::postgres::type_info::PgType;
 use crate::postgres::{PgArgumentBuffer, PgTypeInfo, PgValueFormat, PgValueRef, Postgres};
 use crate::types::Type;
 
+/// A Postgres array of any number of dimensions.
+///
+/// Unlike `Vec<T>` (one dimension) or `Vec<Vec<T>>` (two dimensions), this keeps the lower bound
+/// of each dimension, which may be something other than `1`.
+///
+/// The elements are stored in row-major order; that is, the last dimension varies fastest.
+///
+/// ```rust
+/// # use sqlx_core::postgres::types::{PgArray, PgArrayDimension};
+/// // '[0:1][1:3]={{1,2,3},{4,5,6}}'::int4[]
+/// let array = PgArray::new(
+///     vec![
+///         PgArrayDimension { len: 2, lower_bound: 0 },
+///         PgArrayDimension { len: 3, lower_bound: 1 },
+///     ],
+///     vec![1, 2, 3, 4, 5, 6],
+/// );
+///
+/// assert_eq!(array.get(&[1, 2]), Some(&5));
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PgArray<T> {
+    dimensions: Vec<PgArrayDimension>,
+    elements: Vec<T>,
+}
+
+/// The length and lower bound of one dimension of a [`PgArray`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct PgArrayDimension {
+    pub len: usize,
+    pub lower_bound: i32,
+}
+
+impl<T> PgArray<T> {
+    /// Creates an array from its dimensions, outermost first, and its elements in
+    /// row-major order.
+    ///
+    /// An array without dimensions is empty.
+    ///
+    /// # Panics
+    /// If the number of elements is not the product of the lengths of the dimensions. See
+    /// [`try_new`](#method.try_new) for a version that returns an error instead.
+    pub fn new(dimensions: Vec<PgArrayDimension>, elements: Vec<T>) -> Self {
+        match Self::try_new(dimensions, elements) {
+            Ok(array) => array,
+            Err(error) => panic!("{}", error),
+        }
+    }
+
+    /// Creates an array from its dimensions, outermost first, and its elements in
+    /// row-major order.
+    ///
+    /// Returns an error if the number of elements is not the product of the lengths of the
+    /// dimensions.
+    pub fn try_new(
+        dimensions: Vec<PgArrayDimension>,
+        elements: Vec<T>,
+    ) -> Result<Self, BoxDynError> {
+        let len = if dimensions.is_empty() {
+            Some(0)
+        } else {
+            dimensions
+                .iter()
+                .try_fold(1_usize, |len, dim| len.checked_mul(dim.len))
+        };
+
+        if len != Some(elements.len()) {
+            return Err(format!(
+                "the number of elements of an array must be the product of the lengths of its \
+                 dimensions; {} elements for dimensions {:?}",
+                elements.len(),
+                dimensions.iter().map(|dim| dim.len).collect::<Vec<_>>()
+            )
+            .into());
+        }
+
+        Ok(Self {
+            dimensions,
+            elements,
+        })
+    }
+
+    /// The dimensions of the array, outermost first.
+    pub fn dimensions(&self) -> &[PgArrayDimension] {
+        &self.dimensions
+    }
+
+    /// The elements of the array, in row-major order.
+    pub fn elements(&self) -> &[T] {
+        &self.elements
+    }
+
+    /// Returns the elements of the array, in row-major order.
+    pub fn into_elements(self) -> Vec<T> {
+        self.elements
+    }
+
+    /// Returns the element at the given subscripts, one for each dimension, as they would be
+    /// written in SQL; that is, counting from the lower bound of each dimension.
+    pub fn get(&self, subscripts: &[i32]) -> Option<&T> {
+        if subscripts.len() != self.dimensions.len() {
+            return None;
+        }
+
+        let mut index = 0;
+
+        for (dim, subscript) in self.dimensions.iter().zip(subscripts) {
+            let offset = subscript.checked_sub(dim.lower_bound)?;
+
+            if offset < 0 || offset as usize >= dim.len {
+                return None;
+            }
+
+            index = index * dim.len + offset as usize;
+        }
+
+        self.elements.get(index)
+    }
+}
+
+impl<T> From<Vec<T>> for PgArray<T> {
+    /// Creates a one-dimensional array with a lower bound of `1`.
+    fn from(elements: Vec<T>) -> Self {
+        let dimensions = if elements.is_empty() {
+            Vec::new()
+        } else {
+            vec![PgArrayDimension {
+                len: elements.len(),
+                lower_bound: 1,
+            }]
+        };
+
+        Self {
+            dimensions,
+            elements,
+        }
+    }
+}
+
 impl<T> Type<Postgres> for [Option<T>]
 where
     [T]: Type<Postgres>,
@@ -33,6 +172,49 @@ where
     }
 }
 
+// postgres uses the same type for arrays of any number of dimensions
+
+impl<T> Type<Postgres> for [Vec<T>]
+where
+    T: Type<Postgres>,
+    [T]: Type<Postgres>,
+{
+    fn type_info() -> PgTypeInfo {
+        <[T] as Type<Postgres>>::type_info()
+    }
+
+    fn compatible(ty: &PgTypeInfo) -> bool {
+        <[T] as Type<Postgres>>::compatible(ty)
+    }
+}
+
+impl<T> Type<Postgres> for Vec<Vec<T>>
+where
+    T: Type<Postgres>,
+    [T]: Type<Postgres>,
+{
+    fn type_info() -> PgTypeInfo {
+        <[T] as Type<Postgres>>::type_info()
+    }
+
+    fn compatible(ty: &PgTypeInfo) -> bool {
+        <[T] as Type<Postgres>>::compatible(ty)
+    }
+}
+
+impl<T> Type<Postgres> for PgArray<T>
+where
+    [T]: Type<Postgres>,
+{
+    fn type_info() -> PgTypeInfo {
+        <[T] as Type<Postgres>>::type_info()
+    }
+
+    fn compatible(ty: &PgTypeInfo) -> bool {
+        <[T] as Type<Postgres>>::compatible(ty)
+    }
+}
+
 impl<'q, T> Encode<'q, Postgres> for Vec<T>
 where
     for<'a> &'a [T]: Encode<'q, Postgres>,
@@ -45,23 +227,30 @@ where
     }
 }
 
+/// Encodes a one-dimensional array or, if `T` is itself an array, a multi-dimensional one.
+///
+/// # Panics
+/// If `T` is an array and the elements do not all have the same dimensions (a jagged array,
+/// such as `vec![vec![1, 2], vec![3]]`), or if any of them is `NULL`. Postgres has no way to
+/// represent either; use [`PgArray`] to build a multi-dimensional array without panicking.
 impl<'q, T> Encode<'q, Postgres> for &'_ [T]
 where
     T: Encode<'q, Postgres> + Type<Postgres>,
     Self: Type<Postgres>,
 {
     fn encode_by_ref(&self, buf: &mut PgArgumentBuffer) -> IsNull {
+        if let Some(element) = T::type_info().array_element() {
+            // each element is itself an array; this is a multi-dimensional array
+            encode_nested(self, &element, buf);
+
+            return IsNull::No;
+        }
+
         buf.extend(&1_i32.to_be_bytes()); // number of dimensions
         buf.extend(&0_i32.to_be_bytes()); // flags
 
         // element type
-        match T::type_info().0 {
-            PgType::DeclareWithName(name) => buf.patch_type_by_name(&name),
-
-            ty => {
-                buf.extend(&ty.oid().to_be_bytes());
-            }
-        }
+        encode_element_type(&T::type_info(), buf);
 
         buf.extend(&(self.len() as i32).to_be_bytes()); // len
         buf.extend(&1_i32.to_be_bytes()); // lower bound
@@ -74,60 +263,215 @@ where
     }
 }
 
+impl<'q, T> Encode<'q, Postgres> for PgArray<T>
+where
+    T: Encode<'q, Postgres> + Type<Postgres>,
+{
+    fn encode_by_ref(&self, buf: &mut PgArgumentBuffer) -> IsNull {
+        buf.extend(&(self.dimensions.len() as i32).to_be_bytes()); // number of dimensions
+        buf.extend(&0_i32.to_be_bytes()); // flags
+
+        // element type
+        encode_element_type(&T::type_info(), buf);
+
+        for dim in &self.dimensions {
+            buf.extend(&(dim.len as i32).to_be_bytes()); // len
+            buf.extend(&dim.lower_bound.to_be_bytes()); // lower bound
+        }
+
+        for element in &self.elements {
+            buf.encode(element);
+        }
+
+        IsNull::No
+    }
+}
+
+fn encode_element_type(ty: &PgTypeInfo, buf: &mut PgArgumentBuffer) {
+    match &ty.0 {
+        PgType::DeclareWithName(name) => buf.patch_type_by_name(name),
+
+        ty => {
+            buf.extend(&ty.oid().to_be_bytes());
+        }
+    }
+}
+
+// encodes an array whose elements are arrays of one dimension less
+fn encode_nested<'q, T>(elements: &[T], element_type: &PgTypeInfo, buf: &mut PgArgumentBuffer)
+where
+    T: Encode<'q, Postgres>,
+{
+    let offset = buf.len();
+
+    buf.extend(&1_i32.to_be_bytes()); // number of dimensions, replaced below
+    buf.extend(&0_i32.to_be_bytes()); // flags
+
+    // element type, of the innermost arrays
+    encode_element_type(element_type, buf);
+
+    buf.extend(&(elements.len() as i32).to_be_bytes()); // len
+    buf.extend(&1_i32.to_be_bytes()); // lower bound
+
+    // each sub-array is encoded as a complete array value: its length, then its header,
+    // dimensions and elements; the offset of each is kept to strip them below
+    let mut sub_arrays = Vec::with_capacity(elements.len());
+
+    for element in elements {
+        sub_arrays.push(buf.len());
+        buf.encode(element);
+    }
+
+    // the sub-arrays must all be present and have the same dimensions before anything is
+    // stripped, or the result would not be an array at all
+    if let Err(message) = check_sub_arrays(buf, &sub_arrays) {
+        // leave the buffer as it was, along with its patches
+        let end = buf.len();
+        buf.remove(offset..end);
+
+        panic!("{}", message);
+    }
+
+    // the number of dimensions of the sub-arrays
+    let sub_ndim = match sub_arrays.first() {
+        Some(&start) => (&buf[(start + 4)..]).get_i32() as usize,
+        None => 0,
+    };
+
+    // keep only the elements of each sub-array, and the dimensions of the first one following
+    // our own; from the last, so the offsets of the others are not moved
+    for (i, &start) in sub_arrays.iter().enumerate().rev() {
+        if i == 0 {
+            buf.remove(start..(start + 16));
+        } else {
+            buf.remove(start..(start + 16 + sub_ndim * 8));
+        }
+    }
+
+    let ndim = 1 + sub_ndim as i32;
+    buf[offset..(offset + 4)].copy_from_slice(&ndim.to_be_bytes());
+}
+
+// checks that the encoded sub-arrays, starting at the given offsets, are not NULL and have the
+// same dimensions
+fn check_sub_arrays(buf: &[u8], sub_arrays: &[usize]) -> Result<(), String> {
+    let mut first: Option<&[u8]> = None;
+
+    for (i, &start) in sub_arrays.iter().enumerate() {
+        let mut header = &buf[start..];
+
+        if header.get_i32() == -1 {
+            return Err(format!(
+                "the sub-arrays of a multi-dimensional array cannot be NULL; \
+                 the sub-array at index {} is NULL",
+                i
+            ));
+        }
+
+        let ndim = header.get_i32() as usize;
+        let dimensions = &buf[(start + 16)..(start + 16 + ndim * 8)];
+
+        match first {
+            None => first = Some(dimensions),
+
+            Some(first) if first != dimensions => {
+                return Err(format!(
+                    "the sub-arrays of a multi-dimensional array must all have the same \
+                     dimensions; the sub-array at index {} differs from the first",
+                    i
+                ));
+            }
+
+            Some(_) => {}
+        }
+    }
+
+    Ok(())
+}
+
 impl<'r, T> Decode<'r, Postgres> for Vec<T>
 where
     T: for<'a> Decode<'a, Postgres> + Type<Postgres>,
     Self: Type<Postgres>,
 {
     fn decode(value: PgValueRef<'r>) -> Result<Self, BoxDynError> {
-        let element_type_info;
         let format = value.format();
 
+        // for a multi-dimensional array, each element is an array of one dimension less
+        let nested = T::type_info().array_element().is_some();
+
         match format {
             PgValueFormat::Binary => {
-                // https://github.com/postgres/postgres/blob/a995b371ae29de2d38c4b7881cf414b1560e9746/src/backend/utils/adt/arrayfuncs.c#L1548
-
                 let mut buf = value.as_bytes()?;
+                let header = BinaryHeader::decode(&mut buf)?;
 
-                // number of dimensions in the array
-                let ndim = buf.get_i32();
+                let dim = match header.dimensions.first() {
+                    Some(dim) => *dim,
 
-                if ndim == 0 {
                     // zero dimensions is an empty array
-                    return Ok(Vec::new());
+                    None => return Ok(Vec::new()),
+                };
+
+                // the lower bound, we only support arrays starting from "1"
+                if dim.lower_bound != 1 {
+                    return Err(format!("encountered an array with a lower bound of {} in the first dimension; only arrays starting at one are supported", dim.lower_bound).into());
                 }
 
-                if ndim != 1 {
-                    return Err(format!("encountered an array of {} dimensions; only one-dimensional arrays are supported", ndim).into());
+                let mut elements = Vec::with_capacity(dim.len);
+
+                if dim.len == 0 {
+                    return Ok(elements);
                 }
 
-                // appears to have been used in the past to communicate potential NULLS
-                // but reading source code back through our supported postgres versions (9.5+)
-                // this is never used for anything
-                let _flags = buf.get_i32();
+                if nested {
+                    if header.dimensions.len() == 1 {
+                        return Err("encountered an array of 1 dimension; expected a multi-dimensional array".into());
+                    }
 
-                // the OID of the element
-                let element_type_oid = buf.get_u32();
-                element_type_info = PgTypeInfo::try_from_oid(element_type_oid)
-                    .unwrap_or_else(|| PgTypeInfo(PgType::DeclareWithOid(element_type_oid)));
+                    let sub_dimensions = &header.dimensions[1..];
+                    let sub_len: usize = sub_dimensions.iter().map(|dim| dim.len).product();
 
-                // length of the array axis
-                let len = buf.get_i32();
+                    for _ in 0..dim.len {
+                        // re-assemble each sub-array as an array value of its own
+                        let mut sub_array = Vec::new();
 
-                // the lower bound, we only support arrays starting from "1"
-                let lower = buf.get_i32();
+                        sub_array.extend(&(sub_dimensions.len() as i32).to_be_bytes());
+                        sub_array.extend(&0_i32.to_be_bytes());
+                        sub_array.extend(&header.element_oid.to_be_bytes());
+
+                        for dim in sub_dimensions {
+                            sub_array.extend(&(dim.len as i32).to_be_bytes());
+                            sub_array.extend(&dim.lower_bound.to_be_bytes());
+                        }
+
+                        let data = buf;
 
-                if lower != 1 {
-                    return Err(format!("encountered an array with a lower bound of {} in the first dimension; only arrays starting at one are supported", lower).into());
+                        for _ in 0..sub_len {
+                            PgValueRef::get(&mut buf, format, header.element_type.clone());
+                        }
+
+                        sub_array.extend_from_slice(&data[..(data.len() - buf.len())]);
+
+                        elements.push(T::decode(PgValueRef {
+                            value: Some(&sub_array),
+                            row: None,
+                            type_info: value.type_info.clone(),
+                            format,
+                        })?);
+                    }
+
+                    return Ok(elements);
                 }
 
-                let mut elements = Vec::with_capacity(len as usize);
+                if header.dimensions.len() != 1 {
+                    return Err(format!("encountered an array of {} dimensions; decode into a nested `Vec` or a `PgArray` instead", header.dimensions.len()).into());
+                }
 
-                for _ in 0..len {
+                for _ in 0..dim.len {
                     elements.push(T::decode(PgValueRef::get(
                         &mut buf,
                         format,
-                        element_type_info.clone(),
+                        header.element_type.clone(),
                     ))?)
                 }
 
@@ -136,85 +480,449 @@ where
 
             PgValueFormat::Text => {
                 // no type is provided from the database for the element
-                element_type_info = T::type_info();
+                let element_type_info = T::type_info();
 
                 let s = value.as_str()?;
 
-                // https://github.com/postgres/postgres/blob/a995b371ae29de2d38c4b7881cf414b1560e9746/src/backend/utils/adt/arrayfuncs.c#L718
+                if s.starts_with('[') {
+                    // the dimensions are only written out if a lower bound is not "1"
+                    return Err(format!("encountered an array with explicit bounds, {}; only arrays starting at one are supported", &s[..s.find('=').unwrap_or(s.len())]).into());
+                }
 
-                // trim the wrapping braces
-                let s = &s[1..(s.len() - 1)];
+                let mut elements = Vec::new();
+
+                for element in split_text(s, text_delimiter(&element_type_info))? {
+                    elements.push(match element {
+                        TextElement::Array(sub_array) if nested => T::decode(PgValueRef {
+                            value: Some(sub_array.as_bytes()),
+                            row: None,
+                            type_info: value.type_info.clone(),
+                            format,
+                        })?,
+
+                        TextElement::Value(value) if !nested => T::decode(PgValueRef {
+                            value: value.as_ref().map(|s| s.as_bytes()),
+                            row: None,
+                            type_info: element_type_info.clone(),
+                            format,
+                        })?,
+
+                        TextElement::Array(_) => {
+                            return Err("encountered a multi-dimensional array; decode into a nested `Vec` or a `PgArray` instead".into());
+                        }
 
-                if s.is_empty() {
-                    // short-circuit empty arrays up here
-                    return Ok(Vec::new());
+                        TextElement::Value(_) => {
+                            return Err("encountered an array of 1 dimension; expected a multi-dimensional array".into());
+                        }
+                    });
                 }
 
-                // NOTE: Nearly *all* types use ',' as the sequence delimiter. Yes, there is one
-                //       that does not. The BOX (not PostGIS) type uses ';' as a delimiter.
+                Ok(elements)
+            }
+        }
+    }
+}
+
+impl<'r, T> Decode<'r, Postgres> for PgArray<T>
+where
+    T: for<'a> Decode<'a, Postgres> + Type<Postgres>,
+{
+    fn decode(value: PgValueRef<'r>) -> Result<Self, BoxDynError> {
+        let format = value.format();
+
+        match format {
+            PgValueFormat::Binary => {
+                let mut buf = value.as_bytes()?;
+                let header = BinaryHeader::decode(&mut buf)?;
 
-                // TODO: When we add support for BOX we need to figure out some way to make the
-                //       delimiter selection
+                let len = if header.dimensions.is_empty() {
+                    0
+                } else {
+                    header.dimensions.iter().map(|dim| dim.len).product()
+                };
 
-                let delimiter = ',';
-                let mut done = false;
-                let mut in_quotes = false;
-                let mut in_escape = false;
-                let mut value = String::with_capacity(10);
-                let mut chars = s.chars();
-                let mut elements = Vec::with_capacity(4);
+                let mut elements = Vec::with_capacity(len);
+
+                for _ in 0..len {
+                    elements.push(T::decode(PgValueRef::get(
+                        &mut buf,
+                        format,
+                        header.element_type.clone(),
+                    ))?)
+                }
 
-                while !done {
-                    loop {
-                        match chars.next() {
-                            Some(ch) => match ch {
-                                _ if in_escape => {
-                                    value.push(ch);
-                                    in_escape = false;
-                                }
+                Ok(PgArray::new(header.dimensions, elements))
+            }
 
-                                '"' => {
-                                    in_quotes = !in_quotes;
-                                }
+            PgValueFormat::Text => {
+                let element_type_info = T::type_info();
 
-                                '\\' => {
-                                    in_escape = true;
-                                }
+                let mut s = value.as_str()?;
 
-                                _ if ch == delimiter && !in_quotes => {
-                                    break;
-                                }
+                // the bounds of each dimension are only written out if a lower bound is not "1"
+                // [-1:0][1:3]={{1,2,3},{4,5,6}}
+                let mut lower_bounds = Vec::new();
 
-                                _ => {
-                                    value.push(ch);
-                                }
-                            },
+                if s.starts_with('[') {
+                    let eq = s
+                        .find('=')
+                        .ok_or("expected `=` after the bounds of an array")?;
 
-                            None => {
-                                done = true;
-                                break;
-                            }
-                        }
+                    for bounds in s[1..(eq - 1)].split("][") {
+                        let lower = bounds.split(':').next().unwrap_or_default();
+                        lower_bounds.push(lower.parse::<i32>()?);
                     }
 
-                    let value_opt = if value == "NULL" {
-                        None
-                    } else {
-                        Some(value.as_bytes())
-                    };
+                    s = &s[(eq + 1)..];
+                }
 
+                let mut lens = Vec::new();
+                let mut values = Vec::new();
+
+                let delimiter = text_delimiter(&element_type_info);
+
+                flatten_text(s, delimiter, 0, &mut lens, &mut values)?;
+
+                if !lower_bounds.is_empty() && lower_bounds.len() != lens.len() {
+                    return Err(format!(
+                        "encountered an array of {} dimensions with bounds for {}",
+                        lens.len(),
+                        lower_bounds.len()
+                    )
+                    .into());
+                }
+
+                let dimensions = lens
+                    .into_iter()
+                    .enumerate()
+                    .map(|(i, len)| PgArrayDimension {
+                        len,
+                        lower_bound: lower_bounds.get(i).copied().unwrap_or(1),
+                    })
+                    .collect();
+
+                let mut elements = Vec::with_capacity(values.len());
+
+                for value in values {
                     elements.push(T::decode(PgValueRef {
-                        value: value_opt,
+                        value: value.as_ref().map(|s| s.as_bytes()),
                         row: None,
                         type_info: element_type_info.clone(),
                         format,
                     })?);
+                }
+
+                Ok(PgArray::new(dimensions, elements))
+            }
+        }
+    }
+}
+
+// https://github.com/postgres/postgres/blob/a995b371ae29de2d38c4b7881cf414b1560e9746/src/backend/utils/adt/arrayfuncs.c#L1548
+struct BinaryHeader {
+    element_oid: u32,
+    element_type: PgTypeInfo,
+    dimensions: Vec<PgArrayDimension>,
+}
+
+impl BinaryHeader {
+    fn decode(buf: &mut &[u8]) -> Result<Self, BoxDynError> {
+        // number of dimensions in the array
+        let ndim = buf.get_i32();
+
+        if ndim < 0 {
+            return Err(format!("encountered an array of {} dimensions", ndim).into());
+        }
+
+        // appears to have been used in the past to communicate potential NULLS
+        // but reading source code back through our supported postgres versions (9.5+)
+        // this is never used for anything
+        let _flags = buf.get_i32();
+
+        // the OID of the element
+        let element_oid = buf.get_u32();
+        let element_type = PgTypeInfo::try_from_oid(element_oid)
+            .unwrap_or_else(|| PgTypeInfo(PgType::DeclareWithOid(element_oid)));
+
+        let dimensions = (0..ndim)
+            .map(|_| PgArrayDimension {
+                // length of the array axis
+                len: buf.get_i32() as usize,
+                lower_bound: buf.get_i32(),
+            })
+            .collect();
+
+        Ok(Self {
+            element_oid,
+            element_type,
+            dimensions,
+        })
+    }
+}
+
+enum TextElement<'a> {
+    // a nested array, still in its text form
+    Array(&'a str),
+
+    // an element, unquoted and unescaped; or `None` for NULL
+    Value(Option<String>),
+}
+
+// the character that separates the elements of an array in its text form
+fn text_delimiter(element: &PgTypeInfo) -> char {
+    // the delimiter is that of the innermost element type
+    let mut element = element.clone();
+
+    while let Some(inner) = element.array_element() {
+        element = inner;
+    }
+
+    // NOTE: Nearly *all* types use ',' as the sequence delimiter. Yes, there is one
+    //       that does not. The BOX (not PostGIS) type uses ';' as a delimiter.
+    match element.0 {
+        PgType::Box => ';',
+        _ => ',',
+    }
+}
+
+// splits the text form of an array into its top-level elements
+// https://github.com/postgres/postgres/blob/a995b371ae29de2d38c4b7881cf414b1560e9746/src/backend/utils/adt/arrayfuncs.c#L718
+fn split_text(s: &str, delimiter: char) -> Result<Vec<TextElement<'_>>, BoxDynError> {
+    if !(s.starts_with('{') && s.ends_with('}')) || s.len() < 2 {
+        return Err(format!("expected an array but found {:?}", s).into());
+    }
+
+    // trim the wrapping braces
+    let s = &s[1..(s.len() - 1)];
+
+    let mut elements = Vec::new();
+
+    if s.is_empty() {
+        // short-circuit empty arrays up here
+        return Ok(elements);
+    }
 
-                    value.clear();
+    let mut chars = s.char_indices().peekable();
+
+    loop {
+        if let Some(&(start, '{')) = chars.peek() {
+            // a nested array; runs until its matching brace
+            let mut depth = 0;
+            let mut in_quotes = false;
+            let mut in_escape = false;
+            let mut end = None;
+
+            for (i, ch) in &mut chars {
+                match ch {
+                    _ if in_escape => {
+                        in_escape = false;
+                    }
+
+                    '\\' => {
+                        in_escape = true;
+                    }
+
+                    '"' => {
+                        in_quotes = !in_quotes;
+                    }
+
+                    '{' if !in_quotes => {
+                        depth += 1;
+                    }
+
+                    '}' if !in_quotes => {
+                        depth -= 1;
+
+                        if depth == 0 {
+                            end = Some(i + 1);
+                            break;
+                        }
+                    }
+
+                    _ => {}
                 }
+            }
 
-                Ok(elements)
+            let end = end.ok_or("unterminated nested array")?;
+
+            elements.push(TextElement::Array(&s[start..end]));
+
+            match chars.next() {
+                Some((_, ch)) if ch == delimiter => continue,
+                None => break,
+                Some((_, ch)) => {
+                    return Err(format!("unexpected {:?} after a nested array", ch).into());
+                }
+            }
+        }
+
+        let mut value = String::with_capacity(10);
+        let mut quoted = false;
+        let mut in_quotes = false;
+        let mut in_escape = false;
+        let mut done = true;
+
+        for (_, ch) in &mut chars {
+            match ch {
+                _ if in_escape => {
+                    value.push(ch);
+                    in_escape = false;
+                }
+
+                '"' => {
+                    in_quotes = !in_quotes;
+                    quoted = true;
+                }
+
+                '\\' => {
+                    in_escape = true;
+                }
+
+                _ if ch == delimiter && !in_quotes => {
+                    done = false;
+                    break;
+                }
+
+                _ => {
+                    value.push(ch);
+                }
             }
         }
+
+        // a quoted "NULL" is the string, not a NULL
+        elements.push(TextElement::Value(if !quoted && value == "NULL" {
+            None
+        } else {
+            Some(value)
+        }));
+
+        if done {
+            break;
+        }
     }
+
+    Ok(elements)
+}
+
+// collects the elements of a (possibly) multi-dimensional array in its text form, in row-major
+// order, along with the length of each dimension
+fn flatten_text(
+    s: &str,
+    delimiter: char,
+    depth: usize,
+    lens: &mut Vec<usize>,
+    values: &mut Vec<Option<String>>,
+) -> Result<(), BoxDynError> {
+    let elements = split_text(s, delimiter)?;
+
+    if elements.is_empty() {
+        if depth == 0 {
+            // an empty array has no dimensions
+            return Ok(());
+        }
+
+        return Err("encountered an empty sub-array in a multi-dimensional array".into());
+    }
+
+    if lens.len() == depth {
+        lens.push(elements.len());
+    } else if lens[depth] != elements.len() {
+        return Err(
+            "multi-dimensional arrays must have sub-arrays with matching dimensions".into(),
+        );
+    }
+
+    for element in elements {
+        match element {
+            TextElement::Array(sub_array) => {
+                flatten_text(sub_array, delimiter, depth + 1, lens, values)?;
+            }
+
+            TextElement::Value(value) if lens.len() == depth + 1 => {
+                values.push(value);
+            }
+
+            TextElement::Value(_) => {
+                return Err(
+                    "multi-dimensional arrays must have sub-arrays with matching dimensions".into(),
+                );
+            }
+        }
+    }
+
+    Ok(())
+}
+
+#[test]
+fn test_split_text() {
+    let elements = split_text(r#"{1,NULL,"NULL","a,\"b\"",{2,"}"}}"#, ',').unwrap();
+
+    assert!(matches!(&elements[0], TextElement::Value(Some(v)) if v == "1"));
+    assert!(matches!(&elements[1], TextElement::Value(None)));
+    assert!(matches!(&elements[2], TextElement::Value(Some(v)) if v == "NULL"));
+    assert!(matches!(&elements[3], TextElement::Value(Some(v)) if v == "a,\"b\""));
+    assert!(matches!(&elements[4], TextElement::Array(a) if *a == r#"{2,"}"}"#));
+    assert_eq!(elements.len(), 5);
+}
+
+#[test]
+fn test_split_text_of_boxes() {
+    let elements = split_text("{(1,1),(0,0);(2,2),(1,1)}", ';').unwrap();
+
+    assert!(matches!(&elements[0], TextElement::Value(Some(v)) if v == "(1,1),(0,0)"));
+    assert!(matches!(&elements[1], TextElement::Value(Some(v)) if v == "(2,2),(1,1)"));
+    assert_eq!(elements.len(), 2);
+
+    assert_eq!(text_delimiter(&PgTypeInfo::BOX), ';');
+    assert_eq!(text_delimiter(&PgTypeInfo::BOX_ARRAY), ';');
+    assert_eq!(text_delimiter(&PgTypeInfo::INT4), ',');
+}
+
+#[test]
+fn test_flatten_text() {
+    let mut lens = Vec::new();
+    let mut values = Vec::new();
+
+    flatten_text("{{1,2,3},{4,5,6}}", ',', 0, &mut lens, &mut values).unwrap();
+
+    assert_eq!(lens, vec![2, 3]);
+    assert_eq!(values.len(), 6);
+    assert_eq!(values[4].as_deref(), Some("5"));
+
+    assert!(flatten_text("{{1,2},{3}}", ',', 0, &mut Vec::new(), &mut Vec::new()).is_err());
+    assert!(flatten_text("{{1,2},3}", ',', 0, &mut Vec::new(), &mut Vec::new()).is_err());
+}
+
+#[test]
+fn test_try_new() {
+    let dimensions = vec![
+        PgArrayDimension {
+            len: 2,
+            lower_bound: 1,
+        },
+        PgArrayDimension {
+            len: 2,
+            lower_bound: 1,
+        },
+    ];
+
+    assert!(PgArray::try_new(dimensions.clone(), vec![1, 2, 3]).is_err());
+    assert!(PgArray::try_new(dimensions, vec![1, 2, 3, 4]).is_ok());
+    assert!(PgArray::<i32>::try_new(Vec::new(), Vec::new()).is_ok());
+}
+
+#[test]
+#[should_panic(expected = "must all have the same dimensions")]
+fn test_encode_jagged() {
+    let mut buf = PgArgumentBuffer::default();
+
+    let _ = Encode::encode(vec![vec![1_i32, 2], vec![3]], &mut buf);
+}
+
+#[test]
+#[should_panic(expected = "cannot be NULL")]
+fn test_encode_null_sub_array() {
+    let mut buf = PgArgumentBuffer::default();
+
+    let _ = Encode::encode(vec![Some(vec![1_i32, 2]), None], &mut buf);
 }
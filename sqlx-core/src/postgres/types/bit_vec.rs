@@ -0,0 +1,98 @@
+use std::mem;
+
+use bit_vec::BitVec;
+use bytes::Buf;
+
+use crate::decode::Decode;
+use crate::encode::{Encode, IsNull};
+use crate::error::BoxDynError;
+use crate::postgres::{PgArgumentBuffer, PgTypeInfo, PgValueFormat, PgValueRef, Postgres};
+use crate::types::Type;
+
+// https://www.postgresql.org/docs/current/datatype-bit.html
+
+impl Type<Postgres> for BitVec {
+    fn type_info() -> PgTypeInfo {
+        PgTypeInfo::VARBIT
+    }
+
+    fn compatible(ty: &PgTypeInfo) -> bool {
+        *ty == PgTypeInfo::BIT || *ty == PgTypeInfo::VARBIT
+    }
+}
+
+impl Type<Postgres> for [BitVec] {
+    fn type_info() -> PgTypeInfo {
+        PgTypeInfo::VARBIT_ARRAY
+    }
+
+    fn compatible(ty: &PgTypeInfo) -> bool {
+        *ty == PgTypeInfo::BIT_ARRAY || *ty == PgTypeInfo::VARBIT_ARRAY
+    }
+}
+
+impl Type<Postgres> for Vec<BitVec> {
+    fn type_info() -> PgTypeInfo {
+        <[BitVec] as Type<Postgres>>::type_info()
+    }
+
+    fn compatible(ty: &PgTypeInfo) -> bool {
+        <[BitVec] as Type<Postgres>>::compatible(ty)
+    }
+}
+
+impl Encode<'_, Postgres> for BitVec {
+    fn encode_by_ref(&self, buf: &mut PgArgumentBuffer) -> IsNull {
+        // the number of bits followed by the bits themselves, the first bit in the
+        // most significant bit of the first byte
+        buf.extend(&(self.len() as i32).to_be_bytes());
+        buf.extend(self.to_bytes());
+
+        IsNull::No
+    }
+
+    fn size_hint(&self) -> usize {
+        mem::size_of::<i32>() + self.len().div_ceil(8)
+    }
+}
+
+impl Decode<'_, Postgres> for BitVec {
+    fn decode(value: PgValueRef<'_>) -> Result<Self, BoxDynError> {
+        match value.format() {
+            PgValueFormat::Binary => {
+                let mut buf = value.as_bytes()?;
+
+                if buf.len() < 4 {
+                    return Err("unexpected end of BIT data".into());
+                }
+
+                let len = buf.get_i32();
+
+                if len < 0 || buf.len() != (len as usize).div_ceil(8) {
+                    return Err(format!(
+                        "encountered BIT data of {} bytes for {} bits",
+                        buf.len(),
+                        len
+                    )
+                    .into());
+                }
+
+                // the unused bits at the end of the last byte are always zero
+                let mut bits = BitVec::from_bytes(buf);
+                bits.truncate(len as usize);
+
+                Ok(bits)
+            }
+
+            PgValueFormat::Text => value
+                .as_str()?
+                .chars()
+                .map(|c| match c {
+                    '0' => Ok(false),
+                    '1' => Ok(true),
+                    _ => Err(format!("invalid character {:?} in BIT data", c).into()),
+                })
+                .collect(),
+        }
+    }
+}
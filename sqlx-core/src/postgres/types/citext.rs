@@ -0,0 +1,96 @@
+use std::fmt::{self, Display, Formatter};
+use std::ops::{Deref, DerefMut};
+
+use crate::decode::Decode;
+use crate::encode::{Encode, IsNull};
+use crate::error::BoxDynError;
+use crate::postgres::types::array_compatible;
+use crate::postgres::{PgArgumentBuffer, PgTypeInfo, PgValueRef, Postgres};
+use crate::types::Type;
+
+/// A case-insensitive string of the [`citext`] extension.
+///
+/// Comparisons of `citext` values are case-insensitive on the server; the value itself is
+/// kept as written. `&str` and `String` can also be decoded from `CITEXT` columns, but
+/// bind as `TEXT`; use this type to bind a `CITEXT` parameter.
+///
+/// The `citext` extension must be created in the database (`CREATE EXTENSION citext`).
+///
+/// [`citext`]: https://www.postgresql.org/docs/current/citext.html
+#[derive(Debug, Clone, PartialEq, Eq, Hash, PartialOrd, Ord, Default)]
+pub struct PgCiText(pub String);
+
+impl Deref for PgCiText {
+    type Target = str;
+
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+impl DerefMut for PgCiText {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        &mut self.0
+    }
+}
+
+impl From<String> for PgCiText {
+    fn from(s: String) -> Self {
+        Self(s)
+    }
+}
+
+impl From<&'_ str> for PgCiText {
+    fn from(s: &str) -> Self {
+        Self(s.to_owned())
+    }
+}
+
+impl Display for PgCiText {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.0)
+    }
+}
+
+impl Type<Postgres> for PgCiText {
+    fn type_info() -> PgTypeInfo {
+        PgTypeInfo::with_name("citext")
+    }
+
+    fn compatible(ty: &PgTypeInfo) -> bool {
+        // `citext` is stored and sent just like `text`
+        *ty == Self::type_info() || <str as Type<Postgres>>::compatible(ty)
+    }
+}
+
+impl Type<Postgres> for [PgCiText] {
+    fn type_info() -> PgTypeInfo {
+        PgTypeInfo::with_name("_citext")
+    }
+
+    fn compatible(ty: &PgTypeInfo) -> bool {
+        *ty == Self::type_info() || array_compatible::<PgCiText>(ty)
+    }
+}
+
+impl Type<Postgres> for Vec<PgCiText> {
+    fn type_info() -> PgTypeInfo {
+        <[PgCiText] as Type<Postgres>>::type_info()
+    }
+
+    fn compatible(ty: &PgTypeInfo) -> bool {
+        <[PgCiText] as Type<Postgres>>::compatible(ty)
+    }
+}
+
+impl Encode<'_, Postgres> for PgCiText {
+    fn encode_by_ref(&self, buf: &mut PgArgumentBuffer) -> IsNull {
+        <&str as Encode<Postgres>>::encode(&**self, buf)
+    }
+}
+
+impl Decode<'_, Postgres> for PgCiText {
+    fn decode(value: PgValueRef<'_>) -> Result<Self, BoxDynError> {
+        Ok(Self(value.as_str()?.to_owned()))
+    }
+}
@@ -0,0 +1,390 @@
+use std::mem;
+
+use bytes::Buf;
+
+use crate::decode::Decode;
+use crate::encode::{Encode, IsNull};
+use crate::error::BoxDynError;
+use crate::postgres::{PgArgumentBuffer, PgTypeInfo, PgValueFormat, PgValueRef, Postgres};
+use crate::types::Type;
+
+// https://www.postgresql.org/docs/current/datatype-geometric.html
+// https://github.com/postgres/postgres/blob/REL_13_0/src/backend/utils/adt/geo_ops.c
+
+/// A point on a plane, the Postgres `POINT` type.
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub struct PgPoint {
+    pub x: f64,
+    pub y: f64,
+}
+
+/// An infinite line, the Postgres `LINE` type.
+///
+/// The line is represented by the linear equation `ax + by + c = 0`, where `a` and `b`
+/// are not both zero.
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub struct PgLine {
+    pub a: f64,
+    pub b: f64,
+    pub c: f64,
+}
+
+/// A finite line segment, the Postgres `LSEG` type.
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub struct PgLSeg {
+    pub start: PgPoint,
+    pub end: PgPoint,
+}
+
+/// A rectangular box, the Postgres `BOX` type.
+///
+/// Postgres reorders the corners as needed so that the upper right corner is stored first;
+/// a decoded box always has its corners in this order.
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub struct PgBox {
+    pub upper_right: PgPoint,
+    pub lower_left: PgPoint,
+}
+
+/// A list of connected points, the Postgres `PATH` type.
+///
+/// A closed path connects its last point back to the first; an open path does not.
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct PgPath {
+    pub closed: bool,
+    pub points: Vec<PgPoint>,
+}
+
+/// A closed list of connected points, the Postgres `POLYGON` type.
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct PgPolygon {
+    pub points: Vec<PgPoint>,
+}
+
+/// A circle, the Postgres `CIRCLE` type.
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub struct PgCircle {
+    pub center: PgPoint,
+    pub radius: f64,
+}
+
+macro_rules! impl_type {
+    ($ty:ty, $info:ident, $array_info:ident) => {
+        impl Type<Postgres> for $ty {
+            fn type_info() -> PgTypeInfo {
+                PgTypeInfo::$info
+            }
+        }
+
+        impl Type<Postgres> for [$ty] {
+            fn type_info() -> PgTypeInfo {
+                PgTypeInfo::$array_info
+            }
+        }
+
+        impl Type<Postgres> for Vec<$ty> {
+            fn type_info() -> PgTypeInfo {
+                <[$ty] as Type<Postgres>>::type_info()
+            }
+        }
+    };
+}
+
+impl_type!(PgPoint, POINT, POINT_ARRAY);
+impl_type!(PgLine, LINE, LINE_ARRAY);
+impl_type!(PgLSeg, LSEG, LSEG_ARRAY);
+impl_type!(PgBox, BOX, BOX_ARRAY);
+impl_type!(PgPath, PATH, PATH_ARRAY);
+impl_type!(PgPolygon, POLYGON, POLYGON_ARRAY);
+impl_type!(PgCircle, CIRCLE, CIRCLE_ARRAY);
+
+impl Encode<'_, Postgres> for PgPoint {
+    fn encode_by_ref(&self, buf: &mut PgArgumentBuffer) -> IsNull {
+        encode_point(self, buf);
+
+        IsNull::No
+    }
+
+    fn size_hint(&self) -> usize {
+        2 * mem::size_of::<f64>()
+    }
+}
+
+impl Decode<'_, Postgres> for PgPoint {
+    fn decode(value: PgValueRef<'_>) -> Result<Self, BoxDynError> {
+        let mut floats = [0.0; 2];
+        decode_floats(value, "POINT", &mut floats)?;
+        let [x, y] = floats;
+
+        Ok(PgPoint { x, y })
+    }
+}
+
+impl Encode<'_, Postgres> for PgLine {
+    fn encode_by_ref(&self, buf: &mut PgArgumentBuffer) -> IsNull {
+        buf.extend(&self.a.to_be_bytes());
+        buf.extend(&self.b.to_be_bytes());
+        buf.extend(&self.c.to_be_bytes());
+
+        IsNull::No
+    }
+
+    fn size_hint(&self) -> usize {
+        3 * mem::size_of::<f64>()
+    }
+}
+
+impl Decode<'_, Postgres> for PgLine {
+    fn decode(value: PgValueRef<'_>) -> Result<Self, BoxDynError> {
+        let mut floats = [0.0; 3];
+        decode_floats(value, "LINE", &mut floats)?;
+        let [a, b, c] = floats;
+
+        Ok(PgLine { a, b, c })
+    }
+}
+
+impl Encode<'_, Postgres> for PgLSeg {
+    fn encode_by_ref(&self, buf: &mut PgArgumentBuffer) -> IsNull {
+        encode_point(&self.start, buf);
+        encode_point(&self.end, buf);
+
+        IsNull::No
+    }
+
+    fn size_hint(&self) -> usize {
+        4 * mem::size_of::<f64>()
+    }
+}
+
+impl Decode<'_, Postgres> for PgLSeg {
+    fn decode(value: PgValueRef<'_>) -> Result<Self, BoxDynError> {
+        let mut floats = [0.0; 4];
+        decode_floats(value, "LSEG", &mut floats)?;
+        let [x1, y1, x2, y2] = floats;
+
+        Ok(PgLSeg {
+            start: PgPoint { x: x1, y: y1 },
+            end: PgPoint { x: x2, y: y2 },
+        })
+    }
+}
+
+impl Encode<'_, Postgres> for PgBox {
+    fn encode_by_ref(&self, buf: &mut PgArgumentBuffer) -> IsNull {
+        encode_point(&self.upper_right, buf);
+        encode_point(&self.lower_left, buf);
+
+        IsNull::No
+    }
+
+    fn size_hint(&self) -> usize {
+        4 * mem::size_of::<f64>()
+    }
+}
+
+impl Decode<'_, Postgres> for PgBox {
+    fn decode(value: PgValueRef<'_>) -> Result<Self, BoxDynError> {
+        let mut floats = [0.0; 4];
+        decode_floats(value, "BOX", &mut floats)?;
+        let [x1, y1, x2, y2] = floats;
+
+        Ok(PgBox {
+            upper_right: PgPoint { x: x1, y: y1 },
+            lower_left: PgPoint { x: x2, y: y2 },
+        })
+    }
+}
+
+impl Encode<'_, Postgres> for PgPath {
+    fn encode_by_ref(&self, buf: &mut PgArgumentBuffer) -> IsNull {
+        buf.push(self.closed as u8);
+        encode_points(&self.points, buf);
+
+        IsNull::No
+    }
+
+    fn size_hint(&self) -> usize {
+        1 + mem::size_of::<i32>() + self.points.len() * 2 * mem::size_of::<f64>()
+    }
+}
+
+impl Decode<'_, Postgres> for PgPath {
+    fn decode(value: PgValueRef<'_>) -> Result<Self, BoxDynError> {
+        match value.format() {
+            PgValueFormat::Binary => {
+                let mut buf = value.as_bytes()?;
+
+                if buf.is_empty() {
+                    return Err("unexpected end of PATH data".into());
+                }
+
+                let closed = buf.get_u8() != 0;
+                let points = decode_points(buf, "PATH")?;
+
+                Ok(PgPath { closed, points })
+            }
+
+            PgValueFormat::Text => {
+                let s = value.as_str()?;
+
+                // an open path is written in square brackets
+                let closed = !s.trim_start().starts_with('[');
+                let points = parse_points(s, "PATH")?;
+
+                Ok(PgPath { closed, points })
+            }
+        }
+    }
+}
+
+impl Encode<'_, Postgres> for PgPolygon {
+    fn encode_by_ref(&self, buf: &mut PgArgumentBuffer) -> IsNull {
+        encode_points(&self.points, buf);
+
+        IsNull::No
+    }
+
+    fn size_hint(&self) -> usize {
+        mem::size_of::<i32>() + self.points.len() * 2 * mem::size_of::<f64>()
+    }
+}
+
+impl Decode<'_, Postgres> for PgPolygon {
+    fn decode(value: PgValueRef<'_>) -> Result<Self, BoxDynError> {
+        let points = match value.format() {
+            PgValueFormat::Binary => decode_points(value.as_bytes()?, "POLYGON")?,
+            PgValueFormat::Text => parse_points(value.as_str()?, "POLYGON")?,
+        };
+
+        Ok(PgPolygon { points })
+    }
+}
+
+impl Encode<'_, Postgres> for PgCircle {
+    fn encode_by_ref(&self, buf: &mut PgArgumentBuffer) -> IsNull {
+        encode_point(&self.center, buf);
+        buf.extend(&self.radius.to_be_bytes());
+
+        IsNull::No
+    }
+
+    fn size_hint(&self) -> usize {
+        3 * mem::size_of::<f64>()
+    }
+}
+
+impl Decode<'_, Postgres> for PgCircle {
+    fn decode(value: PgValueRef<'_>) -> Result<Self, BoxDynError> {
+        let mut floats = [0.0; 3];
+        decode_floats(value, "CIRCLE", &mut floats)?;
+        let [x, y, radius] = floats;
+
+        Ok(PgCircle {
+            center: PgPoint { x, y },
+            radius,
+        })
+    }
+}
+
+// decodes a type made up of a fixed number of floats; these are written out in the same
+// order in both formats, with punctuation between them in the text format
+fn decode_floats(value: PgValueRef<'_>, name: &str, floats: &mut [f64]) -> Result<(), BoxDynError> {
+    match value.format() {
+        PgValueFormat::Binary => {
+            let mut buf = value.as_bytes()?;
+
+            if buf.len() != mem::size_of_val(floats) {
+                return Err(format!("expected {} bytes for {}", floats.len() * 8, name).into());
+            }
+
+            for float in floats {
+                *float = buf.get_f64();
+            }
+        }
+
+        PgValueFormat::Text => {
+            let parsed = parse_floats(value.as_str()?, name)?;
+
+            if parsed.len() != floats.len() {
+                return Err(format!("expected {} numbers for {}", floats.len(), name).into());
+            }
+
+            floats.copy_from_slice(&parsed);
+        }
+    }
+
+    Ok(())
+}
+
+fn encode_points(points: &[PgPoint], buf: &mut PgArgumentBuffer) {
+    buf.extend(&(points.len() as i32).to_be_bytes());
+
+    for point in points {
+        encode_point(point, buf);
+    }
+}
+
+fn encode_point(point: &PgPoint, buf: &mut PgArgumentBuffer) {
+    buf.extend(&point.x.to_be_bytes());
+    buf.extend(&point.y.to_be_bytes());
+}
+
+fn decode_points(mut buf: &[u8], name: &str) -> Result<Vec<PgPoint>, BoxDynError> {
+    if buf.len() < 4 {
+        return Err(format!("unexpected end of {} data", name).into());
+    }
+
+    let len = buf.get_i32();
+
+    if len < 0 || buf.len() != len as usize * 2 * mem::size_of::<f64>() {
+        return Err(format!("encountered {} with an invalid number of points", name).into());
+    }
+
+    Ok((0..len)
+        .map(|_| PgPoint {
+            x: buf.get_f64(),
+            y: buf.get_f64(),
+        })
+        .collect())
+}
+
+fn parse_points(s: &str, name: &str) -> Result<Vec<PgPoint>, BoxDynError> {
+    let floats = parse_floats(s, name)?;
+
+    if floats.len() % 2 != 0 {
+        return Err(format!("encountered {} with an odd number of coordinates", name).into());
+    }
+
+    Ok(floats
+        .chunks(2)
+        .map(|xy| PgPoint { x: xy[0], y: xy[1] })
+        .collect())
+}
+
+// every geometric type is written as numbers separated by commas and wrapped in some
+// combination of (), [], {} and <>
+fn parse_floats(s: &str, name: &str) -> Result<Vec<f64>, BoxDynError> {
+    s.split(',')
+        .map(|part| {
+            let part = part.trim_matches(|c: char| "()[]{}<> ".contains(c));
+
+            part.parse()
+                .map_err(|_| format!("invalid number {:?} in {}", part, name).into())
+        })
+        .collect()
+}
+
+#[test]
+fn test_parse_floats() {
+    assert_eq!(parse_floats("(1.5,-2)", "POINT").unwrap(), [1.5, -2.0]);
+    assert_eq!(
+        parse_floats("<(1,2),Infinity>", "CIRCLE").unwrap(),
+        [1.0, 2.0, f64::INFINITY]
+    );
+    assert_eq!(
+        parse_floats("[(0,0),(1,1)]", "PATH").unwrap(),
+        [0.0, 0.0, 1.0, 1.0]
+    );
+    assert!(parse_floats("(1,x)", "POINT").is_err());
+}
@@ -0,0 +1,268 @@
+use std::collections::BTreeMap;
+use std::iter::FromIterator;
+use std::ops::{Deref, DerefMut};
+
+use bytes::Buf;
+
+use crate::decode::Decode;
+use crate::encode::{Encode, IsNull};
+use crate::error::BoxDynError;
+use crate::postgres::{PgArgumentBuffer, PgTypeInfo, PgValueFormat, PgValueRef, Postgres};
+use crate::types::Type;
+
+/// Key-value pairs of the [`hstore`] extension.
+///
+/// A key always has a value but the value itself may be `NULL`, which is represented here
+/// by `None`. Keys are unique; the pairs are kept sorted by key.
+///
+/// The `hstore` extension must be created in the database (`CREATE EXTENSION hstore`).
+/// As an extension type, its OID is looked up by name the first time it is sent to
+/// the server on a given connection.
+///
+/// [`hstore`]: https://www.postgresql.org/docs/current/hstore.html
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub struct PgHstore(pub BTreeMap<String, Option<String>>);
+
+impl Deref for PgHstore {
+    type Target = BTreeMap<String, Option<String>>;
+
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+impl DerefMut for PgHstore {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        &mut self.0
+    }
+}
+
+impl From<BTreeMap<String, Option<String>>> for PgHstore {
+    fn from(map: BTreeMap<String, Option<String>>) -> Self {
+        Self(map)
+    }
+}
+
+impl From<PgHstore> for BTreeMap<String, Option<String>> {
+    fn from(hstore: PgHstore) -> Self {
+        hstore.0
+    }
+}
+
+impl<K, V> FromIterator<(K, V)> for PgHstore
+where
+    K: Into<String>,
+    V: Into<Option<String>>,
+{
+    fn from_iter<I: IntoIterator<Item = (K, V)>>(iter: I) -> Self {
+        Self(
+            iter.into_iter()
+                .map(|(key, value)| (key.into(), value.into()))
+                .collect(),
+        )
+    }
+}
+
+impl Type<Postgres> for PgHstore {
+    fn type_info() -> PgTypeInfo {
+        PgTypeInfo::with_name("hstore")
+    }
+}
+
+impl Type<Postgres> for [PgHstore] {
+    fn type_info() -> PgTypeInfo {
+        PgTypeInfo::with_name("_hstore")
+    }
+}
+
+impl Type<Postgres> for Vec<PgHstore> {
+    fn type_info() -> PgTypeInfo {
+        <[PgHstore] as Type<Postgres>>::type_info()
+    }
+}
+
+impl Encode<'_, Postgres> for PgHstore {
+    fn encode_by_ref(&self, buf: &mut PgArgumentBuffer) -> IsNull {
+        // https://github.com/postgres/postgres/blob/REL_13_0/contrib/hstore/hstore_io.c#L1226
+        buf.extend(&(self.0.len() as i32).to_be_bytes());
+
+        for (key, value) in &self.0 {
+            buf.extend(&(key.len() as i32).to_be_bytes());
+            buf.extend(key.as_bytes());
+
+            match value {
+                Some(value) => {
+                    buf.extend(&(value.len() as i32).to_be_bytes());
+                    buf.extend(value.as_bytes());
+                }
+
+                None => {
+                    buf.extend(&(-1_i32).to_be_bytes());
+                }
+            }
+        }
+
+        IsNull::No
+    }
+}
+
+impl Decode<'_, Postgres> for PgHstore {
+    fn decode(value: PgValueRef<'_>) -> Result<Self, BoxDynError> {
+        match value.format() {
+            PgValueFormat::Binary => {
+                let mut buf = value.as_bytes()?;
+                let mut map = BTreeMap::new();
+
+                let count = read_len(&mut buf)?
+                    .ok_or("encountered an `hstore` with a negative number of pairs")?;
+
+                for _ in 0..count {
+                    let key = read_str(&mut buf)?
+                        .ok_or("encountered a NULL key in an `hstore`")?
+                        .to_owned();
+
+                    let value = read_str(&mut buf)?.map(str::to_owned);
+
+                    map.insert(key, value);
+                }
+
+                Ok(Self(map))
+            }
+
+            PgValueFormat::Text => parse_text(value.as_str()?).map(Self),
+        }
+    }
+}
+
+// reads a length prefix; a negative length marks a NULL
+fn read_len(buf: &mut &[u8]) -> Result<Option<usize>, BoxDynError> {
+    if buf.len() < 4 {
+        return Err("unexpected end of `hstore` data".into());
+    }
+
+    let len = buf.get_i32();
+
+    Ok(if len < 0 { None } else { Some(len as usize) })
+}
+
+fn read_str<'r>(buf: &mut &'r [u8]) -> Result<Option<&'r str>, BoxDynError> {
+    let len = match read_len(buf)? {
+        Some(len) => len,
+        None => return Ok(None),
+    };
+
+    if buf.len() < len {
+        return Err("unexpected end of `hstore` data".into());
+    }
+
+    let s = std::str::from_utf8(&buf[..len])?;
+    buf.advance(len);
+
+    Ok(Some(s))
+}
+
+// parses the text form of an `hstore`, e.g., `"a"=>"1", "b"=>NULL`
+// https://github.com/postgres/postgres/blob/REL_13_0/contrib/hstore/hstore_io.c#L170
+fn parse_text(s: &str) -> Result<BTreeMap<String, Option<String>>, BoxDynError> {
+    let mut map = BTreeMap::new();
+    let mut chars = s.chars().peekable();
+
+    loop {
+        skip_whitespace(&mut chars);
+
+        if chars.peek().is_none() {
+            break;
+        }
+
+        let key = parse_token(&mut chars)?.ok_or("encountered a NULL key in an `hstore`")?;
+
+        skip_whitespace(&mut chars);
+
+        if !(chars.next() == Some('=') && chars.next() == Some('>')) {
+            return Err(format!("expected `=>` after key {:?} in an `hstore`", key).into());
+        }
+
+        skip_whitespace(&mut chars);
+
+        let value = parse_token(&mut chars)?;
+
+        // the first occurrence of a key wins, as it does in postgres
+        map.entry(key).or_insert(value);
+
+        skip_whitespace(&mut chars);
+
+        match chars.next() {
+            Some(',') | None => {}
+            Some(c) => {
+                return Err(format!("unexpected character {:?} in an `hstore`", c).into());
+            }
+        }
+    }
+
+    Ok(map)
+}
+
+fn skip_whitespace(chars: &mut std::iter::Peekable<std::str::Chars<'_>>) {
+    while matches!(chars.peek(), Some(c) if c.is_whitespace()) {
+        chars.next();
+    }
+}
+
+// parses a quoted or unquoted token; an unquoted `NULL` is returned as `None`
+fn parse_token(
+    chars: &mut std::iter::Peekable<std::str::Chars<'_>>,
+) -> Result<Option<String>, BoxDynError> {
+    let mut token = String::new();
+
+    if chars.peek() == Some(&'"') {
+        chars.next();
+
+        loop {
+            match chars.next() {
+                Some('"') => return Ok(Some(token)),
+                Some('\\') => token.push(chars.next().ok_or("unexpected end of `hstore` data")?),
+                Some(c) => token.push(c),
+                None => return Err("unexpected end of `hstore` data".into()),
+            }
+        }
+    }
+
+    while let Some(&c) = chars.peek() {
+        if c.is_whitespace() || c == ',' || c == '=' {
+            break;
+        }
+
+        chars.next();
+
+        if c == '\\' {
+            token.push(chars.next().ok_or("unexpected end of `hstore` data")?);
+        } else {
+            token.push(c);
+        }
+    }
+
+    if token.is_empty() {
+        return Err("expected a key or value in an `hstore`".into());
+    }
+
+    Ok(if token.eq_ignore_ascii_case("NULL") {
+        None
+    } else {
+        Some(token)
+    })
+}
+
+#[test]
+fn test_parse_text() {
+    let map = parse_text(r#""a"=>"1", "b c"=>NULL, "q\"\\"=>"x=>y", d => e"#).unwrap();
+
+    assert_eq!(map.len(), 4);
+    assert_eq!(map["a"].as_deref(), Some("1"));
+    assert_eq!(map["b c"], None);
+    assert_eq!(map["q\"\\"].as_deref(), Some("x=>y"));
+    assert_eq!(map["d"].as_deref(), Some("e"));
+
+    assert!(parse_text("").unwrap().is_empty());
+    assert!(parse_text(r#""a"=>"#).is_err());
+    assert!(parse_text(r#""a" "b""#).is_err());
+}
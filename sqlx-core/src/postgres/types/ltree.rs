@@ -0,0 +1,300 @@
+use std::error::Error;
+use std::fmt::{self, Display, Formatter};
+use std::ops::Deref;
+use std::str::FromStr;
+
+use crate::decode::Decode;
+use crate::encode::{Encode, IsNull};
+use crate::error::BoxDynError;
+use crate::postgres::{PgArgumentBuffer, PgTypeInfo, PgValueFormat, PgValueRef, Postgres};
+use crate::types::Type;
+
+// the only version of the binary format of `ltree` and `lquery`
+// https://github.com/postgres/postgres/blob/REL_13_0/contrib/ltree/ltree_io.c#L202
+const VERSION: u8 = 1;
+
+/// A label path of the [`ltree`] extension, such as `Top.Science.Astronomy`.
+///
+/// Each label must be non-empty and consist of alphanumeric characters, underscores
+/// and hyphens; whether hyphens are accepted depends on the version of the server.
+///
+/// The `ltree` extension must be created in the database (`CREATE EXTENSION ltree`).
+/// Sending or receiving `ltree` values in a prepared query requires PostgreSQL 13 or
+/// newer, which introduced the binary format of the type.
+///
+/// [`ltree`]: https://www.postgresql.org/docs/current/ltree.html
+#[derive(Debug, Clone, PartialEq, Eq, Hash, PartialOrd, Ord, Default)]
+pub struct PgLTree {
+    labels: Vec<String>,
+}
+
+/// The error returned when a label path is not a valid [`PgLTree`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum PgLTreeParseError {
+    /// A label was empty.
+    EmptyLabel,
+
+    /// A label contained a character that is not allowed.
+    InvalidCharacter(char),
+}
+
+impl Display for PgLTreeParseError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match self {
+            PgLTreeParseError::EmptyLabel => f.write_str("ltree label cannot be empty"),
+
+            PgLTreeParseError::InvalidCharacter(c) => {
+                write!(f, "ltree label contains an invalid character {:?}", c)
+            }
+        }
+    }
+}
+
+impl Error for PgLTreeParseError {}
+
+impl PgLTree {
+    /// Creates an empty label path.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Creates a label path from a sequence of labels, validating each of them.
+    pub fn from_labels<I, S>(labels: I) -> Result<Self, PgLTreeParseError>
+    where
+        I: IntoIterator<Item = S>,
+        S: Into<String>,
+    {
+        let mut ltree = Self::new();
+
+        for label in labels {
+            ltree.push(label)?;
+        }
+
+        Ok(ltree)
+    }
+
+    /// Appends a label to the end of the path.
+    pub fn push(&mut self, label: impl Into<String>) -> Result<(), PgLTreeParseError> {
+        let label = label.into();
+
+        validate_label(&label)?;
+        self.labels.push(label);
+
+        Ok(())
+    }
+
+    /// Removes the last label from the path and returns it, or `None` if the path is empty.
+    pub fn pop(&mut self) -> Option<String> {
+        self.labels.pop()
+    }
+
+    /// Consumes the path, returning its labels.
+    pub fn into_labels(self) -> Vec<String> {
+        self.labels
+    }
+}
+
+fn validate_label(label: &str) -> Result<(), PgLTreeParseError> {
+    if label.is_empty() {
+        return Err(PgLTreeParseError::EmptyLabel);
+    }
+
+    match label
+        .chars()
+        .find(|&c| !(c.is_alphanumeric() || c == '_' || c == '-'))
+    {
+        Some(c) => Err(PgLTreeParseError::InvalidCharacter(c)),
+        None => Ok(()),
+    }
+}
+
+impl Deref for PgLTree {
+    type Target = [String];
+
+    fn deref(&self) -> &Self::Target {
+        &self.labels
+    }
+}
+
+impl IntoIterator for PgLTree {
+    type Item = String;
+    type IntoIter = std::vec::IntoIter<String>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.labels.into_iter()
+    }
+}
+
+impl FromStr for PgLTree {
+    type Err = PgLTreeParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        // the empty string is the empty path
+        if s.is_empty() {
+            return Ok(Self::new());
+        }
+
+        Self::from_labels(s.split('.'))
+    }
+}
+
+impl Display for PgLTree {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        for (i, label) in self.labels.iter().enumerate() {
+            if i > 0 {
+                f.write_str(".")?;
+            }
+
+            f.write_str(label)?;
+        }
+
+        Ok(())
+    }
+}
+
+impl Type<Postgres> for PgLTree {
+    fn type_info() -> PgTypeInfo {
+        PgTypeInfo::with_name("ltree")
+    }
+}
+
+impl Type<Postgres> for [PgLTree] {
+    fn type_info() -> PgTypeInfo {
+        PgTypeInfo::with_name("_ltree")
+    }
+}
+
+impl Type<Postgres> for Vec<PgLTree> {
+    fn type_info() -> PgTypeInfo {
+        <[PgLTree] as Type<Postgres>>::type_info()
+    }
+}
+
+impl Encode<'_, Postgres> for PgLTree {
+    fn encode_by_ref(&self, buf: &mut PgArgumentBuffer) -> IsNull {
+        buf.push(VERSION);
+        buf.extend(self.to_string().as_bytes());
+
+        IsNull::No
+    }
+}
+
+impl Decode<'_, Postgres> for PgLTree {
+    fn decode(value: PgValueRef<'_>) -> Result<Self, BoxDynError> {
+        Ok(decode_text(value, "ltree")?.parse()?)
+    }
+}
+
+/// A pattern of the [`ltree`] extension for matching label paths, such as `*.Astronomy.*`.
+///
+/// The pattern is kept as written and is only validated by the server.
+///
+/// The `ltree` extension must be created in the database (`CREATE EXTENSION ltree`).
+/// Sending or receiving `lquery` values in a prepared query requires PostgreSQL 13 or
+/// newer, which introduced the binary format of the type.
+///
+/// [`ltree`]: https://www.postgresql.org/docs/current/ltree.html
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct PgLQuery(String);
+
+impl PgLQuery {
+    /// Creates a pattern from its text form.
+    pub fn new(query: impl Into<String>) -> Self {
+        Self(query.into())
+    }
+
+    /// Returns the text form of the pattern.
+    pub fn as_str(&self) -> &str {
+        &self.0
+    }
+}
+
+impl From<String> for PgLQuery {
+    fn from(query: String) -> Self {
+        Self(query)
+    }
+}
+
+impl From<&'_ str> for PgLQuery {
+    fn from(query: &str) -> Self {
+        Self(query.to_owned())
+    }
+}
+
+impl Display for PgLQuery {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.0)
+    }
+}
+
+impl Type<Postgres> for PgLQuery {
+    fn type_info() -> PgTypeInfo {
+        PgTypeInfo::with_name("lquery")
+    }
+}
+
+impl Type<Postgres> for [PgLQuery] {
+    fn type_info() -> PgTypeInfo {
+        PgTypeInfo::with_name("_lquery")
+    }
+}
+
+impl Type<Postgres> for Vec<PgLQuery> {
+    fn type_info() -> PgTypeInfo {
+        <[PgLQuery] as Type<Postgres>>::type_info()
+    }
+}
+
+impl Encode<'_, Postgres> for PgLQuery {
+    fn encode_by_ref(&self, buf: &mut PgArgumentBuffer) -> IsNull {
+        buf.push(VERSION);
+        buf.extend(self.0.as_bytes());
+
+        IsNull::No
+    }
+}
+
+impl Decode<'_, Postgres> for PgLQuery {
+    fn decode(value: PgValueRef<'_>) -> Result<Self, BoxDynError> {
+        Ok(Self(decode_text(value, "lquery")?.to_owned()))
+    }
+}
+
+// the binary format is the text format prefixed with a version number
+fn decode_text<'r>(value: PgValueRef<'r>, name: &str) -> Result<&'r str, BoxDynError> {
+    match value.format() {
+        PgValueFormat::Binary => {
+            let buf = value.as_bytes()?;
+
+            match buf.split_first() {
+                Some((&VERSION, text)) => Ok(std::str::from_utf8(text)?),
+
+                Some((version, _)) => {
+                    Err(format!("unsupported `{}` binary format version {}", name, version).into())
+                }
+
+                None => Err(format!("unexpected end of `{}` data", name).into()),
+            }
+        }
+
+        PgValueFormat::Text => Ok(value.as_str()?),
+    }
+}
+
+#[test]
+fn test_parse_ltree() {
+    let ltree: PgLTree = "Top.Science.Astronomy".parse().unwrap();
+
+    assert_eq!(&*ltree, ["Top", "Science", "Astronomy"]);
+    assert_eq!(ltree.to_string(), "Top.Science.Astronomy");
+
+    assert!("".parse::<PgLTree>().unwrap().is_empty());
+    assert_eq!(
+        "Top..Science".parse::<PgLTree>(),
+        Err(PgLTreeParseError::EmptyLabel)
+    );
+    assert_eq!(
+        "Top.Sci ence".parse::<PgLTree>(),
+        Err(PgLTreeParseError::InvalidCharacter(' '))
+    );
+}
@@ -0,0 +1,86 @@
+use std::convert::TryInto;
+
+use mac_address::MacAddress;
+
+use crate::decode::Decode;
+use crate::encode::{Encode, IsNull};
+use crate::error::BoxDynError;
+use crate::postgres::{PgArgumentBuffer, PgTypeInfo, PgValueFormat, PgValueRef, Postgres};
+use crate::types::Type;
+
+// https://www.postgresql.org/docs/current/datatype-net-types.html#DATATYPE-MACADDR8
+
+impl Type<Postgres> for MacAddress {
+    fn type_info() -> PgTypeInfo {
+        PgTypeInfo::MACADDR
+    }
+
+    fn compatible(ty: &PgTypeInfo) -> bool {
+        // a MACADDR8 can be decoded if it holds a 6-byte address
+        *ty == PgTypeInfo::MACADDR || *ty == PgTypeInfo::MACADDR8
+    }
+}
+
+impl Type<Postgres> for [MacAddress] {
+    fn type_info() -> PgTypeInfo {
+        PgTypeInfo::MACADDR_ARRAY
+    }
+
+    fn compatible(ty: &PgTypeInfo) -> bool {
+        *ty == PgTypeInfo::MACADDR_ARRAY || *ty == PgTypeInfo::MACADDR8_ARRAY
+    }
+}
+
+impl Type<Postgres> for Vec<MacAddress> {
+    fn type_info() -> PgTypeInfo {
+        <[MacAddress] as Type<Postgres>>::type_info()
+    }
+
+    fn compatible(ty: &PgTypeInfo) -> bool {
+        <[MacAddress] as Type<Postgres>>::compatible(ty)
+    }
+}
+
+impl Encode<'_, Postgres> for MacAddress {
+    fn encode_by_ref(&self, buf: &mut PgArgumentBuffer) -> IsNull {
+        buf.extend_from_slice(&self.bytes());
+
+        IsNull::No
+    }
+
+    fn size_hint(&self) -> usize {
+        6
+    }
+}
+
+impl Decode<'_, Postgres> for MacAddress {
+    fn decode(value: PgValueRef<'_>) -> Result<Self, BoxDynError> {
+        let bytes = match value.format() {
+            PgValueFormat::Binary => value.as_bytes()?.to_vec(),
+
+            // the text format is hex digits separated by `:`, e.g., `08:00:2b:01:02:03`
+            PgValueFormat::Text => value
+                .as_str()?
+                .split(':')
+                .map(|part| u8::from_str_radix(part, 16))
+                .collect::<Result<_, _>>()?,
+        };
+
+        match bytes.len() {
+            6 => Ok(MacAddress::new(bytes[..].try_into()?)),
+
+            // a 6-byte address is stored in a MACADDR8 with `ff:fe` inserted in the middle
+            8 if bytes[3..5] == [0xff, 0xfe] => {
+                let mut address = [0; 6];
+                address[..3].copy_from_slice(&bytes[..3]);
+                address[3..].copy_from_slice(&bytes[5..]);
+
+                Ok(MacAddress::new(address))
+            }
+
+            8 => Err("MACADDR8 value does not hold a 6-byte address".into()),
+
+            len => Err(format!("expected a MAC address of 6 or 8 bytes, got {}", len).into()),
+        }
+    }
+}
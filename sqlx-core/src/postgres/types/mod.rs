@@ -16,10 +16,44 @@
 //! | [`PgInterval`]                        | INTERVAL                                             |
 //! | [`PgRange<T>`]                        | INT8RANGE, INT4RANGE, TSRANGE, TSTZTRANGE, DATERANGE, NUMRANGE |
 //! | [`PgMoney`]                           | MONEY                                                |
+//! | [`PgPoint`]                           | POINT                                                |
+//! | [`PgLine`]                            | LINE                                                 |
+//! | [`PgLSeg`]                            | LSEG                                                 |
+//! | [`PgBox`]                             | BOX                                                  |
+//! | [`PgPath`]                            | PATH                                                 |
+//! | [`PgPolygon`]                         | POLYGON                                              |
+//! | [`PgCircle`]                          | CIRCLE                                               |
 //!
 //! [`PgInterval`]: struct.PgInterval.html
 //! [`PgRange<T>`]: struct.PgRange.html
 //! [`PgMoney`]: struct.PgMoney.html
+//! [`PgPoint`]: struct.PgPoint.html
+//! [`PgLine`]: struct.PgLine.html
+//! [`PgLSeg`]: struct.PgLSeg.html
+//! [`PgBox`]: struct.PgBox.html
+//! [`PgPath`]: struct.PgPath.html
+//! [`PgPolygon`]: struct.PgPolygon.html
+//! [`PgCircle`]: struct.PgCircle.html
+//!
+//! ### Extensions
+//!
+//! The following types are provided by extensions that ship with Postgres and must be created
+//! in the database before use (`CREATE EXTENSION <name>`). Their OIDs differ between databases
+//! and are looked up by name the first time a value is sent on a connection.
+//!
+//! | Rust type                             | Postgres type(s)                                     |
+//! |---------------------------------------|------------------------------------------------------|
+//! | [`PgHstore`]                          | HSTORE                                               |
+//! | [`PgLTree`]                           | LTREE                                                |
+//! | [`PgLQuery`]                          | LQUERY                                               |
+//! | [`PgCiText`]                          | CITEXT                                               |
+//!
+//! `&str` and `String` may also be decoded from a `CITEXT` column.
+//!
+//! [`PgHstore`]: struct.PgHstore.html
+//! [`PgLTree`]: struct.PgLTree.html
+//! [`PgLQuery`]: struct.PgLQuery.html
+//! [`PgCiText`]: struct.PgCiText.html
 //!
 //! ### [`bigdecimal`](https://crates.io/crates/bigdecimal)
 //! Requires the `bigdecimal` Cargo feature flag.
@@ -78,6 +112,24 @@
 //! |---------------------------------------|------------------------------------------------------|
 //! | `ipnetwork::IpNetwork`                | INET, CIDR                                           |
 //!
+//! ### [`mac_address`](https://crates.io/crates/mac_address)
+//!
+//! Requires the `mac_address` Cargo feature flag.
+//!
+//! | Rust type                             | Postgres type(s)                                     |
+//! |---------------------------------------|------------------------------------------------------|
+//! | `mac_address::MacAddress`             | MACADDR, MACADDR8                                    |
+//!
+//! A `MACADDR8` can only be decoded if it holds a 6-byte address.
+//!
+//! ### [`bit-vec`](https://crates.io/crates/bit-vec)
+//!
+//! Requires the `bit-vec` Cargo feature flag.
+//!
+//! | Rust type                             | Postgres type(s)                                     |
+//! |---------------------------------------|------------------------------------------------------|
+//! | `bit_vec::BitVec`                     | BIT, VARBIT                                          |
+//!
 //! ### [`json`](https://crates.io/crates/serde_json)
 //!
 //! Requires the `json` Cargo feature flag.
@@ -124,6 +176,18 @@
 //!
 //! One-dimensional arrays are supported as `Vec<T>` or `&[T]` where `T` implements `Type`.
 //!
+//! Multi-dimensional arrays are supported as nested vectors, such as `Vec<Vec<T>>` for
+//! `INTEGER[][]`, or as a [`PgArray<T>`] of any number of dimensions. A nested vector must be
+//! rectangular and each of its dimensions must start at `1`; a [`PgArray<T>`] keeps the lower
+//! bound of each dimension.
+//!
+//! Encoding a nested vector that is not rectangular, or that contains a `NULL` sub-array (as
+//! in `Vec<Option<Vec<T>>>`), panics. [`PgArray::try_new`] checks the shape of an array
+//! without panicking.
+//!
+//! [`PgArray<T>`]: struct.PgArray.html
+//! [`PgArray::try_new`]: struct.PgArray.html#method.try_new
+//!
 //! # [Enumerations](https://www.postgresql.org/docs/current/datatype-enum.html)
 //!
 //! User-defined enumerations are supported through a derive for `Type`.
@@ -156,9 +220,13 @@ use crate::types::Type;
 mod array;
 mod bool;
 mod bytes;
+mod citext;
 mod float;
+mod geometry;
+mod hstore;
 mod int;
 mod interval;
+mod ltree;
 mod money;
 mod range;
 mod record;
@@ -193,7 +261,18 @@ mod json;
 #[cfg(feature = "ipnetwork")]
 mod ipnetwork;
 
+#[cfg(feature = "mac_address")]
+mod mac_address;
+
+#[cfg(feature = "bit-vec")]
+mod bit_vec;
+
+pub use array::{PgArray, PgArrayDimension};
+pub use citext::PgCiText;
+pub use geometry::{PgBox, PgCircle, PgLSeg, PgLine, PgPath, PgPoint, PgPolygon};
+pub use hstore::PgHstore;
 pub use interval::PgInterval;
+pub use ltree::{PgLQuery, PgLTree, PgLTreeParseError};
 pub use money::PgMoney;
 pub use range::PgRange;
 
@@ -19,6 +19,7 @@ impl Type<Postgres> for str {
             PgTypeInfo::UNKNOWN,
         ]
         .contains(ty)
+            || ty.is_citext()
     }
 }
 
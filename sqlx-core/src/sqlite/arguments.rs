@@ -17,6 +17,19 @@ pub enum SqliteArgumentValue<'q> {
     Int64(i64),
 }
 
+impl SqliteArgumentValue<'_> {
+    // the storage class of the value; used when logging (redacted) parameters
+    pub(crate) fn type_name(&self) -> &'static str {
+        match self {
+            SqliteArgumentValue::Null => "NULL",
+            SqliteArgumentValue::Text(_) => "TEXT",
+            SqliteArgumentValue::Blob(_) => "BLOB",
+            SqliteArgumentValue::Double(_) => "REAL",
+            SqliteArgumentValue::Int(_) | SqliteArgumentValue::Int64(_) => "INTEGER",
+        }
+    }
+}
+
 #[derive(Default)]
 pub struct SqliteArguments<'q> {
     pub(crate) values: Vec<SqliteArgumentValue<'q>>,
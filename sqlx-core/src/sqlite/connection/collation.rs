@@ -1,8 +1,10 @@
 use std::cmp::Ordering;
 use std::ffi::CString;
+use std::fmt::{self, Debug, Formatter};
 use std::os::raw::{c_int, c_void};
 use std::slice;
 use std::str::from_utf8_unchecked;
+use std::sync::Arc;
 
 use libsqlite3_sys::{sqlite3_create_collation_v2, SQLITE_OK, SQLITE_UTF8};
 
@@ -10,7 +12,40 @@ use crate::error::Error;
 use crate::sqlite::connection::handle::ConnectionHandle;
 use crate::sqlite::SqliteError;
 
-unsafe extern "C" fn free_boxed_value<T>(p: *mut c_void) {
+/// A collation to be registered on every connection.
+#[derive(Clone)]
+pub(crate) struct Collation {
+    name: Arc<str>,
+    compare: Arc<dyn Fn(&str, &str) -> Ordering + Send + Sync + 'static>,
+}
+
+impl Collation {
+    pub(crate) fn new<F>(name: &str, compare: F) -> Self
+    where
+        F: Fn(&str, &str) -> Ordering + Send + Sync + 'static,
+    {
+        Self {
+            name: name.into(),
+            compare: Arc::new(compare),
+        }
+    }
+
+    pub(crate) fn create(&self, handle: &ConnectionHandle) -> Result<(), Error> {
+        let compare = Arc::clone(&self.compare);
+
+        create_collation(handle, &self.name, move |l, r| compare(l, r))
+    }
+}
+
+impl Debug for Collation {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Collation")
+            .field("name", &self.name)
+            .finish()
+    }
+}
+
+pub(super) unsafe extern "C" fn free_boxed_value<T>(p: *mut c_void) {
     drop(Box::from_raw(p as *mut T));
 }
 
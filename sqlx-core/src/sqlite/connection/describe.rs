@@ -13,6 +13,8 @@ pub(super) fn describe<'c: 'e, 'q: 'e, 'e>(
     query: &'q str,
 ) -> BoxFuture<'e, Result<Describe<Sqlite>, Error>> {
     Box::pin(async move {
+        conn.wait_until_ready().await?;
+
         // describing a statement from SQLite can be involved
         // each SQLx statement is comprised of multiple SQL statements
 
@@ -65,9 +67,6 @@ pub(super) fn describe<'c: 'e, 'q: 'e, 'e>(
                     if !stepped && statement.read_only() {
                         stepped = true;
 
-                        conn.worker.execute(statement);
-                        conn.worker.wake();
-
                         let _ = conn.worker.step(statement).await;
                     }
 
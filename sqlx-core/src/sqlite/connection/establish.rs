@@ -1,4 +1,6 @@
 use std::io;
+use std::mem::ManuallyDrop;
+use std::os::raw::c_int;
 use std::ptr::{null, null_mut};
 
 use libsqlite3_sys::{
@@ -48,6 +50,8 @@ pub(crate) async fn establish(options: &SqliteConnectOptions) -> Result<SqliteCo
         flags |= SQLITE_OPEN_MEMORY;
     }
 
+    let busy_timeout = options.busy_timeout;
+
     let handle = blocking!({
         let mut handle = null_mut();
 
@@ -85,8 +89,9 @@ pub(crate) async fn establish(options: &SqliteConnectOptions) -> Result<SqliteCo
         // This causes SQLite to automatically sleep in increasing intervals until the time
         // when there is something locked during [sqlite3_step]. This is sync. but we only
         // run [sqlite3_step] in [blocking!] so its okay.
-        // TODO: Allow this timeout to be configured in SqliteOptions
-        status = unsafe { sqlite3_busy_timeout(handle.0.as_ptr(), 5000) };
+        let ms = busy_timeout.as_millis().min(c_int::MAX as u128) as c_int;
+
+        status = unsafe { sqlite3_busy_timeout(handle.0.as_ptr(), ms) };
         if status != SQLITE_OK {
             return Err(Error::Database(Box::new(SqliteError::new(handle.as_ptr()))));
         }
@@ -94,11 +99,21 @@ pub(crate) async fn establish(options: &SqliteConnectOptions) -> Result<SqliteCo
         Ok(handle)
     })?;
 
+    for collation in &options.collations {
+        collation.create(&handle)?;
+    }
+
+    for function in &options.functions {
+        function.create(&handle)?;
+    }
+
     Ok(SqliteConnection {
-        handle,
+        handle: ManuallyDrop::new(handle),
         worker: StatementWorker::new(),
         statements: StatementCache::new(options.statement_cache_capacity),
         statement: None,
         transaction_depth: 0,
+        pending_rollback: None,
+        log_settings: options.log_settings.clone(),
     })
 }
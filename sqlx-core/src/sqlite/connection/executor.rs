@@ -2,11 +2,12 @@ use crate::common::StatementCache;
 use crate::describe::Describe;
 use crate::error::Error;
 use crate::executor::{Execute, Executor};
+use crate::logging::QueryLogger;
 use crate::sqlite::connection::describe::describe;
 use crate::sqlite::statement::{StatementHandle, VirtualStatement};
 use crate::sqlite::{
-    Sqlite, SqliteArguments, SqliteConnection, SqliteDone, SqliteRow, SqliteStatement,
-    SqliteTypeInfo,
+    Sqlite, SqliteArgumentValue, SqliteArguments, SqliteConnection, SqliteDone, SqliteRow,
+    SqliteStatement, SqliteTypeInfo,
 };
 use either::Either;
 use futures_core::future::BoxFuture;
@@ -22,6 +23,17 @@ fn prepare<'a>(
     query: &str,
     persistent: bool,
 ) -> Result<&'a mut VirtualStatement, Error> {
+    // a statement that was not stepped to completion (e.g., by `fetch_optional`) is still
+    // active, which prevents tables from being dropped; only the previously executed
+    // statement may be in that state so we make sure it is reset
+    if let Some(statement) = statement {
+        statement.reset();
+    }
+
+    if let Some(statement) = statements.get_most_recent_mut() {
+        statement.reset();
+    }
+
     if !persistent || !statements.is_enabled() {
         *statement = Some(VirtualStatement::new(query, false)?);
         return Ok(statement.as_mut().unwrap());
@@ -59,6 +71,71 @@ fn bind(
     Ok(n)
 }
 
+impl SqliteConnection {
+    /// Execute one prepared statement once for each set of arguments and return the rows
+    /// affected by each execution, in order.
+    ///
+    /// The statement is prepared once and then reset and re-bound for every set of
+    /// arguments. Any rows returned by the statement are discarded.
+    ///
+    /// If an execution fails, its error is returned and the remaining sets of arguments are
+    /// not executed. Run the batch inside a transaction to have it applied all-or-nothing.
+    pub async fn execute_batch<'q, I>(
+        &mut self,
+        query: &'q str,
+        arguments: I,
+    ) -> Result<Vec<SqliteDone>, Error>
+    where
+        I: IntoIterator<Item = SqliteArguments<'q>>,
+    {
+        self.wait_until_ready().await?;
+
+        let SqliteConnection {
+            handle: ref mut conn,
+            ref mut statements,
+            ref mut statement,
+            ref mut worker,
+            ref log_settings,
+            ..
+        } = self;
+
+        let mut done = Vec::new();
+
+        for arguments in arguments {
+            let mut logger = QueryLogger::new(query, log_settings.clone());
+            logger.parameters(arguments.values.iter().map(SqliteArgumentValue::type_name));
+
+            // checkout from the cache; after the first execution, this resets the statement
+            // and clears its bindings
+            let stmt = prepare(statements, statement, query, true)?;
+
+            let mut num_arguments = 0;
+            let mut changes = 0;
+
+            while let Some((handle, columns, _, last_row_values)) = stmt.prepare(conn)? {
+                num_arguments += arguments.bind(handle, num_arguments)?;
+
+                loop {
+                    SqliteRow::inflate_if_needed(handle, &*columns, last_row_values.take());
+
+                    // any rows are stepped over
+                    if let Either::Left(n) = worker.step(handle).await? {
+                        changes += n;
+                        break;
+                    }
+                }
+            }
+
+            done.push(SqliteDone {
+                changes,
+                last_insert_rowid: unsafe { sqlite3_last_insert_rowid(conn.as_ptr()) },
+            });
+        }
+
+        Ok(done)
+    }
+}
+
 impl<'c> Executor<'c> for &'c mut SqliteConnection {
     type Database = Sqlite;
 
@@ -75,14 +152,23 @@ impl<'c> Executor<'c> for &'c mut SqliteConnection {
         let persistent = query.persistent() && arguments.is_some();
 
         Box::pin(try_stream! {
+            self.wait_until_ready().await?;
+
             let SqliteConnection {
                 handle: ref mut conn,
                 ref mut statements,
                 ref mut statement,
-                ref worker,
+                ref mut worker,
+                ref log_settings,
                 ..
             } = self;
 
+            let mut logger = QueryLogger::new(sql, log_settings.clone());
+
+            if let Some(arguments) = &arguments {
+                logger.parameters(arguments.values.iter().map(SqliteArgumentValue::type_name));
+            }
+
             // prepare statement object (or checkout from cache)
             let stmt = prepare(statements, statement, sql, persistent)?;
 
@@ -93,19 +179,12 @@ impl<'c> Executor<'c> for &'c mut SqliteConnection {
                 // bind values to the statement
                 num_arguments += bind(handle, &arguments, num_arguments)?;
 
-                // tell the worker about the new statement
-                worker.execute(handle);
-
-                // wake up the worker if needed
-                // the worker parks its thread on async-std when not in use
-                worker.wake();
-
                 loop {
                     // save the rows from the _current_ position on the statement
                     // and send them to the still-live row object
                     SqliteRow::inflate_if_needed(handle, &*columns, last_row_values.take());
 
-                    // invoke [sqlite3_step] on the dedicated worker thread
+                    // invoke [sqlite3_step] on a worker thread
                     // this will move us forward one row or finish the statement
                     let s = worker.step(handle).await?;
 
@@ -126,6 +205,8 @@ impl<'c> Executor<'c> for &'c mut SqliteConnection {
                         }
 
                         Either::Right(()) => {
+                            logger.increment_rows();
+
                             let (row, weak_values_ref) = SqliteRow::current(
                                 *handle,
                                 columns,
@@ -175,6 +256,8 @@ impl<'c> Executor<'c> for &'c mut SqliteConnection {
         'c: 'e,
     {
         Box::pin(async move {
+            self.wait_until_ready().await?;
+
             let SqliteConnection {
                 handle: ref mut conn,
                 ref mut statements,
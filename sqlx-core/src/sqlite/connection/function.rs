@@ -0,0 +1,306 @@
+use std::ffi::CString;
+use std::fmt::{self, Debug, Formatter};
+use std::mem::size_of;
+use std::os::raw::{c_char, c_int, c_void};
+use std::panic::{catch_unwind, AssertUnwindSafe};
+use std::ptr::null_mut;
+use std::slice;
+use std::sync::Arc;
+
+use libsqlite3_sys::{
+    sqlite3_aggregate_context, sqlite3_context, sqlite3_create_function_v2, sqlite3_result_blob64,
+    sqlite3_result_double, sqlite3_result_error, sqlite3_result_error_nomem, sqlite3_result_int,
+    sqlite3_result_int64, sqlite3_result_null, sqlite3_result_text64, sqlite3_user_data,
+    sqlite3_value, SQLITE_DETERMINISTIC, SQLITE_OK, SQLITE_TRANSIENT, SQLITE_UTF8,
+};
+
+use crate::encode::{Encode, IsNull};
+use crate::error::{BoxDynError, Error};
+use crate::sqlite::connection::collation::free_boxed_value;
+use crate::sqlite::connection::handle::ConnectionHandle;
+use crate::sqlite::type_info::DataType;
+use crate::sqlite::{
+    Sqlite, SqliteArgumentValue, SqliteError, SqliteTypeInfo, SqliteValue, SqliteValueRef,
+};
+
+/// A user-defined aggregate function.
+///
+/// A new value is created for each group of rows. [`step`](#tymethod.step) is called with the
+/// arguments for each row in the group and [`finalize`](#tymethod.finalize) produces the result.
+///
+/// See [`SqliteConnectOptions::aggregate`](struct.SqliteConnectOptions.html#method.aggregate).
+pub trait SqliteAggregate: Send + 'static {
+    type Output: Encode<'static, Sqlite>;
+
+    /// Adds a row to the aggregate.
+    fn step(&mut self, args: &[SqliteValueRef<'_>]) -> Result<(), BoxDynError>;
+
+    /// Returns the result of the aggregate.
+    fn finalize(self) -> Result<Self::Output, BoxDynError>
+    where
+        Self: Sized;
+}
+
+/// A function to be registered on every connection.
+#[derive(Clone)]
+pub(crate) struct Function {
+    name: Arc<str>,
+    create: Arc<dyn Fn(&ConnectionHandle, &str) -> Result<(), Error> + Send + Sync>,
+}
+
+impl Function {
+    pub(crate) fn scalar<F, R>(name: &str, n_args: i32, deterministic: bool, func: F) -> Self
+    where
+        F: Fn(&[SqliteValueRef<'_>]) -> Result<R, BoxDynError> + Send + Sync + 'static,
+        R: Encode<'static, Sqlite> + 'static,
+    {
+        let func = Arc::new(func);
+
+        Self {
+            name: name.into(),
+            create: Arc::new(move |handle, name| {
+                create_function(
+                    handle,
+                    name,
+                    n_args,
+                    deterministic,
+                    Arc::clone(&func),
+                    Some(call_scalar::<F, R>),
+                    None,
+                    None,
+                )
+            }),
+        }
+    }
+
+    pub(crate) fn aggregate<I, A>(name: &str, n_args: i32, deterministic: bool, init: I) -> Self
+    where
+        I: Fn() -> A + Send + Sync + 'static,
+        A: SqliteAggregate,
+    {
+        let init = Arc::new(init);
+
+        Self {
+            name: name.into(),
+            create: Arc::new(move |handle, name| {
+                create_function(
+                    handle,
+                    name,
+                    n_args,
+                    deterministic,
+                    Arc::clone(&init),
+                    None,
+                    Some(call_step::<I, A>),
+                    Some(call_final::<I, A>),
+                )
+            }),
+        }
+    }
+
+    pub(crate) fn create(&self, handle: &ConnectionHandle) -> Result<(), Error> {
+        (self.create)(handle, &self.name)
+    }
+}
+
+impl Debug for Function {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Function")
+            .field("name", &self.name)
+            .finish()
+    }
+}
+
+type Callback = unsafe extern "C" fn(*mut sqlite3_context, c_int, *mut *mut sqlite3_value);
+
+#[allow(clippy::too_many_arguments)]
+fn create_function<T>(
+    handle: &ConnectionHandle,
+    name: &str,
+    n_args: i32,
+    deterministic: bool,
+    user_data: Arc<T>,
+    func: Option<Callback>,
+    step: Option<Callback>,
+    finalize: Option<unsafe extern "C" fn(*mut sqlite3_context)>,
+) -> Result<(), Error> {
+    let c_name =
+        CString::new(name).map_err(|_| err_protocol!("invalid function name: {}", name))?;
+
+    let mut flags = SQLITE_UTF8;
+
+    if deterministic {
+        flags |= SQLITE_DETERMINISTIC;
+    }
+
+    // the closure is shared by every connection; each holds its own reference to it
+    let user_data: *mut Arc<T> = Box::into_raw(Box::new(user_data));
+
+    // https://www.sqlite.org/c3ref/create_function.html
+    // NOTE: the destructor is called by SQLite if this fails
+    let r = unsafe {
+        sqlite3_create_function_v2(
+            handle.as_ptr(),
+            c_name.as_ptr(),
+            n_args,
+            flags,
+            user_data as *mut c_void,
+            func,
+            step,
+            finalize,
+            Some(free_boxed_value::<Arc<T>>),
+        )
+    };
+
+    if r == SQLITE_OK {
+        Ok(())
+    } else {
+        Err(Error::Database(Box::new(SqliteError::new(handle.as_ptr()))))
+    }
+}
+
+unsafe extern "C" fn call_scalar<F, R>(
+    ctx: *mut sqlite3_context,
+    argc: c_int,
+    argv: *mut *mut sqlite3_value,
+) where
+    F: Fn(&[SqliteValueRef<'_>]) -> Result<R, BoxDynError>,
+    R: Encode<'static, Sqlite>,
+{
+    let func = &*(sqlite3_user_data(ctx) as *const Arc<F>);
+    let args = values(argc, argv);
+    let args: Vec<_> = args.iter().map(SqliteValueRef::value).collect();
+
+    set_result(ctx, call(|| func(&args)));
+}
+
+unsafe extern "C" fn call_step<I, A>(
+    ctx: *mut sqlite3_context,
+    argc: c_int,
+    argv: *mut *mut sqlite3_value,
+) where
+    I: Fn() -> A,
+    A: SqliteAggregate,
+{
+    // https://www.sqlite.org/c3ref/aggregate_context.html
+    // the memory is zeroed on the first call for each group, so it starts as a null pointer
+    let state = sqlite3_aggregate_context(ctx, size_of::<*mut A>() as c_int) as *mut *mut A;
+
+    if state.is_null() {
+        sqlite3_result_error_nomem(ctx);
+        return;
+    }
+
+    if (*state).is_null() {
+        match init::<I, A>(ctx) {
+            Ok(aggregate) => *state = Box::into_raw(Box::new(aggregate)),
+            Err(error) => return set_error(ctx, error),
+        }
+    }
+
+    let aggregate = &mut **state;
+    let args = values(argc, argv);
+    let args: Vec<_> = args.iter().map(SqliteValueRef::value).collect();
+
+    if let Err(error) = call(|| aggregate.step(&args)) {
+        set_error(ctx, error);
+    }
+}
+
+unsafe extern "C" fn call_final<I, A>(ctx: *mut sqlite3_context)
+where
+    I: Fn() -> A,
+    A: SqliteAggregate,
+{
+    // with no rows in the group, no state was created by `call_step`
+    let state = sqlite3_aggregate_context(ctx, 0) as *mut *mut A;
+
+    let aggregate = if state.is_null() || (*state).is_null() {
+        init::<I, A>(ctx)
+    } else {
+        let aggregate = Box::from_raw(*state);
+        *state = null_mut();
+
+        Ok(*aggregate)
+    };
+
+    set_result(
+        ctx,
+        aggregate.and_then(|aggregate| call(|| aggregate.finalize())),
+    );
+}
+
+unsafe fn init<I, A>(ctx: *mut sqlite3_context) -> Result<A, BoxDynError>
+where
+    I: Fn() -> A,
+{
+    let init = &*(sqlite3_user_data(ctx) as *const Arc<I>);
+
+    call(|| Ok(init()))
+}
+
+unsafe fn values(argc: c_int, argv: *mut *mut sqlite3_value) -> Vec<SqliteValue> {
+    if argc <= 0 {
+        return Vec::new();
+    }
+
+    slice::from_raw_parts(argv, argc as usize)
+        .iter()
+        .map(|&value| SqliteValue::new(value, SqliteTypeInfo(DataType::Null)))
+        .collect()
+}
+
+// a panic must not unwind into SQLite
+fn call<T>(f: impl FnOnce() -> Result<T, BoxDynError>) -> Result<T, BoxDynError> {
+    catch_unwind(AssertUnwindSafe(f))
+        .unwrap_or_else(|_| Err("panicked in user-defined function".into()))
+}
+
+unsafe fn set_error(ctx: *mut sqlite3_context, error: BoxDynError) {
+    let message = error.to_string();
+
+    sqlite3_result_error(
+        ctx,
+        message.as_ptr() as *const c_char,
+        message.len() as c_int,
+    );
+}
+
+unsafe fn set_result<R>(ctx: *mut sqlite3_context, result: Result<R, BoxDynError>)
+where
+    R: Encode<'static, Sqlite>,
+{
+    let value = match result {
+        Ok(value) => value,
+        Err(error) => return set_error(ctx, error),
+    };
+
+    let mut buf = Vec::with_capacity(1);
+
+    let value = match value.encode(&mut buf) {
+        IsNull::Yes => SqliteArgumentValue::Null,
+        IsNull::No => buf.pop().unwrap_or(SqliteArgumentValue::Null),
+    };
+
+    // https://www.sqlite.org/c3ref/result_blob.html
+    match value {
+        SqliteArgumentValue::Null => sqlite3_result_null(ctx),
+        SqliteArgumentValue::Int(v) => sqlite3_result_int(ctx, v),
+        SqliteArgumentValue::Int64(v) => sqlite3_result_int64(ctx, v),
+        SqliteArgumentValue::Double(v) => sqlite3_result_double(ctx, v),
+
+        SqliteArgumentValue::Text(v) => sqlite3_result_text64(
+            ctx,
+            v.as_ptr() as *const c_char,
+            v.len() as u64,
+            SQLITE_TRANSIENT(),
+            SQLITE_UTF8 as u8,
+        ),
+
+        SqliteArgumentValue::Blob(v) => sqlite3_result_blob64(
+            ctx,
+            v.as_ptr() as *const c_void,
+            v.len() as u64,
+            SQLITE_TRANSIENT(),
+        ),
+    }
+}
@@ -1,7 +1,8 @@
 use crate::common::StatementCache;
-use crate::connection::Connection;
+use crate::connection::{Connection, LogSettings};
 use crate::error::Error;
 use crate::sqlite::statement::{StatementWorker, VirtualStatement};
+use crate::sqlite::transaction;
 use crate::sqlite::{Sqlite, SqliteConnectOptions};
 use crate::transaction::Transaction;
 use futures_core::future::BoxFuture;
@@ -9,29 +10,41 @@ use futures_util::future;
 use libsqlite3_sys::sqlite3;
 use std::cmp::Ordering;
 use std::fmt::{self, Debug, Formatter};
+use std::mem::{self, ManuallyDrop};
 
 mod collation;
 mod describe;
 pub(crate) mod establish;
 mod executor;
 mod explain;
+mod function;
 mod handle;
 
+pub(crate) use collation::Collation;
+pub(crate) use function::Function;
+pub use function::SqliteAggregate;
 pub(crate) use handle::ConnectionHandle;
 
 /// A connection to a [Sqlite] database.
 pub struct SqliteConnection {
-    pub(crate) handle: ConnectionHandle,
+    // dropped explicitly, after the statements (see `Drop` below)
+    pub(crate) handle: ManuallyDrop<ConnectionHandle>,
     pub(crate) worker: StatementWorker,
 
     // transaction status
     pub(crate) transaction_depth: usize,
 
+    // the depth of a transaction that was dropped while a statement was being stepped;
+    // it is rolled back before the connection is next used
+    pub(crate) pending_rollback: Option<usize>,
+
     // cache of semi-persistent statements
     pub(crate) statements: StatementCache<VirtualStatement>,
 
     // most recent non-persistent statement
     pub(crate) statement: Option<VirtualStatement>,
+
+    log_settings: LogSettings,
 }
 
 impl SqliteConnection {
@@ -45,10 +58,29 @@ impl SqliteConnection {
         name: &str,
         compare: impl Fn(&str, &str) -> Ordering + Send + Sync + 'static,
     ) -> Result<(), Error> {
+        if self.worker.is_stepping() {
+            return Err(err_protocol!(
+                "cannot create a collation while a dropped query is still running"
+            ));
+        }
+
         collation::create_collation(&self.handle, name, compare)
     }
 }
 
+impl SqliteConnection {
+    // will return when the connection is ready for another query
+    pub(crate) async fn wait_until_ready(&mut self) -> Result<(), Error> {
+        self.worker.wait_until_ready().await?;
+
+        if let Some(depth) = self.pending_rollback.take() {
+            transaction::rollback(&self.handle, depth)?;
+        }
+
+        Ok(())
+    }
+}
+
 impl Debug for SqliteConnection {
     fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
         f.debug_struct("SqliteConnection").finish()
@@ -104,10 +136,16 @@ impl Drop for SqliteConnection {
     fn drop(&mut self) {
         // before the connection handle is dropped,
         // we must explicitly drop the statements as the drop-order in a struct is undefined
-        self.statements.clear();
-        self.statement.take();
-
-        // we then explicitly close the worker
-        self.worker.close();
+        let statements = mem::replace(&mut self.statements, StatementCache::new(0));
+        let statement = self.statement.take();
+        let handle = unsafe { ManuallyDrop::take(&mut self.handle) };
+
+        // a worker may still be stepping a statement of this connection; rather than wait for
+        // it here, the connection is then closed on the worker thread once it is done
+        self.worker.close(move || {
+            drop(statements);
+            drop(statement);
+            drop(handle);
+        });
     }
 }
@@ -2,7 +2,7 @@ use crate::connection::{ConnectOptions, Connection};
 use crate::error::Error;
 use crate::executor::Executor;
 use crate::migrate::MigrateError;
-use crate::migrate::Migration;
+use crate::migrate::{AppliedMigration, AppliedMigrationRow, Migration};
 use crate::migrate::{Migrate, MigrateDatabase};
 use crate::query::query;
 use crate::query_as::query_as;
@@ -74,6 +74,19 @@ CREATE TABLE IF NOT EXISTS _sqlx_migrations (
         })
     }
 
+    fn migrations_table_exists(&mut self) -> BoxFuture<'_, Result<bool, MigrateError>> {
+        Box::pin(async move {
+            // language=SQLite
+            let exists: bool = query_scalar(
+                "SELECT EXISTS (SELECT 1 FROM sqlite_master WHERE type = 'table' AND name = '_sqlx_migrations')",
+            )
+            .fetch_one(self)
+            .await?;
+
+            Ok(exists)
+        })
+    }
+
     fn version(&mut self) -> BoxFuture<'_, Result<Option<(i64, bool)>, MigrateError>> {
         Box::pin(async move {
             // language=SQLite
@@ -87,6 +100,25 @@ CREATE TABLE IF NOT EXISTS _sqlx_migrations (
         })
     }
 
+    fn list_applied_migrations(
+        &mut self,
+    ) -> BoxFuture<'_, Result<Vec<AppliedMigration>, MigrateError>> {
+        Box::pin(async move {
+            // language=SQLite
+            let rows: Vec<AppliedMigrationRow> = query_as(
+                r#"
+    SELECT version, description, installed_on, success, checksum, execution_time
+    FROM _sqlx_migrations
+    ORDER BY version
+                "#,
+            )
+            .fetch_all(self)
+            .await?;
+
+            Ok(rows.into_iter().map(AppliedMigration::from_row).collect())
+        })
+    }
+
     fn lock(&mut self) -> BoxFuture<'_, Result<(), MigrateError>> {
         Box::pin(async move { Ok(()) })
     }
@@ -124,12 +156,22 @@ CREATE TABLE IF NOT EXISTS _sqlx_migrations (
         migration: &'m Migration,
     ) -> BoxFuture<'m, Result<Duration, MigrateError>> {
         Box::pin(async move {
-            let mut tx = self.begin().await?;
             let start = Instant::now();
 
-            let _ = tx.execute(&*migration.sql).await?;
+            let res = if migration.no_tx {
+                // a failure is recorded below as the script may have been partially applied;
+                // this leaves the database dirty
+                self.execute(&*migration.sql).await.map(drop)
+            } else {
+                let mut tx = self.begin().await?;
 
-            tx.commit().await?;
+                let _ = tx.execute(&*migration.sql).await?;
+
+                // nothing is recorded if the transaction fails to commit, as it was rolled back
+                tx.commit().await?;
+
+                Ok(())
+            };
 
             let elapsed = start.elapsed();
 
@@ -137,17 +179,66 @@ CREATE TABLE IF NOT EXISTS _sqlx_migrations (
             let _ = query(
                 r#"
     INSERT INTO _sqlx_migrations ( version, description, success, checksum, execution_time )
-    VALUES ( ?1, ?2, TRUE, ?3, ?4 )
+    VALUES ( ?1, ?2, ?3, ?4, ?5 )
                 "#,
             )
             .bind(migration.version)
             .bind(&*migration.description)
+            .bind(res.is_ok())
             .bind(&*migration.checksum)
             .bind(elapsed.as_nanos() as i64)
             .execute(self)
             .await?;
 
+            res?;
+
             Ok(elapsed)
         })
     }
+
+    fn revert<'e: 'm, 'm>(
+        &'e mut self,
+        migration: &'m Migration,
+    ) -> BoxFuture<'m, Result<Duration, MigrateError>> {
+        Box::pin(async move {
+            if migration.no_tx {
+                // if the script fails part way through, the migration is left marked as dirty
+
+                // language=SQL
+                let _ = query("UPDATE _sqlx_migrations SET success = FALSE WHERE version = ?1")
+                    .bind(migration.version)
+                    .execute(&mut *self)
+                    .await?;
+
+                let start = Instant::now();
+
+                let _ = self.execute(&*migration.sql).await?;
+
+                let elapsed = start.elapsed();
+
+                // language=SQL
+                let _ = query("DELETE FROM _sqlx_migrations WHERE version = ?1")
+                    .bind(migration.version)
+                    .execute(self)
+                    .await?;
+
+                return Ok(elapsed);
+            }
+
+            let mut tx = self.begin().await?;
+            let start = Instant::now();
+
+            let _ = tx.execute(&*migration.sql).await?;
+
+            // language=SQL
+            let _ = query("DELETE FROM _sqlx_migrations WHERE version = ?1")
+                .bind(migration.version)
+                .execute(&mut tx)
+                .await?;
+
+            tx.commit().await?;
+
+            Ok(start.elapsed())
+        })
+    }
 }
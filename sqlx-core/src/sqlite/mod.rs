@@ -24,11 +24,13 @@ mod migrate;
 
 pub use arguments::{SqliteArgumentValue, SqliteArguments};
 pub use column::SqliteColumn;
-pub use connection::SqliteConnection;
+pub use connection::{SqliteAggregate, SqliteConnection};
 pub use database::Sqlite;
 pub use done::SqliteDone;
 pub use error::SqliteError;
-pub use options::{SqliteConnectOptions, SqliteJournalMode};
+pub use options::{
+    SqliteAutoVacuum, SqliteConnectOptions, SqliteJournalMode, SqliteLockingMode, SqliteSynchronous,
+};
 pub use row::SqliteRow;
 pub use statement::SqliteStatement;
 pub use transaction::SqliteTransactionManager;
@@ -2,8 +2,13 @@ use crate::connection::ConnectOptions;
 use crate::error::Error;
 use crate::executor::Executor;
 use crate::sqlite::connection::establish::establish;
-use crate::sqlite::{SqliteConnectOptions, SqliteConnection};
+use crate::sqlite::options::DEFAULT_PAGE_SIZE;
+use crate::sqlite::{
+    SqliteAutoVacuum, SqliteConnectOptions, SqliteConnection, SqliteLockingMode, SqliteSynchronous,
+};
 use futures_core::future::BoxFuture;
+use log::LevelFilter;
+use std::time::Duration;
 
 impl ConnectOptions for SqliteConnectOptions {
     type Connection = SqliteConnection;
@@ -16,15 +21,56 @@ impl ConnectOptions for SqliteConnectOptions {
             let mut conn = establish(self).await?;
 
             // send an initial sql statement comprised of options
-            let init = format!(
+            // NOTE: settings left at the SQLite default are skipped, as each statement adds to
+            //       the time it takes to connect
+            let mut init = String::new();
+
+            // the page size and auto-vacuum mode can't be changed in WAL mode, so they are set
+            // before the journal mode
+            if self.page_size != DEFAULT_PAGE_SIZE {
+                init += &format!("PRAGMA page_size = {}; ", self.page_size);
+            }
+
+            if !matches!(self.auto_vacuum, SqliteAutoVacuum::None) {
+                init += &format!("PRAGMA auto_vacuum = {}; ", self.auto_vacuum.as_str());
+            }
+
+            if !matches!(self.locking_mode, SqliteLockingMode::Normal) {
+                init += &format!("PRAGMA locking_mode = {}; ", self.locking_mode.as_str());
+            }
+
+            init += &format!(
                 "PRAGMA journal_mode = {}; PRAGMA foreign_keys = {};",
                 self.journal_mode.as_str(),
                 if self.foreign_keys { "ON" } else { "OFF" }
             );
 
+            if !matches!(self.synchronous, SqliteSynchronous::Full) {
+                init += &format!(" PRAGMA synchronous = {};", self.synchronous.as_str());
+            }
+
+            for (key, value) in &self.pragmas {
+                init += &format!(" PRAGMA {} = {};", key, value);
+            }
+
             conn.execute(&*init).await?;
 
             Ok(conn)
         })
     }
+
+    fn log_statements(mut self, level: LevelFilter) -> Self {
+        self.log_settings.log_statements(level);
+        self
+    }
+
+    fn log_slow_statements(mut self, level: LevelFilter, duration: Duration) -> Self {
+        self.log_settings.log_slow_statements(level, duration);
+        self
+    }
+
+    fn log_parameters(mut self, enabled: bool) -> Self {
+        self.log_settings.log_parameters(enabled);
+        self
+    }
 }
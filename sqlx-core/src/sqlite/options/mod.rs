@@ -1,11 +1,27 @@
+use crate::connection::LogSettings;
+use crate::encode::Encode;
+use crate::error::BoxDynError;
+use crate::sqlite::connection::{Collation, Function};
+use crate::sqlite::{Sqlite, SqliteAggregate, SqliteValueRef};
+use std::cmp::Ordering;
 use std::path::Path;
 
+mod auto_vacuum;
 mod connect;
 mod journal_mode;
+mod locking_mode;
 mod parse;
+mod synchronous;
 
+pub use auto_vacuum::SqliteAutoVacuum;
 pub use journal_mode::SqliteJournalMode;
+pub use locking_mode::SqliteLockingMode;
 use std::borrow::Cow;
+use std::time::Duration;
+pub use synchronous::SqliteSynchronous;
+
+// the default page size of SQLite since 3.12.0
+pub(crate) const DEFAULT_PAGE_SIZE: u32 = 4096;
 
 /// Options and flags which can be used to configure a SQLite connection.
 ///
@@ -19,6 +35,8 @@ use std::borrow::Cow;
 /// `sqlite://data.db` | Open the file `data.db` in the current directory. |
 /// `sqlite:///data.db` | Open the file `data.db` from the root (`/`) directory. |
 /// `sqlite://data.db?mode=ro` | Open the file `data.db` for read-only access. |
+/// `sqlite://data.db?busy_timeout=30000&synchronous=normal` | Open the file `data.db`, waiting up to 30 seconds for locks, with `PRAGMA synchronous = NORMAL`. |
+/// `sqlite://data.db?pragma.cache_size=-64000` | Open the file `data.db` with `PRAGMA cache_size = -64000`. A value other than an integer or a keyword is quoted as a string. |
 ///
 /// # Example
 ///
@@ -48,7 +66,16 @@ pub struct SqliteConnectOptions {
     pub(crate) create_if_missing: bool,
     pub(crate) journal_mode: SqliteJournalMode,
     pub(crate) foreign_keys: bool,
+    pub(crate) busy_timeout: Duration,
+    pub(crate) synchronous: SqliteSynchronous,
+    pub(crate) locking_mode: SqliteLockingMode,
+    pub(crate) auto_vacuum: SqliteAutoVacuum,
+    pub(crate) page_size: u32,
+    pub(crate) pragmas: Vec<(Cow<'static, str>, Cow<'static, str>)>,
     pub(crate) statement_cache_capacity: usize,
+    pub(crate) collations: Vec<Collation>,
+    pub(crate) functions: Vec<Function>,
+    pub(crate) log_settings: LogSettings,
 }
 
 impl Default for SqliteConnectOptions {
@@ -65,7 +92,16 @@ impl SqliteConnectOptions {
             read_only: false,
             create_if_missing: false,
             foreign_keys: true,
+            busy_timeout: Duration::from_secs(5),
+            synchronous: SqliteSynchronous::Full,
+            locking_mode: SqliteLockingMode::Normal,
+            auto_vacuum: SqliteAutoVacuum::None,
+            page_size: DEFAULT_PAGE_SIZE,
+            pragmas: Vec::new(),
             statement_cache_capacity: 100,
+            collations: Vec::new(),
+            functions: Vec::new(),
+            log_settings: Default::default(),
             journal_mode: SqliteJournalMode::Wal,
         }
     }
@@ -93,6 +129,75 @@ impl SqliteConnectOptions {
         self
     }
 
+    /// Sets the [busy timeout](https://www.sqlite.org/c3ref/busy_timeout.html): how long to
+    /// wait for a locked database to become available before failing with `SQLITE_BUSY`.
+    ///
+    /// The default busy timeout is 5 seconds.
+    pub fn busy_timeout(mut self, timeout: Duration) -> Self {
+        self.busy_timeout = timeout;
+        self
+    }
+
+    /// Sets the [synchronous](https://www.sqlite.org/pragma.html#pragma_synchronous) setting
+    /// for the database connection.
+    ///
+    /// The default synchronous setting is FULL. In WAL mode, NORMAL is also safe from
+    /// corruption and avoids syncing on most transaction commits.
+    pub fn synchronous(mut self, synchronous: SqliteSynchronous) -> Self {
+        self.synchronous = synchronous;
+        self
+    }
+
+    /// Sets the [locking mode](https://www.sqlite.org/pragma.html#pragma_locking_mode) for the
+    /// database connection.
+    ///
+    /// The default locking mode is NORMAL.
+    pub fn locking_mode(mut self, mode: SqliteLockingMode) -> Self {
+        self.locking_mode = mode;
+        self
+    }
+
+    /// Sets the [auto-vacuum](https://www.sqlite.org/pragma.html#pragma_auto_vacuum) mode for
+    /// the database.
+    ///
+    /// This only takes effect on a database with no tables yet, or after a `VACUUM`.
+    /// By default, auto-vacuum is disabled.
+    pub fn auto_vacuum(mut self, auto_vacuum: SqliteAutoVacuum) -> Self {
+        self.auto_vacuum = auto_vacuum;
+        self
+    }
+
+    /// Sets the [page size](https://www.sqlite.org/pragma.html#pragma_page_size) of the
+    /// database, in bytes.
+    ///
+    /// Like [`auto_vacuum`](#method.auto_vacuum), this only takes effect on a new database
+    /// or after a `VACUUM`. The default page size is 4096 bytes.
+    pub fn page_size(mut self, page_size: u32) -> Self {
+        self.page_size = page_size;
+        self
+    }
+
+    /// Adds a [`PRAGMA`](https://www.sqlite.org/pragma.html) to set on connect.
+    ///
+    /// Pragmas are set in the order they were added, after the settings above.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use sqlx_core::sqlite::SqliteConnectOptions;
+    /// let options = SqliteConnectOptions::new()
+    ///     .pragma("cache_size", "-64000")
+    ///     .pragma("temp_store", "MEMORY");
+    /// ```
+    pub fn pragma<K, V>(mut self, key: K, value: V) -> Self
+    where
+        K: Into<Cow<'static, str>>,
+        V: Into<Cow<'static, str>>,
+    {
+        self.pragmas.push((key.into(), value.into()));
+        self
+    }
+
     /// Sets the [access mode](https://www.sqlite.org/c3ref/open.html) to open the database
     /// for read-only access.
     pub fn read_only(mut self, read_only: bool) -> Self {
@@ -119,4 +224,70 @@ impl SqliteConnectOptions {
         self.statement_cache_capacity = capacity;
         self
     }
+
+    /// Registers a [collating sequence](https://www.sqlite.org/datatype3.html#collation) on
+    /// every connection.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use sqlx_core::sqlite::SqliteConnectOptions;
+    /// let options = SqliteConnectOptions::new()
+    ///     .collation("nocase_ascii", |l, r| {
+    ///         l.to_ascii_lowercase().cmp(&r.to_ascii_lowercase())
+    ///     });
+    /// ```
+    pub fn collation<F>(mut self, name: &str, compare: F) -> Self
+    where
+        F: Fn(&str, &str) -> Ordering + Send + Sync + 'static,
+    {
+        self.collations.push(Collation::new(name, compare));
+        self
+    }
+
+    /// Registers a user-defined [scalar function](https://www.sqlite.org/appfunc.html) on
+    /// every connection.
+    ///
+    /// The function takes `n_args` arguments, or any number if `n_args` is -1. A `deterministic`
+    /// function always returns the same result for the same arguments, which allows SQLite
+    /// to use it in more places, such as indexes.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use sqlx_core::sqlite::{Sqlite, SqliteConnectOptions};
+    /// # use sqlx_core::decode::Decode;
+    /// let options = SqliteConnectOptions::new()
+    ///     .function("shout", 1, true, |args| {
+    ///         let text = <&str as Decode<Sqlite>>::decode(args[0].clone())?;
+    ///
+    ///         Ok(text.to_uppercase())
+    ///     });
+    /// ```
+    pub fn function<F, R>(mut self, name: &str, n_args: i32, deterministic: bool, func: F) -> Self
+    where
+        F: Fn(&[SqliteValueRef<'_>]) -> Result<R, BoxDynError> + Send + Sync + 'static,
+        R: Encode<'static, Sqlite> + 'static,
+    {
+        self.functions
+            .push(Function::scalar(name, n_args, deterministic, func));
+        self
+    }
+
+    /// Registers a user-defined [aggregate function](https://www.sqlite.org/appfunc.html) on
+    /// every connection.
+    ///
+    /// `init` is called to create the [`SqliteAggregate`] for each group of rows. The
+    /// arguments are as for [`function`](#method.function).
+    ///
+    /// [`SqliteAggregate`]: trait.SqliteAggregate.html
+    pub fn aggregate<I, A>(mut self, name: &str, n_args: i32, deterministic: bool, init: I) -> Self
+    where
+        I: Fn() -> A + Send + Sync + 'static,
+        A: SqliteAggregate,
+    {
+        self.functions
+            .push(Function::aggregate(name, n_args, deterministic, init));
+        self
+    }
 }
@@ -4,6 +4,7 @@ use percent_encoding::percent_decode_str;
 use std::borrow::Cow;
 use std::path::Path;
 use std::str::FromStr;
+use std::time::Duration;
 
 // https://www.sqlite.org/uri.html
 
@@ -68,6 +69,41 @@ impl FromStr for SqliteConnectOptions {
                         }
                     }
 
+                    // milliseconds
+                    "busy_timeout" => {
+                        options.busy_timeout =
+                            Duration::from_millis(value.parse().map_err(Error::config)?);
+                    }
+
+                    "synchronous" => {
+                        options.synchronous = value.parse()?;
+                    }
+
+                    "locking_mode" => {
+                        options.locking_mode = value.parse()?;
+                    }
+
+                    "auto_vacuum" => {
+                        options.auto_vacuum = value.parse()?;
+                    }
+
+                    "page_size" => {
+                        options.page_size = value.parse().map_err(Error::config)?;
+                    }
+
+                    // any other pragma, as `pragma.<name>=<value>`
+                    _ if key.starts_with("pragma.") => {
+                        let name = &key["pragma.".len()..];
+
+                        if !is_pragma_name(name) {
+                            return Err(Error::Configuration(
+                                format!("invalid pragma name {:?}", name).into(),
+                            ));
+                        }
+
+                        options = options.pragma(name.to_owned(), pragma_value(&value));
+                    }
+
                     _ => {
                         return Err(Error::Configuration(
                             format!(
@@ -85,6 +121,30 @@ impl FromStr for SqliteConnectOptions {
     }
 }
 
+// the name of a pragma, optionally qualified by a schema, as `main.cache_size`
+fn is_pragma_name(name: &str) -> bool {
+    name.splitn(2, '.').all(is_identifier)
+}
+
+fn is_identifier(s: &str) -> bool {
+    let mut chars = s.chars();
+
+    matches!(chars.next(), Some(c) if c.is_ascii_alphabetic() || c == '_')
+        && chars.all(|c| c.is_ascii_alphanumeric() || c == '_')
+}
+
+// the value of a pragma is sent as is if it is an integer or a keyword, and quoted as a string
+// otherwise
+fn pragma_value(value: &str) -> String {
+    let digits = value.strip_prefix('-').unwrap_or(value);
+
+    if is_identifier(value) || (!digits.is_empty() && digits.chars().all(|c| c.is_ascii_digit())) {
+        return value.to_owned();
+    }
+
+    format!("'{}'", value.replace('\'', "''"))
+}
+
 #[test]
 fn test_parse_in_memory() -> Result<(), Error> {
     let options: SqliteConnectOptions = "sqlite::memory:".parse()?;
@@ -107,3 +167,56 @@ fn test_parse_read_only() -> Result<(), Error> {
 
     Ok(())
 }
+
+#[test]
+fn test_parse_pragmas() -> Result<(), Error> {
+    use crate::sqlite::{SqliteAutoVacuum, SqliteLockingMode, SqliteSynchronous};
+
+    let options: SqliteConnectOptions = "sqlite://a.db?busy_timeout=30000&synchronous=normal\
+        &locking_mode=EXCLUSIVE&auto_vacuum=incremental&page_size=8192\
+        &pragma.cache_size=-64000&pragma.temp_store=memory"
+        .parse()?;
+
+    assert_eq!(options.busy_timeout, Duration::from_secs(30));
+    assert!(matches!(options.synchronous, SqliteSynchronous::Normal));
+    assert!(matches!(options.locking_mode, SqliteLockingMode::Exclusive));
+    assert!(matches!(options.auto_vacuum, SqliteAutoVacuum::Incremental));
+    assert_eq!(options.page_size, 8192);
+    assert_eq!(
+        options.pragmas,
+        vec![
+            ("cache_size".into(), "-64000".into()),
+            ("temp_store".into(), "memory".into())
+        ]
+    );
+
+    assert!("sqlite://a.db?synchronous=sometimes"
+        .parse::<SqliteConnectOptions>()
+        .is_err());
+
+    Ok(())
+}
+
+#[test]
+fn test_parse_pragmas_safely() -> Result<(), Error> {
+    let options: SqliteConnectOptions = "sqlite://a.db?pragma.main.user_version=7\
+        &pragma.application_id=1;%20DROP%20TABLE%20users&pragma.foo=it%27s"
+        .parse()?;
+
+    assert_eq!(
+        options.pragmas,
+        vec![
+            ("main.user_version".into(), "7".into()),
+            ("application_id".into(), "'1; DROP TABLE users'".into()),
+            ("foo".into(), "'it''s'".into())
+        ]
+    );
+
+    assert!(
+        "sqlite://a.db?pragma.cache_size%3D0;%20DROP%20TABLE%20users;--=1"
+            .parse::<SqliteConnectOptions>()
+            .is_err()
+    );
+
+    Ok(())
+}
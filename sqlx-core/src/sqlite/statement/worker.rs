@@ -1,128 +1,157 @@
 use crate::error::Error;
 use crate::sqlite::statement::StatementHandle;
+use crossbeam_channel::{unbounded, Receiver, Sender};
 use either::Either;
-use libsqlite3_sys::sqlite3_stmt;
+use futures_channel::oneshot;
 use libsqlite3_sys::{sqlite3_step, SQLITE_DONE, SQLITE_ROW};
-use sqlx_rt::yield_now;
-use std::ptr::null_mut;
-use std::sync::atomic::{spin_loop_hint, AtomicI32, AtomicPtr, Ordering};
+use once_cell::sync::Lazy;
+use parking_lot::Mutex;
+use std::os::raw::c_int;
+use std::sync::atomic::{AtomicUsize, Ordering};
 use std::sync::Arc;
-use std::thread::{self, park, spawn, JoinHandle};
+use std::thread;
 
-const STATE_CLOSE: i32 = -1;
+// [sqlite3_step] is run on a pool of threads shared by every SQLite connection in the process.
+// Threads are started as they are needed and, up to [MAX_IDLE_THREADS], are kept for reuse.
 
-const STATE_READY: i32 = 0;
+// A statement waiting on a lock (see `SqliteConnectOptions::busy_timeout`) keeps its thread
+// busy until it acquires the lock or times out, so a statement never waits for a thread to be
+// free: one is started whenever none is idle. As a connection steps one statement at a time,
+// there are at most as many busy threads as connections with a statement in progress (for a
+// `Pool`, its `max_connections`).
 
-const STATE_INITIAL: i32 = 1;
+const MAX_IDLE_THREADS: usize = 64;
 
-// Each SQLite connection has a dedicated thread.
+static POOL: Lazy<WorkerPool> = Lazy::new(WorkerPool::new);
 
-// TODO: Tweak this so that we can use a thread pool per pool of SQLite3 connections to reduce
-//       OS resource usage. Low priority because a high concurrent load for SQLite3 is very
-//       unlikely.
+struct Command {
+    // held while the command is run, see [StatementWorker]
+    lock: Arc<Mutex<()>>,
 
-// TODO: Reduce atomic complexity. There must be a simpler way to do this that doesn't
-//       compromise performance.
+    job: Job,
+}
 
-pub(crate) struct StatementWorker {
-    statement: Arc<AtomicPtr<sqlite3_stmt>>,
-    status: Arc<AtomicI32>,
-    handle: Option<JoinHandle<()>>,
+enum Job {
+    Step {
+        statement: StatementHandle,
+        status: oneshot::Sender<c_int>,
+    },
+
+    // closes the connection, see [StatementWorker::close]
+    Close(Box<dyn FnOnce() + Send>),
 }
 
-impl StatementWorker {
-    pub(crate) fn new() -> Self {
-        let statement = Arc::new(AtomicPtr::new(null_mut::<sqlite3_stmt>()));
-        let status = Arc::new(AtomicI32::new(STATE_INITIAL));
-
-        let handle = spawn({
-            let statement = Arc::clone(&statement);
-            let status = Arc::clone(&status);
-
-            move || {
-                // wait for the first command
-                park();
-
-                'run: while status.load(Ordering::Acquire) >= 0 {
-                    'statement: loop {
-                        match status.load(Ordering::Acquire) {
-                            STATE_CLOSE => {
-                                // worker has been dropped; get out
-                                break 'run;
-                            }
-
-                            STATE_READY => {
-                                let statement = statement.load(Ordering::Acquire);
-                                if statement.is_null() {
-                                    // we do not have the statement handle yet
-                                    thread::yield_now();
-                                    continue;
-                                }
-
-                                let v = unsafe { sqlite3_step(statement) };
-
-                                status.store(v, Ordering::Release);
-
-                                if v == SQLITE_DONE {
-                                    // when a statement is _done_, we park the thread until
-                                    // we need it again
-                                    park();
-                                    break 'statement;
-                                }
-                            }
-
-                            _ => {
-                                // waits for the receiving end to be ready to receive the rows
-                                // this should take less than 1 microsecond under most conditions
-                                spin_loop_hint();
-                            }
-                        }
-                    }
-                }
-            }
-        });
+struct WorkerPool {
+    sender: Sender<Command>,
+    receiver: Receiver<Command>,
+
+    // the number of threads waiting for a command
+    idle: AtomicUsize,
+}
+
+impl WorkerPool {
+    fn new() -> Self {
+        let (sender, receiver) = unbounded();
 
         Self {
-            handle: Some(handle),
-            statement,
-            status,
+            sender,
+            receiver,
+            idle: AtomicUsize::new(0),
         }
     }
 
-    pub(crate) fn wake(&self) {
-        if let Some(handle) = &self.handle {
-            handle.thread().unpark();
+    fn submit(&'static self, command: Command) {
+        // the receiver is never dropped
+        let _ = self.sender.send(command);
+
+        // start another thread if there are more commands waiting than idle threads to
+        // receive them
+        if self.sender.len() <= self.idle.load(Ordering::Acquire) {
+            return;
         }
+
+        // NOTE: if a thread cannot be started, the command is left for the threads we have
+        let _ = thread::Builder::new()
+            .name("sqlx-sqlite-worker".into())
+            .spawn(move || self.run());
     }
 
-    pub(crate) fn execute(&self, statement: &StatementHandle) {
-        // readies the worker to execute the statement
-        // for async-std, this unparks our dedicated thread
+    fn run(&self) {
+        loop {
+            self.idle.fetch_add(1, Ordering::AcqRel);
+            let command = self.receiver.recv();
+            self.idle.fetch_sub(1, Ordering::AcqRel);
 
-        self.statement
-            .store(statement.0.as_ptr(), Ordering::Release);
-    }
+            let command = match command {
+                Ok(command) => command,
+                Err(_) => return,
+            };
+
+            let guard = command.lock.lock();
 
-    pub(crate) async fn step(&self, statement: &StatementHandle) -> Result<Either<u64, ()>, Error> {
-        // storing <0> as a terminal in status releases the worker
-        // to proceed to the next [sqlite3_step] invocation
-        self.status.store(STATE_READY, Ordering::Release);
+            match command.job {
+                Job::Step { statement, status } => {
+                    if status.is_canceled() {
+                        // the connection was dropped, and may have been closed
+                        continue;
+                    }
 
-        // we then use a spin loop to wait for this to finish
-        // 99% of the time this should be < 1 μs
-        let status = loop {
-            let status = self
-                .status
-                .compare_and_swap(STATE_READY, STATE_READY, Ordering::AcqRel);
+                    let _ = status.send(unsafe { sqlite3_step(statement.0.as_ptr()) });
+                }
 
-            if status != STATE_READY {
-                break status;
+                Job::Close(close) => close(),
             }
 
-            yield_now().await;
-        };
+            drop(guard);
+
+            if self.idle.load(Ordering::Acquire) >= MAX_IDLE_THREADS {
+                // enough threads are already waiting for the next command
+                return;
+            }
+        }
+    }
+}
+
+pub(crate) struct StatementWorker {
+    // a statement of this connection is only stepped while this is held
+    lock: Arc<Mutex<()>>,
+
+    // the status of the last step, if it was not received before its future was dropped
+    pending: Option<oneshot::Receiver<c_int>>,
+}
 
-        match status {
+impl StatementWorker {
+    pub(crate) fn new() -> Self {
+        Self {
+            lock: Arc::new(Mutex::new(())),
+            pending: None,
+        }
+    }
+
+    pub(crate) async fn step(
+        &mut self,
+        statement: &StatementHandle,
+    ) -> Result<Either<u64, ()>, Error> {
+        self.wait_until_ready().await?;
+
+        let (tx, rx) = oneshot::channel();
+
+        POOL.submit(Command {
+            lock: Arc::clone(&self.lock),
+            job: Job::Step {
+                statement: *statement,
+                status: tx,
+            },
+        });
+
+        // if this future is dropped before the step completes, the receiver is kept and
+        // awaited before the connection is next used
+        self.pending = Some(rx);
+
+        let status = self.pending.as_mut().unwrap().await;
+        self.pending = None;
+
+        match status.map_err(|_| worker_dropped())? {
             // a row was found
             SQLITE_ROW => Ok(Either::Right(())),
 
@@ -134,18 +163,51 @@ impl StatementWorker {
         }
     }
 
-    pub(crate) fn close(&mut self) {
-        self.status.store(STATE_CLOSE, Ordering::Release);
+    // will return when no statement of this connection is being stepped
+    pub(crate) async fn wait_until_ready(&mut self) -> Result<(), Error> {
+        if let Some(pending) = &mut self.pending {
+            // the step was abandoned; only its completion matters, not its status
+            let status = pending.await;
+            self.pending = None;
+
+            status.map_err(|_| worker_dropped())?;
+        }
+
+        Ok(())
+    }
+
+    // returns true if a step that was abandoned may still be running
+    pub(crate) fn is_stepping(&mut self) -> bool {
+        if let Some(pending) = &mut self.pending {
+            if let Ok(None) = pending.try_recv() {
+                return true;
+            }
 
-        if let Some(handle) = self.handle.take() {
-            handle.thread().unpark();
-            handle.join().unwrap();
+            self.pending = None;
         }
+
+        false
     }
-}
 
-impl Drop for StatementWorker {
-    fn drop(&mut self) {
-        self.close();
+    // runs `close` once no statement of this connection is being stepped; if a step that was
+    // abandoned may still be running, that is on a worker thread after the step
+    pub(crate) fn close(&mut self, close: impl FnOnce() + Send + 'static) {
+        if !self.is_stepping() {
+            return close();
+        }
+
+        // once the receiver is dropped, a worker that has yet to start will skip the
+        // statement; a worker that has started holds the lock until it is done
+        self.pending = None;
+
+        POOL.submit(Command {
+            lock: Arc::clone(&self.lock),
+            job: Job::Close(Box::new(close)),
+        });
     }
 }
+
+// a worker only drops the sender without a status if it panicked
+fn worker_dropped() -> Error {
+    err_protocol!("SQLite worker dropped a statement")
+}
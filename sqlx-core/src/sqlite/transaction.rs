@@ -5,6 +5,7 @@ use libsqlite3_sys::{sqlite3_exec, SQLITE_OK};
 
 use crate::error::Error;
 use crate::executor::Executor;
+use crate::sqlite::connection::ConnectionHandle;
 use crate::sqlite::{Sqlite, SqliteConnection, SqliteError};
 use crate::transaction::{
     begin_ansi_transaction_sql, commit_ansi_transaction_sql, rollback_ansi_transaction_sql,
@@ -58,31 +59,39 @@ impl TransactionManager for SqliteTransactionManager {
         let depth = conn.transaction_depth;
 
         if depth > 0 {
-            let query = rollback_ansi_transaction_sql(depth);
-            let mut z_query = String::with_capacity(query.len() + 1);
-            z_query.push_str(&query);
-            z_query.push('\0');
-
-            unsafe {
-                // NOTE: this is a direct execution as a ROLLBACK is unlikely to block
-                //       for any amount of time
-                let status = sqlite3_exec(
-                    conn.handle.as_ptr(),
-                    z_query.as_ptr() as _,
-                    None,
-                    ptr::null_mut(),
-                    ptr::null_mut(),
-                );
-
-                if status != SQLITE_OK {
-                    panic!(
-                        "error occurred while dropping a transaction: {}",
-                        SqliteError::new(conn.handle.as_ptr())
-                    );
-                }
+            if conn.worker.is_stepping() {
+                // a statement is still being stepped on a worker thread; the transaction is
+                // rolled back before the connection is next used
+                conn.pending_rollback = Some(depth);
+            } else if let Err(error) = rollback(&conn.handle, depth) {
+                panic!("error occurred while dropping a transaction: {}", error);
             }
 
             conn.transaction_depth = depth - 1;
         }
     }
 }
+
+// NOTE: this is a direct execution as a ROLLBACK is unlikely to block for any amount of time
+pub(crate) fn rollback(handle: &ConnectionHandle, depth: usize) -> Result<(), Error> {
+    let query = rollback_ansi_transaction_sql(depth);
+    let mut z_query = String::with_capacity(query.len() + 1);
+    z_query.push_str(&query);
+    z_query.push('\0');
+
+    let status = unsafe {
+        sqlite3_exec(
+            handle.as_ptr(),
+            z_query.as_ptr() as _,
+            None,
+            ptr::null_mut(),
+            ptr::null_mut(),
+        )
+    };
+
+    if status != SQLITE_OK {
+        return Err(SqliteError::new(handle.as_ptr()).into());
+    }
+
+    Ok(())
+}
@@ -16,6 +16,7 @@ use crate::sqlite::{Sqlite, SqliteTypeInfo};
 use crate::value::{Value, ValueRef};
 use std::borrow::Cow;
 
+#[derive(Clone)]
 enum SqliteValueData<'r> {
     Statement {
         statement: &'r StatementHandle,
@@ -26,6 +27,7 @@ enum SqliteValueData<'r> {
     Value(&'r SqliteValue),
 }
 
+#[derive(Clone)]
 pub struct SqliteValueRef<'r>(SqliteValueData<'r>);
 
 impl<'r> SqliteValueRef<'r> {
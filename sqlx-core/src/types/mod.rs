@@ -56,6 +56,16 @@ pub mod ipnetwork {
     pub use ipnetwork::{IpNetwork, Ipv4Network, Ipv6Network};
 }
 
+#[cfg(feature = "mac_address")]
+#[cfg_attr(docsrs, doc(cfg(feature = "mac_address")))]
+pub mod mac_address {
+    pub use mac_address::MacAddress;
+}
+
+#[cfg(feature = "bit-vec")]
+#[cfg_attr(docsrs, doc(cfg(feature = "bit-vec")))]
+pub use bit_vec::BitVec;
+
 #[cfg(feature = "json")]
 pub use json::Json;
 
@@ -101,7 +111,8 @@ pub use json::Json;
 ///
 /// With `#[repr(_)]` the integer representation is used when converting from/to SQL and expects
 /// that SQL type (e.g., `INT`). Without, the names of the variants are used instead and
-/// expects a textual SQL type (e.g., `VARCHAR`, `TEXT`).
+/// expects a textual SQL type (e.g., `VARCHAR`, `TEXT`); an `ENUM` in MySQL, or a type created
+/// with `CREATE TYPE .. AS ENUM` in PostgreSQL.
 ///
 /// ```rust,ignore
 /// #[derive(sqlx::Type)]
@@ -9,10 +9,49 @@ impl_database_ext! {
         i64,
         f32,
         f64,
+
+        // ordering is important here as otherwise we might infer strings to be binary
+        // NVARCHAR, NCHAR, VARCHAR, CHAR, NTEXT, TEXT, XML
         String,
+
+        // VARBINARY, BINARY, IMAGE
+        Vec<u8>,
+
+        #[cfg(all(feature = "chrono", not(feature = "time")))]
+        sqlx::types::chrono::NaiveTime,
+
+        #[cfg(all(feature = "chrono", not(feature = "time")))]
+        sqlx::types::chrono::NaiveDate,
+
+        #[cfg(all(feature = "chrono", not(feature = "time")))]
+        sqlx::types::chrono::NaiveDateTime,
+
+        #[cfg(all(feature = "chrono", not(feature = "time")))]
+        sqlx::types::chrono::DateTime<sqlx::types::chrono::FixedOffset>,
+
+        #[cfg(feature = "time")]
+        sqlx::types::time::Time,
+
+        #[cfg(feature = "time")]
+        sqlx::types::time::Date,
+
+        #[cfg(feature = "time")]
+        sqlx::types::time::PrimitiveDateTime,
+
+        #[cfg(feature = "time")]
+        sqlx::types::time::OffsetDateTime,
+
+        #[cfg(feature = "bigdecimal")]
+        sqlx::types::BigDecimal,
+
+        #[cfg(feature = "decimal")]
+        sqlx::types::Decimal,
+
+        #[cfg(feature = "uuid")]
+        sqlx::types::Uuid,
     },
     ParamChecking::Weak,
-    feature-types: _info => None,
+    feature-types: info => info.__type_feature_gate(),
     row = sqlx::mssql::MssqlRow,
     name = "MSSQL"
 }
@@ -16,6 +16,19 @@ impl_database_ext! {
 
         sqlx::postgres::types::PgInterval,
 
+        sqlx::postgres::types::PgHstore,
+        sqlx::postgres::types::PgLTree,
+        sqlx::postgres::types::PgLQuery,
+        sqlx::postgres::types::PgCiText,
+
+        sqlx::postgres::types::PgPoint,
+        sqlx::postgres::types::PgLine,
+        sqlx::postgres::types::PgLSeg,
+        sqlx::postgres::types::PgBox,
+        sqlx::postgres::types::PgPath,
+        sqlx::postgres::types::PgPolygon,
+        sqlx::postgres::types::PgCircle,
+
         #[cfg(feature = "uuid")]
         sqlx::types::Uuid,
 
@@ -58,6 +71,12 @@ impl_database_ext! {
         #[cfg(feature = "ipnetwork")]
         sqlx::types::ipnetwork::IpNetwork,
 
+        #[cfg(feature = "mac_address")]
+        sqlx::types::mac_address::MacAddress,
+
+        #[cfg(feature = "bit-vec")]
+        sqlx::types::BitVec,
+
         #[cfg(feature = "json")]
         serde_json::Value,
 
@@ -73,6 +92,18 @@ impl_database_ext! {
         Vec<f32> | &[f32],
         Vec<f64> | &[f64],
 
+        Vec<sqlx::postgres::types::PgHstore> | &[sqlx::postgres::types::PgHstore],
+        Vec<sqlx::postgres::types::PgLTree> | &[sqlx::postgres::types::PgLTree],
+        Vec<sqlx::postgres::types::PgLQuery> | &[sqlx::postgres::types::PgLQuery],
+        Vec<sqlx::postgres::types::PgCiText> | &[sqlx::postgres::types::PgCiText],
+        Vec<sqlx::postgres::types::PgPoint> | &[sqlx::postgres::types::PgPoint],
+        Vec<sqlx::postgres::types::PgLine> | &[sqlx::postgres::types::PgLine],
+        Vec<sqlx::postgres::types::PgLSeg> | &[sqlx::postgres::types::PgLSeg],
+        Vec<sqlx::postgres::types::PgBox> | &[sqlx::postgres::types::PgBox],
+        Vec<sqlx::postgres::types::PgPath> | &[sqlx::postgres::types::PgPath],
+        Vec<sqlx::postgres::types::PgPolygon> | &[sqlx::postgres::types::PgPolygon],
+        Vec<sqlx::postgres::types::PgCircle> | &[sqlx::postgres::types::PgCircle],
+
         #[cfg(feature = "uuid")]
         Vec<sqlx::types::Uuid> | &[sqlx::types::Uuid],
 
@@ -107,6 +138,12 @@ impl_database_ext! {
         #[cfg(feature = "ipnetwork")]
         Vec<sqlx::types::ipnetwork::IpNetwork> | &[sqlx::types::ipnetwork::IpNetwork],
 
+        #[cfg(feature = "mac_address")]
+        Vec<sqlx::types::mac_address::MacAddress> | &[sqlx::types::mac_address::MacAddress],
+
+        #[cfg(feature = "bit-vec")]
+        Vec<sqlx::types::BitVec> | &[sqlx::types::BitVec],
+
         #[cfg(feature = "json")]
         Vec<serde_json::Value> | &[serde_json::Value],
 
@@ -1,7 +1,7 @@
 use proc_macro2::Ident;
 use syn::punctuated::Punctuated;
 use syn::token::Comma;
-use syn::{Attribute, DeriveInput, Field, Lit, Meta, MetaNameValue, NestedMeta, Variant};
+use syn::{Attribute, DeriveInput, Field, Lit, Meta, MetaNameValue, NestedMeta, Type, Variant};
 
 macro_rules! assert_attribute {
     ($e:expr, $err:expr, $input:expr) => {
@@ -44,6 +44,10 @@ pub struct SqlxContainerAttributes {
 pub struct SqlxChildAttributes {
     pub rename: Option<String>,
     pub default: bool,
+    pub flatten: bool,
+    pub prefix: Option<String>,
+    pub try_from: Option<Type>,
+    pub skip: bool,
 }
 
 pub fn parse_container_attributes(input: &[Attribute]) -> syn::Result<SqlxContainerAttributes> {
@@ -120,6 +124,10 @@ pub fn parse_container_attributes(input: &[Attribute]) -> syn::Result<SqlxContai
 pub fn parse_child_attributes(input: &[Attribute]) -> syn::Result<SqlxChildAttributes> {
     let mut rename = None;
     let mut default = false;
+    let mut flatten = false;
+    let mut prefix = None;
+    let mut try_from = None;
+    let mut skip = false;
 
     for attr in input {
         let meta = attr
@@ -137,6 +145,20 @@ pub fn parse_child_attributes(input: &[Attribute]) -> syn::Result<SqlxChildAttri
                                 ..
                             }) if path.is_ident("rename") => try_set!(rename, val.value(), value),
                             Meta::Path(path) if path.is_ident("default") => default = true,
+                            Meta::Path(path) if path.is_ident("flatten") => flatten = true,
+                            Meta::NameValue(MetaNameValue {
+                                path,
+                                lit: Lit::Str(val),
+                                ..
+                            }) if path.is_ident("prefix") => try_set!(prefix, val.value(), value),
+                            Meta::NameValue(MetaNameValue {
+                                path,
+                                lit: Lit::Str(val),
+                                ..
+                            }) if path.is_ident("try_from") => {
+                                try_set!(try_from, val.parse()?, value)
+                            }
+                            Meta::Path(path) if path.is_ident("skip") => skip = true,
                             u => fail!(u, "unexpected attribute"),
                         },
                         u => fail!(u, "unexpected attribute"),
@@ -147,7 +169,14 @@ pub fn parse_child_attributes(input: &[Attribute]) -> syn::Result<SqlxChildAttri
         }
     }
 
-    Ok(SqlxChildAttributes { rename, default })
+    Ok(SqlxChildAttributes {
+        rename,
+        default,
+        flatten,
+        prefix,
+        try_from,
+        skip,
+    })
 }
 
 pub fn check_transparent_attributes(
@@ -2,7 +2,7 @@ use proc_macro2::Span;
 use quote::quote;
 use syn::{
     parse_quote, punctuated::Punctuated, token::Comma, Data, DataStruct, DeriveInput, Field,
-    Fields, FieldsNamed, Lifetime, Stmt,
+    FieldValue, Fields, FieldsNamed, Lifetime,
 };
 
 use super::attributes::parse_child_attributes;
@@ -61,51 +61,155 @@ fn expand_derive_from_row_struct(
 
     let predicates = &mut generics.make_where_clause().predicates;
 
-    predicates.push(parse_quote!(&#lifetime str: sqlx::ColumnIndex<R>));
+    predicates.push(parse_quote!(for<'s> &'s str: sqlx::ColumnIndex<R>));
+
+    let mut reads: Vec<FieldValue> = Vec::with_capacity(fields.len());
 
     for field in fields {
+        let id = match &field.ident {
+            Some(id) => id,
+            None => continue,
+        };
+
+        let attributes = parse_child_attributes(&field.attrs)?;
         let ty = &field.ty;
 
-        predicates.push(parse_quote!(#ty: sqlx::decode::Decode<#lifetime, R::Database>));
-        predicates.push(parse_quote!(#ty: sqlx::types::Type<R::Database>));
-    }
+        if attributes.skip {
+            if attributes.rename.is_some()
+                || attributes.default
+                || attributes.flatten
+                || attributes.try_from.is_some()
+            {
+                return Err(syn::Error::new_spanned(
+                    field,
+                    "#[sqlx(skip)] cannot be combined with other attributes",
+                ));
+            }
 
-    let (impl_generics, _, where_clause) = generics.split_for_impl();
+            reads.push(parse_quote!(#id: Default::default()));
+
+            continue;
+        }
+
+        if attributes.flatten {
+            if attributes.rename.is_some() {
+                return Err(syn::Error::new_spanned(
+                    field,
+                    "unexpected #[sqlx(rename = ..)] on a flattened field",
+                ));
+            }
+
+            if attributes.default {
+                return Err(syn::Error::new_spanned(
+                    field,
+                    "unexpected #[sqlx(default)] on a flattened field",
+                ));
+            }
+
+            if attributes.try_from.is_some() {
+                return Err(syn::Error::new_spanned(
+                    field,
+                    "unexpected #[sqlx(try_from = ..)] on a flattened field",
+                ));
+            }
+
+            predicates.push(parse_quote!(#ty: sqlx::FromRow<#lifetime, R>));
+
+            let prefix = match attributes.prefix {
+                Some(field_prefix) => quote!(&format!("{}{}", prefix, #field_prefix)),
+                None => quote!(prefix),
+            };
+
+            reads.push(parse_quote!(
+                #id: <#ty as sqlx::FromRow<#lifetime, R>>::from_row_prefixed(row, #prefix)?
+            ));
+
+            continue;
+        }
+
+        if attributes.prefix.is_some() {
+            return Err(syn::Error::new_spanned(
+                field,
+                "#[sqlx(prefix = ..)] requires #[sqlx(flatten)]",
+            ));
+        }
 
-    let reads = fields.iter().filter_map(|field| -> Option<Stmt> {
-        let id = &field.ident.as_ref()?;
-        let attributes = parse_child_attributes(&field.attrs).unwrap();
         let id_s = match attributes.rename {
             Some(rename) => rename,
             None => id.to_string().trim_start_matches("r#").to_owned(),
         };
-        let ty = &field.ty;
+
+        // only allocates when reading with a prefix
+        let column = quote!(if prefix.is_empty() {
+            std::borrow::Cow::Borrowed(#id_s)
+        } else {
+            std::borrow::Cow::Owned(format!("{}{}", prefix, #id_s))
+        });
+
+        if let Some(try_from) = &attributes.try_from {
+            predicates.push(parse_quote!(#try_from: sqlx::decode::Decode<#lifetime, R::Database>));
+            predicates.push(parse_quote!(#try_from: sqlx::types::Type<R::Database>));
+            predicates.push(parse_quote!(#ty: std::convert::TryFrom<#try_from>));
+            predicates.push(parse_quote!(
+                <#ty as std::convert::TryFrom<#try_from>>::Error:
+                    std::error::Error + Send + Sync + 'static
+            ));
+
+            let not_found = if attributes.default {
+                quote!(Err(sqlx::Error::ColumnNotFound(_)) => Default::default(),)
+            } else {
+                quote!()
+            };
+
+            reads.push(parse_quote!(
+                #id: {
+                    let column = #column;
+
+                    match row.try_get::<#try_from, _>(&*column) {
+                        Ok(value) => <#ty as std::convert::TryFrom<#try_from>>::try_from(value)
+                            .map_err(|e| sqlx::Error::ColumnDecode {
+                                index: format!("{:?}", column),
+                                source: Box::new(e),
+                            })?,
+                        #not_found
+                        Err(e) => return Err(e),
+                    }
+                }
+            ));
+
+            continue;
+        }
+
+        predicates.push(parse_quote!(#ty: sqlx::decode::Decode<#lifetime, R::Database>));
+        predicates.push(parse_quote!(#ty: sqlx::types::Type<R::Database>));
 
         if attributes.default {
-            Some(
-                parse_quote!(let #id: #ty = row.try_get(#id_s).or_else(|e| match e {
-                sqlx::Error::ColumnNotFound(_) => {
-                    Ok(Default::default())
-                },
-                e => Err(e)
-            })?;),
-            )
+            reads.push(parse_quote!(
+                #id: row.try_get(&*#column).or_else(|e| match e {
+                    sqlx::Error::ColumnNotFound(_) => {
+                        Ok(Default::default())
+                    },
+                    e => Err(e)
+                })?
+            ));
         } else {
-            Some(parse_quote!(
-                let #id: #ty = row.try_get(#id_s)?;
-            ))
+            reads.push(parse_quote!(
+                #id: row.try_get(&*#column)?
+            ));
         }
-    });
+    }
 
-    let names = fields.iter().map(|field| &field.ident);
+    let (impl_generics, _, where_clause) = generics.split_for_impl();
 
     Ok(quote!(
         impl #impl_generics sqlx::FromRow<#lifetime, R> for #ident #ty_generics #where_clause {
             fn from_row(row: &#lifetime R) -> sqlx::Result<Self> {
-                #(#reads)*
+                <Self as sqlx::FromRow<#lifetime, R>>::from_row_prefixed(row, "")
+            }
 
+            fn from_row_prefixed(row: &#lifetime R, prefix: &str) -> sqlx::Result<Self> {
                 Ok(#ident {
-                    #(#names),*
+                    #(#reads),*
                 })
             }
         }
@@ -135,7 +135,8 @@ fn expand_derive_has_sql_type_strong_enum(
                 }
 
                 fn compatible(ty: &sqlx::mysql::MySqlTypeInfo) -> bool {
-                    *ty == sqlx::mysql::MySqlTypeInfo::__enum()
+                    // an `ENUM` column, or any textual column
+                    ty.__is_enum() || <str as sqlx::Type<sqlx::MySql>>::compatible(ty)
                 }
             }
         ));
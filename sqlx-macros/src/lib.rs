@@ -16,6 +16,9 @@ mod database;
 mod derives;
 mod query;
 
+#[cfg(feature = "migrate")]
+mod migrate;
+
 fn macro_result(tokens: proc_macro2::TokenStream) -> TokenStream {
     quote!(
         macro_rules! macro_result {
@@ -79,6 +82,25 @@ pub fn derive_from_row(input: TokenStream) -> TokenStream {
     }
 }
 
+#[cfg(feature = "migrate")]
+#[proc_macro]
+pub fn migrate(input: TokenStream) -> TokenStream {
+    use syn::LitStr;
+
+    let input = syn::parse_macro_input!(input as LitStr);
+    match migrate::expand_migrator_from_dir(input) {
+        Ok(ts) => ts.into(),
+        Err(e) => {
+            if let Some(parse_err) = e.downcast_ref::<syn::Error>() {
+                parse_err.to_compile_error().into()
+            } else {
+                let msg = e.to_string();
+                quote!(compile_error!(#msg)).into()
+            }
+        }
+    }
+}
+
 #[doc(hidden)]
 #[proc_macro_attribute]
 pub fn test(_attr: TokenStream, input: TokenStream) -> TokenStream {
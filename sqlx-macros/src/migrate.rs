@@ -0,0 +1,159 @@
+use std::env;
+use std::fs;
+use std::path::Path;
+
+use proc_macro2::TokenStream;
+use quote::{quote, ToTokens, TokenStreamExt};
+use sha2::{Digest, Sha384};
+use sqlx_core::migrate::{Migration, MigrationType};
+use syn::LitStr;
+
+struct QuotedMigrationType(MigrationType);
+
+impl ToTokens for QuotedMigrationType {
+    fn to_tokens(&self, tokens: &mut TokenStream) {
+        let ts = match self.0 {
+            MigrationType::Simple => quote! { sqlx::migrate::MigrationType::Simple },
+            MigrationType::ReversibleUp => quote! { sqlx::migrate::MigrationType::ReversibleUp },
+            MigrationType::ReversibleDown => {
+                quote! { sqlx::migrate::MigrationType::ReversibleDown }
+            }
+        };
+
+        tokens.append_all(ts);
+    }
+}
+
+struct QuotedMigration {
+    version: i64,
+    description: String,
+    migration_type: QuotedMigrationType,
+    path: String,
+    checksum: Vec<u8>,
+    no_tx: bool,
+}
+
+impl ToTokens for QuotedMigration {
+    fn to_tokens(&self, tokens: &mut TokenStream) {
+        let QuotedMigration {
+            version,
+            description,
+            migration_type,
+            path,
+            checksum,
+            no_tx,
+        } = &self;
+
+        let ts = quote! {
+            sqlx::migrate::Migration {
+                version: #version,
+                description: std::borrow::Cow::Borrowed(#description),
+                migration_type: #migration_type,
+                // `include_str!()` tells the compiler to rebuild when the file changes
+                sql: std::borrow::Cow::Borrowed(include_str!(#path)),
+                checksum: std::borrow::Cow::Borrowed(&[
+                    #(#checksum),*
+                ]),
+                no_tx: #no_tx,
+            }
+        };
+
+        tokens.append_all(ts);
+    }
+}
+
+pub(crate) fn expand_migrator_from_dir(dir: LitStr) -> crate::Result<TokenStream> {
+    let path = Path::new(&dir.value()).to_owned();
+
+    if path.is_absolute() {
+        return Err(syn::Error::new(
+            dir.span(),
+            "absolute paths will only work on the current machine",
+        )
+        .into());
+    }
+
+    let base_dir =
+        env::var("CARGO_MANIFEST_DIR").map_err(|_| "`CARGO_MANIFEST_DIR` must be set")?;
+
+    let path = Path::new(&base_dir)
+        .join(path)
+        .canonicalize()
+        .map_err(|e| {
+            syn::Error::new(
+                dir.span(),
+                format!(
+                    "failed to resolve migrations directory {}: {}",
+                    dir.value(),
+                    e
+                ),
+            )
+        })?;
+
+    let mut migrations = Vec::new();
+
+    for entry in fs::read_dir(&path)? {
+        let entry = entry?;
+
+        if !entry.metadata()?.is_file() {
+            // not a file; ignore
+            continue;
+        }
+
+        let file_name = entry.file_name();
+        let file_name = file_name.to_string_lossy();
+
+        let parts = file_name.splitn(2, '_').collect::<Vec<_>>();
+
+        let (migration_type, description) = match parts
+            .get(1)
+            .and_then(|name| MigrationType::from_filename(name))
+        {
+            Some(it) => it,
+
+            // not of the format: <VERSION>_<DESCRIPTION>[.up|.down].sql; ignore
+            None => continue,
+        };
+
+        let version: i64 = parts[0]
+            .parse()
+            .map_err(|e| format!("invalid version in migration {}: {}", file_name, e))?;
+
+        // replace `_` with ` `
+        let description = description.replace('_', " ");
+
+        let sql = fs::read_to_string(entry.path())?;
+
+        let checksum = Vec::from(Sha384::digest(sql.as_bytes()).as_slice());
+
+        // a migration may opt out of running in a transaction with a header line
+        let no_tx = Migration::parse_no_tx(&sql);
+
+        let path = entry
+            .path()
+            .to_str()
+            .ok_or_else(|| format!("migration path is not valid UTF-8: {:?}", entry.path()))?
+            .to_owned();
+
+        migrations.push(QuotedMigration {
+            version,
+            description,
+            migration_type: QuotedMigrationType(migration_type),
+            path,
+            checksum,
+            no_tx,
+        })
+    }
+
+    // ensure that we are sorted by `VERSION ASC`; with the up migration of each
+    // reversible pair before its down migration
+    migrations.sort_by_key(|m| (m.version, m.migration_type.0.is_down_migration()));
+
+    Ok(quote! {
+        sqlx::migrate::Migrator {
+            migrations: std::borrow::Cow::Borrowed(&[
+                #(#migrations),*
+            ])
+        }
+    })
+}
@@ -573,3 +573,38 @@ macro_rules! query_file_as_unchecked (
         macro_result!($($args),*)
     })
 );
+
+/// Embeds migrations into the binary by expanding to a static instance of [Migrator][crate::migrate::Migrator].
+///
+/// ```rust,ignore
+/// use sqlx::migrate::Migrator;
+///
+/// static MIGRATOR: Migrator = sqlx::migrate!(); // defaults to "./migrations"
+/// ```
+///
+/// The directory must be relative to the project root (the directory containing `Cargo.toml`),
+/// unlike `include_str!()` which uses compiler internals to get the path of the file where it
+/// was invoked.
+///
+/// The crate is rebuilt when the contents of an embedded migration change. However, the
+/// compiler is not aware of migrations that are added or removed. To trigger a rebuild when
+/// that happens, add a build script that watches the migrations directory:
+///
+/// ```rust,ignore
+/// // build.rs
+/// fn main() {
+///     println!("cargo:rerun-if-changed=migrations");
+/// }
+/// ```
+#[cfg(feature = "migrate")]
+#[macro_export]
+#[cfg_attr(docsrs, doc(cfg(all(feature = "macros", feature = "migrate"))))]
+macro_rules! migrate {
+    ($dir:literal) => {
+        $crate::sqlx_macros::migrate!($dir)
+    };
+
+    () => {
+        $crate::sqlx_macros::migrate!("./migrations")
+    };
+}
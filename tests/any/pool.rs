@@ -66,3 +66,36 @@ async fn pool_should_be_returned_failed_transactions() -> anyhow::Result<()> {
 
     Ok(())
 }
+
+#[sqlx_macros::test]
+async fn pool_should_report_metrics() -> anyhow::Result<()> {
+    let pool = AnyPoolOptions::new()
+        .max_connections(2)
+        .connect_timeout(Duration::from_millis(500))
+        .connect(&dotenv::var("DATABASE_URL")?)
+        .await?;
+
+    let conn1 = pool.acquire().await?;
+    let conn2 = pool.acquire().await?;
+
+    assert_eq!(pool.size(), 2);
+    assert_eq!(pool.num_idle(), 0);
+
+    // the pool is exhausted
+    assert!(pool.acquire().await.is_err());
+
+    drop(conn1);
+    drop(conn2);
+
+    let metrics = pool.metrics();
+
+    assert_eq!(metrics.size(), 2);
+    assert_eq!(metrics.num_idle(), 2);
+    assert_eq!(metrics.num_waiters(), 0);
+    assert_eq!(metrics.acquire_count(), 2);
+    assert_eq!(metrics.acquire_timeout_count(), 1);
+    assert_eq!(metrics.acquire_wait().count(), 2);
+    assert_eq!(metrics.connect_error_count(), 0);
+
+    Ok(())
+}
@@ -0,0 +1,6 @@
+-- no-transaction
+
+-- CREATE INDEX CONCURRENTLY cannot run inside a transaction block; this includes the
+-- implicit one of a query with several statements
+CREATE INDEX CONCURRENTLY users_name ON users (name);
+CREATE INDEX CONCURRENTLY users_email ON users (email);
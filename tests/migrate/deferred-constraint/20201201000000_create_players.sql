@@ -0,0 +1,10 @@
+CREATE TABLE teams (
+    id INTEGER PRIMARY KEY
+);
+
+CREATE TABLE players (
+    team_id INTEGER NOT NULL REFERENCES teams (id) DEFERRABLE INITIALLY DEFERRED
+);
+
+-- the missing team is only noticed when the transaction commits
+INSERT INTO players (team_id) VALUES (1);
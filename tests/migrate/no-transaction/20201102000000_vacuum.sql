@@ -0,0 +1,4 @@
+-- no-transaction
+
+-- VACUUM cannot be run from within a transaction
+VACUUM;
@@ -0,0 +1,4 @@
+CREATE TABLE user_emails (
+    user_id INTEGER NOT NULL REFERENCES users (id),
+    email TEXT NOT NULL
+);
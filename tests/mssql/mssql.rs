@@ -1,8 +1,9 @@
 use futures::TryStreamExt;
-use sqlx::mssql::Mssql;
+use sqlx::mssql::{Mssql, MssqlConnectOptions};
 use sqlx::{Column, Connection, Done, Executor, MssqlConnection, Row, Statement, TypeInfo};
 use sqlx_core::mssql::MssqlRow;
 use sqlx_test::new;
+use std::env;
 
 #[sqlx_macros::test]
 async fn it_connects() -> anyhow::Result<()> {
@@ -325,3 +326,54 @@ async fn it_can_prepare_then_execute() -> anyhow::Result<()> {
 
     Ok(())
 }
+
+#[sqlx_macros::test]
+async fn it_connects_with_encryption() -> anyhow::Result<()> {
+    sqlx_test::setup_if_needed();
+
+    // the test server has a self-signed certificate
+    let url = format!(
+        "{}?encrypt=true&trust-server-certificate=true",
+        env::var("DATABASE_URL")?
+    );
+    let mut conn = MssqlConnection::connect(&url).await?;
+
+    let encrypted: String = sqlx::query_scalar(
+        "SELECT encrypt_option FROM sys.dm_exec_connections WHERE session_id = @@SPID",
+    )
+    .fetch_one(&mut conn)
+    .await?;
+
+    assert_eq!(encrypted, "TRUE");
+
+    let v: i32 = sqlx::query_scalar("SELECT 4").fetch_one(&mut conn).await?;
+    assert_eq!(v, 4);
+
+    conn.close().await?;
+
+    Ok(())
+}
+
+#[sqlx_macros::test]
+async fn it_connects_with_only_the_login_encrypted() -> anyhow::Result<()> {
+    sqlx_test::setup_if_needed();
+
+    let options: MssqlConnectOptions = env::var("DATABASE_URL")?.parse()?;
+    let mut conn = MssqlConnection::connect_with(&options.encrypt(false)).await?;
+
+    // the login is encrypted, then the connection continues in the clear
+    let encrypted: String = sqlx::query_scalar(
+        "SELECT encrypt_option FROM sys.dm_exec_connections WHERE session_id = @@SPID",
+    )
+    .fetch_one(&mut conn)
+    .await?;
+
+    assert_eq!(encrypted, "FALSE");
+
+    let v: i32 = sqlx::query_scalar("SELECT 4").fetch_one(&mut conn).await?;
+    assert_eq!(v, 4);
+
+    conn.close().await?;
+
+    Ok(())
+}
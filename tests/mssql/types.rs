@@ -1,5 +1,10 @@
+extern crate time_ as time;
+
+#[cfg(feature = "decimal")]
+use std::str::FromStr;
+
 use sqlx::mssql::Mssql;
-use sqlx_test::test_type;
+use sqlx_test::{test_decode_type, test_type};
 
 test_type!(null<Option<i32>>(Mssql,
     "CAST(NULL as INT)" == None::<i32>
@@ -41,3 +46,139 @@ test_type!(bool(
     "CAST(1 as BIT)" == true,
     "CAST(0 as BIT)" == false
 ));
+
+test_type!(str_nvarchar_max<String>(Mssql,
+    "REPLICATE(CAST('a' AS NVARCHAR(MAX)), 5000)" == "a".repeat(5000),
+));
+
+test_decode_type!(str_ntext<String>(Mssql,
+    "CAST('this is foo' AS NTEXT)" == "this is foo",
+));
+
+test_type!(bytes<Vec<u8>>(Mssql,
+    "CAST(0xDEADBEEF AS VARBINARY)" == vec![0xDE_u8, 0xAD, 0xBE, 0xEF],
+    "CAST(0x AS VARBINARY)" == Vec::<u8>::new(),
+    "CAST(0x0000000052 AS VARBINARY)" == vec![0_u8, 0, 0, 0, 0x52],
+));
+
+test_type!(bytes_varbinary_max<Vec<u8>>(Mssql,
+    "CAST(REPLICATE(CAST(0xAB AS VARBINARY(MAX)), 10000) AS VARBINARY(MAX))" == vec![0xAB_u8; 10000],
+));
+
+test_decode_type!(bytes_image<Vec<u8>>(Mssql,
+    "CAST(0xDEADBEEF AS IMAGE)" == vec![0xDE_u8, 0xAD, 0xBE, 0xEF],
+));
+
+#[cfg(feature = "uuid")]
+test_type!(uuid<sqlx::types::Uuid>(Mssql,
+    "CAST('b731678f-636f-4135-bc6f-19440c13bd19' AS UNIQUEIDENTIFIER)"
+        == sqlx::types::Uuid::parse_str("b731678f-636f-4135-bc6f-19440c13bd19").unwrap(),
+    "CAST('00000000-0000-0000-0000-000000000000' AS UNIQUEIDENTIFIER)"
+        == sqlx::types::Uuid::nil()
+));
+
+#[cfg(feature = "chrono")]
+mod chrono {
+    use super::*;
+    use sqlx::types::chrono::{
+        DateTime, FixedOffset, NaiveDate, NaiveDateTime, NaiveTime, TimeZone, Utc,
+    };
+
+    test_type!(chrono_date<NaiveDate>(Mssql,
+        "CAST('2001-01-05' AS DATE)" == NaiveDate::from_ymd(2001, 1, 5),
+        "CAST('0001-01-01' AS DATE)" == NaiveDate::from_ymd(1, 1, 1),
+        "CAST('9999-12-31' AS DATE)" == NaiveDate::from_ymd(9999, 12, 31)
+    ));
+
+    test_type!(chrono_time<NaiveTime>(Mssql,
+        "CAST('00:00:00' AS TIME)" == NaiveTime::from_hms(0, 0, 0),
+        "CAST('05:10:20.1151234' AS TIME)" == NaiveTime::from_hms_nano(5, 10, 20, 115_123_400)
+    ));
+
+    test_decode_type!(chrono_time_scale<NaiveTime>(Mssql,
+        "CAST('05:10:20.115' AS TIME(3))" == NaiveTime::from_hms_milli(5, 10, 20, 115)
+    ));
+
+    test_type!(chrono_date_time<NaiveDateTime>(Mssql,
+        "CAST('2019-01-02 05:10:20.1151234' AS DATETIME2)"
+            == NaiveDate::from_ymd(2019, 1, 2).and_hms_nano(5, 10, 20, 115_123_400)
+    ));
+
+    test_decode_type!(chrono_date_time_legacy<NaiveDateTime>(Mssql,
+        "CAST('2019-01-02 05:10:20' AS DATETIME)"
+            == NaiveDate::from_ymd(2019, 1, 2).and_hms(5, 10, 20),
+        "CAST('2019-01-02 05:10:00' AS SMALLDATETIME)"
+            == NaiveDate::from_ymd(2019, 1, 2).and_hms(5, 10, 0)
+    ));
+
+    test_type!(chrono_date_time_offset<DateTime<FixedOffset>>(Mssql,
+        "CAST('2019-01-02 05:10:20.115 +02:30' AS DATETIMEOFFSET)"
+            == FixedOffset::east(9000).ymd(2019, 1, 2).and_hms_milli(5, 10, 20, 115)
+    ));
+
+    test_type!(chrono_date_time_utc<DateTime<Utc>>(Mssql,
+        "CAST('2019-01-02 05:10:20 +00:00' AS DATETIMEOFFSET)"
+            == Utc.ymd(2019, 1, 2).and_hms(5, 10, 20)
+    ));
+}
+
+#[cfg(feature = "time")]
+mod time_tests {
+    use super::*;
+    use sqlx::types::time::{Date, OffsetDateTime, PrimitiveDateTime, Time};
+    use time::{date, time};
+
+    test_type!(time_date<Date>(Mssql,
+        "CAST('2001-01-05' AS DATE)" == date!(2001 - 1 - 5),
+        "CAST('9999-12-31' AS DATE)" == date!(9999 - 12 - 31)
+    ));
+
+    test_type!(time_time<Time>(Mssql,
+        "CAST('05:10:20.1151234' AS TIME)" == time!(5:10:20.1151234)
+    ));
+
+    test_type!(time_date_time<PrimitiveDateTime>(Mssql,
+        "CAST('2019-01-02 05:10:20.115' AS DATETIME2)"
+            == date!(2019 - 1 - 2).with_time(time!(5:10:20.115))
+    ));
+
+    test_type!(time_date_time_offset<OffsetDateTime>(Mssql,
+        "CAST('2019-01-02 05:10:20.115 -05:00' AS DATETIMEOFFSET)"
+            == date!(2019 - 1 - 2)
+                .with_time(time!(10:10:20.115))
+                .assume_utc()
+                .to_offset(time::UtcOffset::west_hours(5))
+    ));
+}
+
+#[cfg(feature = "bigdecimal")]
+test_type!(bigdecimal<sqlx::types::BigDecimal>(
+    Mssql,
+    "CAST(0 AS DECIMAL(1, 0))" == "0".parse::<sqlx::types::BigDecimal>().unwrap(),
+    "CAST(10000 AS DECIMAL(5, 0))" == "10000".parse::<sqlx::types::BigDecimal>().unwrap(),
+    "CAST(-0.01234 AS DECIMAL(6, 5))" == "-0.01234".parse::<sqlx::types::BigDecimal>().unwrap(),
+    "CAST(12345.6789 AS NUMERIC(9, 4))" == "12345.6789".parse::<sqlx::types::BigDecimal>().unwrap(),
+    "CAST(12345678901234567890123456789.123456789 AS DECIMAL(38, 9))"
+        == "12345678901234567890123456789.123456789".parse::<sqlx::types::BigDecimal>().unwrap(),
+));
+
+#[cfg(feature = "bigdecimal")]
+test_type!(bigdecimal_money<sqlx::types::BigDecimal>(
+    Mssql,
+    "CAST(-12345.6789 AS MONEY)" == "-12345.6789".parse::<sqlx::types::BigDecimal>().unwrap(),
+    "CAST(12.34 AS SMALLMONEY)" == "12.3400".parse::<sqlx::types::BigDecimal>().unwrap(),
+));
+
+#[cfg(feature = "decimal")]
+test_type!(decimal<sqlx::types::Decimal>(Mssql,
+    "CAST(0 AS DECIMAL(1, 0))" == sqlx::types::Decimal::from_str("0").unwrap(),
+    "CAST(10000 AS DECIMAL(5, 0))" == sqlx::types::Decimal::from_str("10000").unwrap(),
+    "CAST(-0.01234 AS DECIMAL(6, 5))" == sqlx::types::Decimal::from_str("-0.01234").unwrap(),
+    "CAST(12345.6789 AS NUMERIC(9, 4))" == sqlx::types::Decimal::from_str("12345.6789").unwrap(),
+));
+
+#[cfg(feature = "decimal")]
+test_type!(decimal_money<sqlx::types::Decimal>(Mssql,
+    "CAST(-12345.6789 AS MONEY)" == sqlx::types::Decimal::from_str("-12345.6789").unwrap(),
+    "CAST(12.34 AS SMALLMONEY)" == sqlx::types::Decimal::from_str("12.34").unwrap(),
+));
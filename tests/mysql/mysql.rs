@@ -1,6 +1,6 @@
-use futures::TryStreamExt;
-use sqlx::mysql::{MySql, MySqlConnection, MySqlPool, MySqlPoolOptions, MySqlRow};
-use sqlx::{Column, Connection, Done, Executor, Row, Statement, TypeInfo};
+use futures::{FutureExt, TryStreamExt};
+use sqlx::mysql::{MySql, MySqlArguments, MySqlConnection, MySqlPool, MySqlPoolOptions, MySqlRow};
+use sqlx::{Arguments, Column, Connection, Done, Executor, Row, Statement, TypeInfo};
 use sqlx_test::{new, setup_if_needed};
 use std::env;
 
@@ -333,3 +333,146 @@ async fn it_can_prepare_then_execute() -> anyhow::Result<()> {
 
     Ok(())
 }
+
+#[sqlx_macros::test]
+async fn it_can_execute_batch() -> anyhow::Result<()> {
+    let mut conn = new::<MySql>().await?;
+
+    conn.execute(
+        r#"
+CREATE TEMPORARY TABLE users (id INTEGER PRIMARY KEY AUTO_INCREMENT, name TEXT NOT NULL);
+    "#,
+    )
+    .await?;
+
+    let arguments = (1..=3_i32).map(|id| {
+        let mut args = MySqlArguments::default();
+        args.add(format!("user {}", id));
+        args
+    });
+
+    let done = conn
+        .execute_batch("INSERT INTO users (name) VALUES (?)", arguments)
+        .await?;
+
+    assert_eq!(done.len(), 3);
+    assert!(done.iter().all(|d| d.rows_affected() == 1));
+    assert_eq!(done[2].last_insert_id(), 3);
+
+    // a failing execution does not stop the ones after it
+    let arguments = vec![1_i32, 4, 5].into_iter().map(|id| {
+        let mut args = MySqlArguments::default();
+        args.add(id);
+        args
+    });
+
+    let res = conn
+        .execute_batch("INSERT INTO users (id, name) VALUES (?, 'x')", arguments)
+        .await;
+
+    assert!(res.is_err());
+
+    let count: i64 = sqlx::query_scalar("SELECT COUNT(*) FROM users")
+        .fetch_one(&mut conn)
+        .await?;
+
+    assert_eq!(count, 5);
+
+    Ok(())
+}
+
+#[sqlx_macros::test]
+async fn it_can_reuse_a_connection_after_a_failed_or_abandoned_batch() -> anyhow::Result<()> {
+    let mut conn = new::<MySql>().await?;
+
+    conn.execute("CREATE TEMPORARY TABLE users (id INTEGER PRIMARY KEY, name TEXT NOT NULL);")
+        .await?;
+
+    let arguments = |ids: Vec<i32>| {
+        ids.into_iter().map(|id| {
+            let mut args = MySqlArguments::default();
+            args.add(id);
+            args
+        })
+    };
+
+    // the execution in the middle of the batch fails
+    let res = conn
+        .execute_batch(
+            "INSERT INTO users (id, name) VALUES (?, 'x')",
+            arguments(vec![1, 2, 2, 3, 4]),
+        )
+        .await;
+
+    assert!(res.is_err());
+
+    let count: i64 = sqlx::query_scalar("SELECT COUNT(*) FROM users")
+        .fetch_one(&mut conn)
+        .await?;
+
+    assert_eq!(count, 4);
+
+    // the batch is sent, then dropped while waiting for its first response
+    let res = conn
+        .execute_batch(
+            "INSERT INTO users (id, name) VALUES (?, 'x')",
+            arguments(vec![5, 1, 6, 7]),
+        )
+        .now_or_never();
+
+    assert!(res.is_none());
+
+    let count: i64 = sqlx::query_scalar("SELECT COUNT(*) FROM users")
+        .fetch_one(&mut conn)
+        .await?;
+
+    assert_eq!(count, 7);
+
+    Ok(())
+}
+
+#[derive(Debug, PartialEq, sqlx::Type)]
+#[sqlx(rename_all = "lowercase")]
+enum Size {
+    Small,
+    Medium,
+    Large,
+}
+
+#[sqlx_macros::test]
+async fn it_can_bind_and_decode_an_enum_column() -> anyhow::Result<()> {
+    let mut conn = new::<MySql>().await?;
+
+    conn.execute(
+        r#"
+CREATE TEMPORARY TABLE shirts (
+    id INT PRIMARY KEY AUTO_INCREMENT,
+    size ENUM('small', 'medium', 'large') NOT NULL
+);
+    "#,
+    )
+    .await?;
+
+    sqlx::query("INSERT INTO shirts (size) VALUES (?), (?)")
+        .bind(Size::Large)
+        .bind(Size::Small)
+        .execute(&mut conn)
+        .await?;
+
+    let rows = sqlx::query("SELECT size FROM shirts ORDER BY id")
+        .fetch_all(&mut conn)
+        .await?;
+
+    assert_eq!(rows[0].column(0).type_info().name(), "ENUM");
+    assert_eq!(rows[0].try_get::<Size, _>(0)?, Size::Large);
+    assert_eq!(rows[1].try_get::<Size, _>(0)?, Size::Small);
+
+    // a textual column is also accepted
+    let size: Size = sqlx::query_scalar("SELECT 'medium'")
+        .fetch_one(&mut conn)
+        .await?;
+
+    assert_eq!(size, Size::Medium);
+
+    Ok(())
+}
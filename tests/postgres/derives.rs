@@ -426,3 +426,93 @@ async fn test_default() -> anyhow::Result<()> {
 
     Ok(())
 }
+
+#[cfg(feature = "macros")]
+#[sqlx_macros::test]
+async fn test_from_row_with_flatten() -> anyhow::Result<()> {
+    #[derive(Debug, sqlx::FromRow)]
+    struct Address {
+        country: String,
+        city: String,
+        #[sqlx(default)]
+        road: Option<String>,
+    }
+
+    #[derive(Debug, sqlx::FromRow)]
+    struct User {
+        id: i32,
+        name: String,
+        #[sqlx(flatten)]
+        address: Address,
+        #[sqlx(flatten, prefix = "billing_")]
+        billing_address: Address,
+    }
+
+    let mut conn = new::<Postgres>().await?;
+
+    let user: User = sqlx::query_as(
+        r#"
+SELECT 1 AS id, 'Herp Derpinson' AS name,
+       'USA' AS country, 'Herpington' AS city, 'Derp Road' AS road,
+       'Canada' AS billing_country, 'Derpington' AS billing_city
+        "#,
+    )
+    .fetch_one(&mut conn)
+    .await?;
+
+    assert_eq!(user.id, 1);
+    assert_eq!(user.name, "Herp Derpinson");
+    assert_eq!(user.address.country, "USA");
+    assert_eq!(user.address.city, "Herpington");
+    assert_eq!(user.address.road.as_deref(), Some("Derp Road"));
+    assert_eq!(user.billing_address.country, "Canada");
+    assert_eq!(user.billing_address.city, "Derpington");
+    assert_eq!(user.billing_address.road, None);
+
+    Ok(())
+}
+
+#[cfg(feature = "macros")]
+#[sqlx_macros::test]
+async fn test_from_row_with_try_from_and_skip() -> anyhow::Result<()> {
+    use std::convert::TryFrom;
+
+    #[derive(Debug, PartialEq)]
+    struct UserId(i64);
+
+    impl TryFrom<i64> for UserId {
+        type Error = std::num::TryFromIntError;
+
+        fn try_from(id: i64) -> Result<Self, Self::Error> {
+            // ids must be positive
+            u64::try_from(id).map(|_| UserId(id))
+        }
+    }
+
+    #[derive(Debug, sqlx::FromRow)]
+    struct User {
+        #[sqlx(try_from = "i64")]
+        id: UserId,
+        name: String,
+        #[sqlx(skip)]
+        permissions: Vec<String>,
+    }
+
+    let mut conn = new::<Postgres>().await?;
+
+    let user: User = sqlx::query_as("SELECT 1::INT8 AS id, 'Herp Derpinson' AS name")
+        .fetch_one(&mut conn)
+        .await?;
+
+    assert_eq!(user.id, UserId(1));
+    assert_eq!(user.name, "Herp Derpinson");
+    assert!(user.permissions.is_empty());
+
+    let res: Result<User, _> = sqlx::query_as("SELECT -1::INT8 AS id, 'Herp Derpinson' AS name")
+        .fetch_one(&mut conn)
+        .await;
+
+    assert!(matches!(res, Err(sqlx::Error::ColumnDecode { .. })));
+
+    Ok(())
+}
@@ -85,6 +85,31 @@ async fn test_text_var_char_char_n() -> anyhow::Result<()> {
     Ok(())
 }
 
+#[sqlx_macros::test]
+async fn test_geometric_types() -> anyhow::Result<()> {
+    use sqlx::postgres::types::{PgCircle, PgPoint};
+
+    let mut conn = new::<Postgres>().await?;
+
+    let rec = sqlx::query!(
+        r#"SELECT $1::point as "center!", circle($1::point, 2) as "circle!""#,
+        PgPoint { x: 1.0, y: 2.0 }
+    )
+    .fetch_one(&mut conn)
+    .await?;
+
+    assert_eq!(rec.center, PgPoint { x: 1.0, y: 2.0 });
+    assert_eq!(
+        rec.circle,
+        PgCircle {
+            center: rec.center,
+            radius: 2.0
+        }
+    );
+
+    Ok(())
+}
+
 #[sqlx_macros::test]
 async fn test_void() -> anyhow::Result<()> {
     let mut conn = new::<Postgres>().await?;
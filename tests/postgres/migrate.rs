@@ -0,0 +1,84 @@
+use sqlx::migrate::{Migrate, Migrator};
+use sqlx::postgres::{PgConnection, Postgres};
+use sqlx::Executor;
+use sqlx_test::new;
+use std::path::Path;
+
+// each test works in a schema of its own, which is created anew
+async fn connect(schema: &str) -> anyhow::Result<PgConnection> {
+    let mut conn = new::<Postgres>().await?;
+
+    conn.execute(&*format!(
+        "DROP SCHEMA IF EXISTS {0} CASCADE; CREATE SCHEMA {0}; SET search_path TO {0};",
+        schema
+    ))
+    .await?;
+
+    Ok(conn)
+}
+
+async fn table_exists(conn: &mut PgConnection, name: &str) -> anyhow::Result<bool> {
+    let exists: bool = sqlx::query_scalar(
+        "SELECT EXISTS(SELECT 1 FROM pg_tables WHERE schemaname = current_schema() AND tablename = $1)",
+    )
+    .bind(name)
+    .fetch_one(conn)
+    .await?;
+
+    Ok(exists)
+}
+
+#[sqlx_macros::test]
+async fn it_does_not_record_a_migration_that_failed_to_commit() -> anyhow::Result<()> {
+    let mut conn = connect("_sqlx_migrate_deferred").await?;
+    let migrator = Migrator::new(Path::new("tests/migrate/deferred-constraint")).await?;
+
+    assert!(migrator.run(&mut conn).await.is_err());
+
+    // the transaction was rolled back, so the database is not dirty
+    assert!(!table_exists(&mut conn, "teams").await?);
+    assert_eq!(conn.version().await?, None);
+
+    Ok(())
+}
+
+#[sqlx_macros::test]
+async fn it_can_run_concurrent_index_builds_outside_of_a_transaction() -> anyhow::Result<()> {
+    let mut conn = connect("_sqlx_migrate_concurrent_index").await?;
+    let migrator = Migrator::new(Path::new("tests/migrate/concurrent-index")).await?;
+
+    let no_tx: Vec<_> = migrator.iter().map(|m| m.no_tx()).collect();
+
+    assert_eq!(no_tx, [false, true]);
+
+    migrator.run(&mut conn).await?;
+
+    let indexes: Vec<String> = sqlx::query_scalar(
+        "SELECT indexname FROM pg_indexes WHERE schemaname = current_schema() AND tablename = 'users' ORDER BY indexname",
+    )
+    .fetch_all(&mut conn)
+    .await?;
+
+    assert_eq!(indexes, ["users_email", "users_name", "users_pkey"]);
+    assert_eq!(conn.version().await?, Some((20201202000000, false)));
+
+    Ok(())
+}
+
+#[sqlx_macros::test]
+async fn it_lists_pending_migrations_without_creating_the_migrations_table() -> anyhow::Result<()> {
+    let mut conn = connect("_sqlx_migrate_pending").await?;
+    let migrator = Migrator::new(Path::new("tests/migrate/reversible")).await?;
+
+    let pending: Vec<_> = migrator
+        .pending(&mut conn)
+        .await?
+        .iter()
+        .map(|m| m.version())
+        .collect();
+
+    assert_eq!(pending, [20201001000000, 20201002000000]);
+    assert!(!table_exists(&mut conn, "_sqlx_migrations").await?);
+
+    Ok(())
+}
@@ -1,9 +1,11 @@
 use futures::TryStreamExt;
+use sqlx::postgres::types::{PgCiText, PgLQuery, PgLTree};
 use sqlx::postgres::{
-    PgConnectOptions, PgConnection, PgDatabaseError, PgErrorPosition, PgSeverity,
+    PgArguments, PgConnectOptions, PgConnection, PgDatabaseError, PgErrorPosition, PgPipeline,
+    PgSeverity, PgTargetSessionAttrs,
 };
 use sqlx::postgres::{PgPoolOptions, PgRow, Postgres};
-use sqlx::{Column, Connection, Done, Executor, Row, Statement, TypeInfo};
+use sqlx::{Arguments, Column, Connection, Done, Executor, Row, Statement, TypeInfo};
 use sqlx_test::{new, setup_if_needed};
 use std::env;
 use std::thread;
@@ -659,3 +661,587 @@ async fn it_can_prepare_then_execute() -> anyhow::Result<()> {
 
     Ok(())
 }
+
+#[sqlx_macros::test]
+async fn it_can_copy_in_and_out() -> anyhow::Result<()> {
+    let mut conn = new::<Postgres>().await?;
+
+    conn.execute(
+        r#"
+CREATE TEMPORARY TABLE users (id INTEGER NOT NULL, name TEXT NOT NULL);
+    "#,
+    )
+    .await?;
+
+    let mut copy = conn.copy_in_raw("COPY users (id, name) FROM STDIN").await?;
+
+    assert!(copy.is_textual());
+    assert_eq!(copy.num_columns(), 2);
+
+    copy.send(&b"1\tAlice\n2\tBob\n"[..]).await?;
+    copy.read_from(&b"3\tCarol\n"[..]).await?;
+
+    assert_eq!(copy.finish().await?, 3);
+
+    let chunks: Vec<_> = conn
+        .copy_out_raw("COPY (SELECT * FROM users ORDER BY id) TO STDOUT")
+        .await?
+        .try_collect()
+        .await?;
+
+    assert_eq!(chunks.concat(), b"1\tAlice\n2\tBob\n3\tCarol\n");
+
+    Ok(())
+}
+
+#[sqlx_macros::test]
+async fn it_can_recover_from_rejected_copy_data() -> anyhow::Result<()> {
+    let mut conn = new::<Postgres>().await?;
+
+    conn.execute("CREATE TEMPORARY TABLE users (id INTEGER NOT NULL);")
+        .await?;
+
+    let mut copy = conn.copy_in_raw("COPY users (id) FROM STDIN").await?;
+    copy.send(&b"1\nnot a number\n"[..]).await?;
+
+    assert!(copy.finish().await.is_err());
+
+    // an aborted copy rolls back everything that was sent
+    let copy = conn.copy_in_raw("COPY users (id) FROM STDIN").await?;
+    copy.abort("changed my mind").await?;
+
+    // a dropped copy is aborted on the next use of the connection
+    let _ = conn.copy_in_raw("COPY users (id) FROM STDIN").await?;
+
+    let count: i64 = sqlx::query_scalar("SELECT COUNT(*) FROM users")
+        .fetch_one(&mut conn)
+        .await?;
+
+    assert_eq!(count, 0);
+
+    Ok(())
+}
+
+#[sqlx_macros::test]
+async fn it_returns_the_error_of_an_abandoned_query() -> anyhow::Result<()> {
+    let mut conn = new::<Postgres>().await?;
+
+    {
+        let mut cursor = conn.fetch("SELECT 1; SELECT 1 / 0");
+
+        // the second statement fails after we have stopped reading
+        assert!(cursor.try_next().await?.is_some());
+    }
+
+    let err = sqlx::query_scalar::<_, i32>("SELECT 1")
+        .fetch_one(&mut conn)
+        .await
+        .unwrap_err()
+        .into_database_error()
+        .unwrap();
+
+    assert_eq!(err.code().as_deref(), Some("22012"));
+
+    // the connection is still usable
+    let value: i32 = sqlx::query_scalar("SELECT 1").fetch_one(&mut conn).await?;
+    assert_eq!(value, 1);
+
+    Ok(())
+}
+
+#[sqlx_macros::test]
+async fn it_can_copy_with_pool() -> anyhow::Result<()> {
+    let pool = sqlx_test::pool::<Postgres>().await?;
+
+    let mut copy = pool
+        .copy_in_raw("COPY tweet (text) FROM STDIN WITH (FORMAT CSV)")
+        .await?;
+
+    assert_eq!(copy.send(&b"\"Hello, COPY\"\n"[..]).await?.num_columns(), 1);
+    assert_eq!(copy.finish().await?, 1);
+
+    let chunks: Vec<_> = pool
+        .copy_out_raw("COPY (SELECT text FROM tweet WHERE text = 'Hello, COPY') TO STDOUT")
+        .await?
+        .try_collect()
+        .await?;
+
+    assert!(!chunks.is_empty());
+
+    sqlx::query("DELETE FROM tweet WHERE text = 'Hello, COPY'")
+        .execute(&pool)
+        .await?;
+
+    Ok(())
+}
+
+#[sqlx_macros::test]
+async fn it_can_pipeline_queries() -> anyhow::Result<()> {
+    let mut conn = new::<Postgres>().await?;
+    let mut tx = conn.begin().await?;
+
+    let mut pipeline = PgPipeline::new();
+
+    pipeline
+        .push(sqlx::query(
+            "INSERT INTO tweet ( text ) VALUES ( 'Hello, Pipeline' )",
+        ))
+        .push(sqlx::query("SELECT generate_series(1, $1)").bind(3_i32))
+        .push(sqlx::query("SELECT text FROM tweet WHERE text = $1").bind("Hello, Pipeline"))
+        .push(sqlx::query("DELETE FROM tweet WHERE text = $1").bind("Hello, Pipeline"));
+
+    assert_eq!(pipeline.len(), 4);
+
+    let results = pipeline.fetch_all(&mut tx).await?;
+
+    assert_eq!(results.len(), 4);
+    assert!(results[0].is_empty());
+
+    let series: Vec<i32> = results[1].iter().map(|row| row.get(0)).collect();
+    assert_eq!(series, vec![1, 2, 3]);
+
+    assert_eq!(results[2].len(), 1);
+    assert_eq!(results[2][0].get::<&str, _>("text"), "Hello, Pipeline");
+
+    let mut pipeline = PgPipeline::new();
+
+    pipeline
+        .push(sqlx::query("INSERT INTO tweet ( text ) VALUES ( $1 )").bind("a"))
+        .push(sqlx::query("INSERT INTO tweet ( text ) VALUES ( $1 )").bind("b"))
+        .push(sqlx::query("DELETE FROM tweet WHERE text IN ('a', 'b')"));
+
+    let done = pipeline.execute(&mut tx).await?;
+    let rows_affected: Vec<u64> = done.iter().map(|d| d.rows_affected()).collect();
+
+    assert_eq!(rows_affected, vec![1, 1, 2]);
+
+    tx.rollback().await?;
+
+    Ok(())
+}
+
+#[sqlx_macros::test]
+async fn it_can_fail_and_recover_from_pipeline() -> anyhow::Result<()> {
+    let mut conn = new::<Postgres>().await?;
+
+    conn.execute("CREATE TEMPORARY TABLE users (id INTEGER PRIMARY KEY);")
+        .await?;
+
+    let mut pipeline = PgPipeline::new();
+
+    pipeline
+        .push(sqlx::query("INSERT INTO users (id) VALUES ($1)").bind(1_i32))
+        .push(sqlx::query("INSERT INTO users (id) VALUES ($1)").bind(1_i32))
+        .push(sqlx::query("INSERT INTO users (id) VALUES ($1)").bind(2_i32));
+
+    assert!(pipeline.execute(&mut conn).await.is_err());
+
+    // the whole pipeline ran in one implicit transaction which was rolled back
+    let count: i64 = sqlx::query_scalar("SELECT COUNT(*) FROM users")
+        .fetch_one(&mut conn)
+        .await?;
+
+    assert_eq!(count, 0);
+
+    Ok(())
+}
+
+#[sqlx_macros::test]
+async fn it_can_pipeline_more_statements_than_the_cache_holds() -> anyhow::Result<()> {
+    sqlx_test::setup_if_needed();
+
+    let mut options: PgConnectOptions = env::var("DATABASE_URL")?.parse().unwrap();
+
+    // each statement prepared for the pipeline closes the one before it
+    options = options.statement_cache_capacity(1);
+
+    let mut conn = PgConnection::connect_with(&options).await?;
+    let mut pipeline = PgPipeline::new();
+
+    let queries: Vec<String> = (0..5)
+        .map(|i| format!("SELECT {}::int4 + $1 AS val", i))
+        .collect();
+
+    for query in &queries {
+        pipeline.push(sqlx::query(query).bind(10_i32));
+    }
+
+    let results = pipeline.fetch_all(&mut conn).await?;
+    let values: Vec<i32> = results.iter().map(|rows| rows[0].get("val")).collect();
+
+    assert_eq!(values, vec![10, 11, 12, 13, 14]);
+    assert_eq!(1, conn.cached_statements_size());
+
+    // the statements prepared again outside of the cache are closed, as are the ones
+    // evicted from it; only the statement of this query is left
+    let prepared: i64 = sqlx::query_scalar("SELECT COUNT(*) FROM pg_prepared_statements")
+        .fetch_one(&mut conn)
+        .await?;
+
+    assert_eq!(1, prepared);
+
+    // the same holds when the results of the pipeline are dropped
+    let mut pipeline = PgPipeline::new();
+
+    for query in &queries {
+        pipeline.push(sqlx::query(query).bind(10_i32));
+    }
+
+    drop(pipeline.fetch_many(&mut conn).try_next().await?);
+
+    let prepared: i64 = sqlx::query_scalar("SELECT COUNT(*) FROM pg_prepared_statements")
+        .fetch_one(&mut conn)
+        .await?;
+
+    assert_eq!(1, prepared);
+
+    Ok(())
+}
+
+#[sqlx_macros::test]
+async fn it_can_pipeline_a_query_twice_with_a_smaller_cache() -> anyhow::Result<()> {
+    sqlx_test::setup_if_needed();
+
+    let options: PgConnectOptions = env::var("DATABASE_URL")?.parse().unwrap();
+    let mut conn = PgConnection::connect_with(&options.statement_cache_capacity(1)).await?;
+
+    // the second query evicts the statement of the first, which is then cached again under a
+    // new statement when it repeats; the first entry must not use the one that was closed
+    let mut pipeline = PgPipeline::new();
+
+    pipeline
+        .push(sqlx::query("SELECT 1::int4 + $1 AS val").bind(10_i32))
+        .push(sqlx::query("SELECT 2::int4 + $1 AS val").bind(10_i32))
+        .push(sqlx::query("SELECT 1::int4 + $1 AS val").bind(20_i32));
+
+    let results = pipeline.fetch_all(&mut conn).await?;
+    let values: Vec<i32> = results.iter().map(|rows| rows[0].get("val")).collect();
+
+    assert_eq!(values, vec![11, 12, 21]);
+
+    let prepared: i64 = sqlx::query_scalar("SELECT COUNT(*) FROM pg_prepared_statements")
+        .fetch_one(&mut conn)
+        .await?;
+
+    assert_eq!(1, prepared);
+
+    Ok(())
+}
+
+#[sqlx_macros::test]
+async fn it_can_execute_batch() -> anyhow::Result<()> {
+    let mut conn = new::<Postgres>().await?;
+
+    conn.execute("CREATE TEMPORARY TABLE users (id INTEGER PRIMARY KEY, name TEXT NOT NULL);")
+        .await?;
+
+    let arguments = (1..=3_i32).map(|id| {
+        let mut args = PgArguments::default();
+        args.add(id);
+        args.add(format!("user {}", id));
+        args
+    });
+
+    let done = conn
+        .execute_batch("INSERT INTO users (id, name) VALUES ($1, $2)", arguments)
+        .await?;
+
+    assert_eq!(done.len(), 3);
+    assert!(done.iter().all(|d| d.rows_affected() == 1));
+
+    // a failing execution rolls back the whole batch
+    let arguments = vec![4_i32, 1, 5].into_iter().map(|id| {
+        let mut args = PgArguments::default();
+        args.add(id);
+        args
+    });
+
+    let res = conn
+        .execute_batch("INSERT INTO users (id, name) VALUES ($1, 'x')", arguments)
+        .await;
+
+    assert!(res.is_err());
+
+    let count: i64 = sqlx::query_scalar("SELECT COUNT(*) FROM users")
+        .fetch_one(&mut conn)
+        .await?;
+
+    assert_eq!(count, 3);
+
+    Ok(())
+}
+
+#[sqlx_macros::test]
+async fn it_can_cancel_a_query() -> anyhow::Result<()> {
+    let mut conn = new::<Postgres>().await?;
+
+    let token = conn.cancel_token();
+
+    sqlx_rt::spawn(async move {
+        sqlx_rt::sleep(Duration::from_millis(500)).await;
+        token.cancel().await.unwrap();
+    });
+
+    let res = conn.execute("SELECT pg_sleep(30)").await;
+
+    let err = res.unwrap_err().into_database_error().unwrap();
+    assert_eq!(err.code().as_deref(), Some("57014"));
+
+    // the connection is still usable
+    let value: i32 = sqlx::query_scalar("SELECT 1").fetch_one(&mut conn).await?;
+    assert_eq!(value, 1);
+
+    Ok(())
+}
+
+#[sqlx_macros::test]
+async fn it_cancels_a_dropped_query() -> anyhow::Result<()> {
+    sqlx_test::setup_if_needed();
+
+    let options: PgConnectOptions = env::var("DATABASE_URL")?.parse().unwrap();
+    let mut conn = PgConnection::connect_with(&options.cancel_on_drop(true)).await?;
+
+    let res = sqlx_rt::timeout(
+        Duration::from_millis(500),
+        sqlx::query("SELECT pg_sleep($1)")
+            .bind(30_f64)
+            .fetch_all(&mut conn),
+    )
+    .await;
+
+    assert!(res.is_err());
+
+    // without the cancel, this would wait for the sleep to complete
+    let started = std::time::Instant::now();
+
+    let value: i32 = sqlx::query_scalar("SELECT 1").fetch_one(&mut conn).await?;
+    assert_eq!(value, 1);
+
+    assert!(started.elapsed() < Duration::from_secs(10));
+
+    Ok(())
+}
+
+#[sqlx_macros::test]
+async fn it_closes_the_connection_if_a_dropped_query_cannot_be_canceled() -> anyhow::Result<()> {
+    use std::net::{TcpListener, TcpStream};
+
+    sqlx_test::setup_if_needed();
+
+    let url = url::Url::parse(&env::var("DATABASE_URL")?)?;
+    let upstream = format!(
+        "{}:{}",
+        url.host_str().unwrap_or("localhost"),
+        url.port().unwrap_or(5432)
+    );
+
+    // forward the first connection to the server and leave any later one (the cancel
+    // request) hanging
+    let listener = TcpListener::bind("127.0.0.1:0")?;
+    let port = listener.local_addr()?.port();
+
+    thread::spawn(move || {
+        let mut incoming = listener.incoming();
+
+        if let Some(Ok(client)) = incoming.next() {
+            let server = TcpStream::connect(&upstream).unwrap();
+
+            let (mut client_r, mut server_w) =
+                (client.try_clone().unwrap(), server.try_clone().unwrap());
+            thread::spawn(move || std::io::copy(&mut client_r, &mut server_w));
+
+            let (mut server_r, mut client_w) = (server, client);
+            thread::spawn(move || std::io::copy(&mut server_r, &mut client_w));
+        }
+
+        let _hanging: Vec<_> = incoming.collect();
+    });
+
+    let options: PgConnectOptions = env::var("DATABASE_URL")?.parse().unwrap();
+    let options = options
+        .host("127.0.0.1")
+        .port(port)
+        .cancel_on_drop(true)
+        .cancel_timeout(Duration::from_secs(1));
+
+    let mut conn = PgConnection::connect_with(&options).await?;
+
+    let res = sqlx_rt::timeout(
+        Duration::from_millis(500),
+        sqlx::query("SELECT pg_sleep($1)")
+            .bind(30_f64)
+            .fetch_all(&mut conn),
+    )
+    .await;
+
+    assert!(res.is_err());
+
+    // the cancel request times out; instead of waiting for the sleep, the connection is closed
+    let started = std::time::Instant::now();
+
+    let res = sqlx::query_scalar::<_, i32>("SELECT 1")
+        .fetch_one(&mut conn)
+        .await;
+
+    assert!(res.is_err());
+    assert!(started.elapsed() < Duration::from_secs(10));
+
+    Ok(())
+}
+
+#[sqlx_macros::test]
+async fn it_sends_startup_params() -> anyhow::Result<()> {
+    sqlx_test::setup_if_needed();
+
+    let options: PgConnectOptions = env::var("DATABASE_URL")?.parse().unwrap();
+    let mut conn = PgConnection::connect_with(
+        &options
+            .application_name("sqlx-test")
+            .options("-c statement_timeout=5000")
+            .startup_param("search_path", "pg_catalog"),
+    )
+    .await?;
+
+    let name: String = sqlx::query_scalar(
+        "SELECT application_name FROM pg_stat_activity WHERE pid = pg_backend_pid()",
+    )
+    .fetch_one(&mut conn)
+    .await?;
+
+    assert_eq!(name, "sqlx-test");
+
+    let timeout: String = sqlx::query_scalar("SHOW statement_timeout")
+        .fetch_one(&mut conn)
+        .await?;
+
+    assert_eq!(timeout, "5s");
+
+    let search_path: String = sqlx::query_scalar("SHOW search_path")
+        .fetch_one(&mut conn)
+        .await?;
+
+    assert_eq!(search_path, "pg_catalog");
+
+    Ok(())
+}
+
+#[sqlx_macros::test]
+async fn it_checks_target_session_attrs() -> anyhow::Result<()> {
+    sqlx_test::setup_if_needed();
+
+    let options: PgConnectOptions = env::var("DATABASE_URL")?.parse().unwrap();
+
+    let conn = PgConnection::connect_with(
+        &options
+            .clone()
+            .target_session_attrs(PgTargetSessionAttrs::ReadWrite),
+    )
+    .await?;
+
+    conn.close().await?;
+
+    let res = PgConnection::connect_with(
+        &options
+            .clone()
+            .target_session_attrs(PgTargetSessionAttrs::ReadOnly),
+    )
+    .await;
+
+    assert!(res.is_err());
+
+    // a session that is read-only by default is what a standby looks like
+    let conn = PgConnection::connect_with(
+        &options
+            .startup_param("default_transaction_read_only", "on")
+            .target_session_attrs(PgTargetSessionAttrs::ReadOnly),
+    )
+    .await?;
+
+    conn.close().await?;
+
+    Ok(())
+}
+
+#[sqlx_macros::test]
+async fn it_fails_over_to_the_next_host() -> anyhow::Result<()> {
+    sqlx_test::setup_if_needed();
+
+    let url: url::Url = env::var("DATABASE_URL")?.parse()?;
+    let host = url.host_str().unwrap_or("localhost");
+    let port = url.port().unwrap_or(5432);
+
+    // nothing listens on port 1 of the first host
+    let mut failover = url.clone();
+    failover.set_port(Some(1)).unwrap();
+
+    let uri = failover.as_str().replacen(
+        &format!("{}:1", host),
+        &format!("{}:1,{}:{}", host, host, port),
+        1,
+    );
+
+    let mut conn = PgConnection::connect(&format!(
+        "{}{}target_session_attrs=read-write",
+        uri,
+        if uri.contains('?') { "&" } else { "?" }
+    ))
+    .await?;
+
+    let value: i32 = sqlx::query_scalar("SELECT 1").fetch_one(&mut conn).await?;
+
+    assert_eq!(1, value);
+
+    Ok(())
+}
+
+#[sqlx_macros::test]
+async fn it_can_bind_and_decode_ltree_and_lquery() -> anyhow::Result<()> {
+    let mut conn = new::<Postgres>().await?;
+
+    // the binary format of `ltree` and `lquery` was added in PostgreSQL 13
+    let version: String = sqlx::query_scalar("SHOW server_version_num")
+        .fetch_one(&mut conn)
+        .await?;
+
+    if version.parse::<i32>()? < 130000 {
+        return Ok(());
+    }
+
+    let path: PgLTree = "Top.Science.Astronomy".parse()?;
+    let query = PgLQuery::new("*.Science.*");
+
+    let (matches, parent, pattern): (bool, PgLTree, PgLQuery) =
+        sqlx::query_as("SELECT $1 ~ $2, subpath($1, 0, 2), $2")
+            .bind(&path)
+            .bind(&query)
+            .fetch_one(&mut conn)
+            .await?;
+
+    assert!(matches);
+    assert_eq!(parent.to_string(), "Top.Science");
+    assert_eq!(pattern, query);
+
+    let paths: Vec<PgLTree> = sqlx::query_scalar("SELECT $1::ltree[]")
+        .bind(vec![path.clone(), PgLTree::new()])
+        .fetch_one(&mut conn)
+        .await?;
+
+    assert_eq!(paths, vec![path, PgLTree::new()]);
+
+    Ok(())
+}
+
+#[sqlx_macros::test]
+async fn it_compares_citext_case_insensitively() -> anyhow::Result<()> {
+    let mut conn = new::<Postgres>().await?;
+
+    let (equal, value): (bool, PgCiText) = sqlx::query_as("SELECT $1 = 'HELLO'::citext, $1")
+        .bind(PgCiText::from("Hello"))
+        .fetch_one(&mut conn)
+        .await?;
+
+    assert!(equal);
+    assert_eq!(value, PgCiText::from("Hello"));
+
+    Ok(())
+}
@@ -4,7 +4,10 @@ use std::ops::Bound;
 #[cfg(feature = "decimal")]
 use std::str::FromStr;
 
-use sqlx::postgres::types::{PgInterval, PgMoney, PgRange};
+use sqlx::postgres::types::{
+    PgArray, PgArrayDimension, PgBox, PgCiText, PgCircle, PgHstore, PgInterval, PgLSeg, PgLine,
+    PgMoney, PgPath, PgPoint, PgPolygon, PgRange,
+};
 use sqlx::postgres::Postgres;
 use sqlx_test::{test_decode_type, test_prepared_type, test_type};
 
@@ -65,6 +68,48 @@ test_type!(string_vec<Vec<String>>(Postgres,
         == vec!["Hello, World", "", "Goodbye"]
 ));
 
+test_type!(int_vec_vec<Vec<Vec<i32>>>(Postgres,
+    "array[[1,2,3],[4,5,6]]::int4[]"
+        == vec![vec![1, 2, 3], vec![4, 5, 6]],
+
+    "array[[7]]::int4[]"
+        == vec![vec![7]],
+
+    "'{}'::int4[]"
+        == Vec::<Vec<i32>>::new(),
+));
+
+test_type!(int_vec_vec_vec<Vec<Vec<Vec<i16>>>>(Postgres,
+    "array[[[1,2],[3,4]],[[5,6],[7,8]]]::int2[]"
+        == vec![vec![vec![1_i16, 2], vec![3, 4]], vec![vec![5, 6], vec![7, 8]]],
+));
+
+test_type!(string_vec_vec<Vec<Vec<Option<String>>>>(Postgres,
+    "array[['a','b, c'],[NULL,'\"{}\"'],['NULL','']]::text[]"
+        == vec![
+            vec![Some("a".to_owned()), Some("b, c".to_owned())],
+            vec![None, Some("\"{}\"".to_owned())],
+            vec![Some("NULL".to_owned()), Some("".to_owned())],
+        ],
+));
+
+test_type!(int_array<PgArray<Option<i32>>>(Postgres,
+    "'[0:1][-1:1]={{1,2,3},{4,5,NULL}}'::int4[]"
+        == PgArray::new(
+            vec![
+                PgArrayDimension { len: 2, lower_bound: 0 },
+                PgArrayDimension { len: 3, lower_bound: -1 },
+            ],
+            vec![Some(1), Some(2), Some(3), Some(4), Some(5), None],
+        ),
+
+    "array[1,2]::int4[]"
+        == PgArray::from(vec![Some(1), Some(2)]),
+
+    "'{}'::int4[]"
+        == PgArray::<Option<i32>>::from(Vec::new()),
+));
+
 test_type!(i8(
     Postgres,
     "0::\"char\"" == 0_i8,
@@ -176,6 +221,52 @@ test_type!(ipnetwork_vec<Vec<sqlx::types::ipnetwork::IpNetwork>>(Postgres,
         ]
 ));
 
+#[cfg(feature = "mac_address")]
+test_type!(mac_address<sqlx::types::mac_address::MacAddress>(Postgres,
+    "'08:00:2b:01:02:03'::macaddr"
+        == "08:00:2b:01:02:03".parse::<sqlx::types::mac_address::MacAddress>().unwrap(),
+));
+
+#[cfg(feature = "mac_address")]
+test_decode_type!(mac_address_from_macaddr8<sqlx::types::mac_address::MacAddress>(Postgres,
+    "'08:00:2b:01:02:03'::macaddr8"
+        == "08:00:2b:01:02:03".parse::<sqlx::types::mac_address::MacAddress>().unwrap(),
+));
+
+#[cfg(feature = "mac_address")]
+test_type!(mac_address_vec<Vec<sqlx::types::mac_address::MacAddress>>(Postgres,
+    "'{08:00:2b:01:02:03,00:00:00:00:00:00}'::macaddr[]"
+        == vec![
+            "08:00:2b:01:02:03".parse::<sqlx::types::mac_address::MacAddress>().unwrap(),
+            "00:00:00:00:00:00".parse::<sqlx::types::mac_address::MacAddress>().unwrap(),
+        ],
+));
+
+#[cfg(feature = "bit-vec")]
+test_type!(bitvec<sqlx::types::BitVec>(Postgres,
+    "B'1'::varbit" == sqlx::types::BitVec::from_elem(1, true),
+    "B''::varbit" == sqlx::types::BitVec::new(),
+    "B'0101101101'::varbit"
+        == [false, true, false, true, true, false, true, true, false, true]
+            .iter()
+            .copied()
+            .collect::<sqlx::types::BitVec>(),
+));
+
+#[cfg(feature = "bit-vec")]
+test_decode_type!(bitvec_from_bit<sqlx::types::BitVec>(Postgres,
+    "B'101000001'::bit(9)" == sqlx::types::BitVec::from_fn(9, |i| i == 0 || i == 2 || i == 8),
+));
+
+#[cfg(feature = "bit-vec")]
+test_type!(bitvec_vec<Vec<sqlx::types::BitVec>>(Postgres,
+    "array[B'01', B'1']::varbit[]"
+        == vec![
+            sqlx::types::BitVec::from_fn(2, |i| i == 1),
+            sqlx::types::BitVec::from_elem(1, true),
+        ],
+));
+
 #[cfg(feature = "chrono")]
 mod chrono {
     use super::*;
@@ -443,3 +534,101 @@ test_prepared_type!(money<PgMoney>(Postgres, "123.45::money" == PgMoney(12345)))
 test_prepared_type!(money_vec<Vec<PgMoney>>(Postgres,
     "array[123.45,420.00,666.66]::money[]" == vec![PgMoney(12345), PgMoney(42000), PgMoney(66666)],
 ));
+
+test_type!(hstore<PgHstore>(Postgres,
+    "''::hstore" == PgHstore::default(),
+    "'a=>1, b=>NULL'::hstore"
+        == vec![("a", Some("1".to_owned())), ("b", None)].into_iter().collect::<PgHstore>(),
+    "'\"key with spaces\"=>\"a \\\"quoted\\\" value\"'::hstore"
+        == vec![("key with spaces", Some("a \"quoted\" value".to_owned()))].into_iter().collect::<PgHstore>(),
+));
+
+test_type!(hstore_vec<Vec<PgHstore>>(Postgres,
+    "array['a=>1'::hstore, ''::hstore]"
+        == vec![
+            vec![("a", Some("1".to_owned()))].into_iter().collect::<PgHstore>(),
+            PgHstore::default(),
+        ],
+));
+
+test_type!(citext<PgCiText>(Postgres,
+    "'Hello, World'::citext" == PgCiText::from("Hello, World"),
+));
+
+test_type!(citext_vec<Vec<PgCiText>>(Postgres,
+    "array['One', 'TWO']::citext[]" == vec![PgCiText::from("One"), PgCiText::from("TWO")],
+));
+
+test_prepared_type!(string_citext<String>(Postgres,
+    "'Case Insensitive'::citext" == "Case Insensitive",
+));
+
+test_type!(point<PgPoint>(Postgres,
+    "SELECT ({0} ~= $1)::int4, {0}, $2",
+    "point(1.5, -2)" == PgPoint { x: 1.5, y: -2.0 },
+));
+
+// there is no `=` operator for `point` so arrays of points cannot be compared
+test_decode_type!(point_vec<Vec<PgPoint>>(Postgres,
+    "array[point(0, 0), point(1, 2)]" == vec![PgPoint { x: 0.0, y: 0.0 }, PgPoint { x: 1.0, y: 2.0 }],
+));
+
+test_type!(line<PgLine>(Postgres,
+    "line '{1, -1, 0.5}'" == PgLine { a: 1.0, b: -1.0, c: 0.5 },
+));
+
+test_type!(lseg<PgLSeg>(Postgres,
+    "lseg '[(0, 0), (1, 2)]'"
+        == PgLSeg { start: PgPoint { x: 0.0, y: 0.0 }, end: PgPoint { x: 1.0, y: 2.0 } },
+));
+
+test_type!(box<PgBox>(Postgres,
+    "SELECT ({0} ~= $1)::int4, {0}, $2",
+    "box '(1, 2), (-3, 4)'"
+        == PgBox {
+            upper_right: PgPoint { x: 1.0, y: 4.0 },
+            lower_left: PgPoint { x: -3.0, y: 2.0 },
+        },
+));
+
+// the elements of a BOX[] are separated by ';', and box has no equality for arrays
+test_type!(box_vec<Vec<PgBox>>(Postgres,
+    "SELECT ({0}::text = $1::text)::int4, {0}, $2",
+    "array[box '(1, 2), (-3, 4)', box '(0, 0), (1, 1)']"
+        == vec![
+            PgBox {
+                upper_right: PgPoint { x: 1.0, y: 4.0 },
+                lower_left: PgPoint { x: -3.0, y: 2.0 },
+            },
+            PgBox {
+                upper_right: PgPoint { x: 1.0, y: 1.0 },
+                lower_left: PgPoint { x: 0.0, y: 0.0 },
+            },
+        ],
+));
+
+test_type!(path<PgPath>(Postgres,
+    "path '[(0, 0), (1, 1), (2, 0)]'"
+        == PgPath {
+            closed: false,
+            points: vec![PgPoint { x: 0.0, y: 0.0 }, PgPoint { x: 1.0, y: 1.0 }, PgPoint { x: 2.0, y: 0.0 }],
+        },
+    "path '((0, 0), (1, 1))'"
+        == PgPath {
+            closed: true,
+            points: vec![PgPoint { x: 0.0, y: 0.0 }, PgPoint { x: 1.0, y: 1.0 }],
+        },
+));
+
+test_type!(polygon<PgPolygon>(Postgres,
+    "SELECT ({0} ~= $1)::int4, {0}, $2",
+    "polygon '((0, 0), (0, 1), (1, 0))'"
+        == PgPolygon {
+            points: vec![PgPoint { x: 0.0, y: 0.0 }, PgPoint { x: 0.0, y: 1.0 }, PgPoint { x: 1.0, y: 0.0 }],
+        },
+));
+
+test_type!(circle<PgCircle>(Postgres,
+    "SELECT ({0} ~= $1)::int4, {0}, $2",
+    "circle '<(1, 2), 3.5>'" == PgCircle { center: PgPoint { x: 1.0, y: 2.0 }, radius: 3.5 },
+));
@@ -0,0 +1,188 @@
+use sqlx::migrate::{Migrate, MigrationType, Migrator};
+use sqlx::{Connection, Executor, SqliteConnection};
+use std::path::Path;
+
+async fn table_exists(conn: &mut SqliteConnection, name: &str) -> anyhow::Result<bool> {
+    let tables: Vec<String> =
+        sqlx::query_scalar("SELECT name FROM sqlite_master WHERE type = 'table'")
+            .fetch_all(conn)
+            .await?;
+
+    Ok(tables.iter().any(|table| table == name))
+}
+
+#[sqlx_macros::test]
+async fn it_can_run_and_undo_reversible_migrations() -> anyhow::Result<()> {
+    let mut conn = SqliteConnection::connect("sqlite::memory:").await?;
+    let migrator = Migrator::new(Path::new("tests/migrate/reversible")).await?;
+
+    let types: Vec<_> = migrator.iter().map(|m| m.migration_type()).collect();
+
+    assert_eq!(
+        types,
+        [
+            MigrationType::ReversibleUp,
+            MigrationType::ReversibleDown,
+            MigrationType::ReversibleUp,
+            MigrationType::ReversibleDown,
+        ]
+    );
+
+    migrator.run(&mut conn).await?;
+
+    assert!(table_exists(&mut conn, "users").await?);
+    assert!(table_exists(&mut conn, "user_emails").await?);
+
+    // running again is a no-op
+    migrator.run(&mut conn).await?;
+
+    migrator.undo(&mut conn, 20201001000000).await?;
+
+    assert!(table_exists(&mut conn, "users").await?);
+    assert!(!table_exists(&mut conn, "user_emails").await?);
+
+    migrator.undo(&mut conn, 0).await?;
+
+    assert!(!table_exists(&mut conn, "users").await?);
+
+    let applied: i32 = sqlx::query_scalar("SELECT COUNT(*) FROM _sqlx_migrations")
+        .fetch_one(&mut conn)
+        .await?;
+
+    assert_eq!(applied, 0);
+
+    // and everything can be applied again
+    migrator.run(&mut conn).await?;
+
+    assert!(table_exists(&mut conn, "user_emails").await?);
+
+    Ok(())
+}
+
+#[sqlx_macros::test]
+async fn it_only_undoes_migrations_that_were_applied() -> anyhow::Result<()> {
+    let mut conn = SqliteConnection::connect("sqlite::memory:").await?;
+    let migrator = Migrator::new(Path::new("tests/migrate/reversible")).await?;
+
+    migrator.run(&mut conn).await?;
+
+    // as if the first migration had been added after the second was applied
+    conn.execute("DELETE FROM _sqlx_migrations WHERE version = 20201001000000")
+        .await?;
+
+    migrator.undo(&mut conn, 0).await?;
+
+    assert!(!table_exists(&mut conn, "user_emails").await?);
+    assert!(table_exists(&mut conn, "users").await?);
+
+    Ok(())
+}
+
+static EMBEDDED: Migrator = sqlx::migrate!("tests/migrate/reversible");
+
+#[sqlx_macros::test]
+async fn it_can_run_embedded_migrations() -> anyhow::Result<()> {
+    let mut conn = SqliteConnection::connect("sqlite::memory:").await?;
+    let migrator = Migrator::new(Path::new("tests/migrate/reversible")).await?;
+
+    // the embedded migrations must be identical to those read at runtime
+    assert_eq!(EMBEDDED.iter().count(), migrator.iter().count());
+
+    for (embedded, resolved) in EMBEDDED.iter().zip(migrator.iter()) {
+        assert_eq!(embedded.version(), resolved.version());
+        assert_eq!(embedded.description(), resolved.description());
+        assert_eq!(embedded.migration_type(), resolved.migration_type());
+        assert_eq!(embedded.sql, resolved.sql);
+        assert_eq!(embedded.checksum, resolved.checksum);
+    }
+
+    EMBEDDED.run(&mut conn).await?;
+
+    assert!(table_exists(&mut conn, "user_emails").await?);
+
+    // migrations applied from the embedded migrator validate against the runtime one
+    migrator.run(&mut conn).await?;
+
+    Ok(())
+}
+
+#[sqlx_macros::test]
+async fn it_can_run_migrations_outside_of_a_transaction() -> anyhow::Result<()> {
+    let mut conn = SqliteConnection::connect("sqlite::memory:").await?;
+    let migrator = Migrator::new(Path::new("tests/migrate/no-transaction")).await?;
+
+    let no_tx: Vec<_> = migrator.iter().map(|m| m.no_tx()).collect();
+
+    assert_eq!(no_tx, [false, true]);
+
+    // `VACUUM` fails if it is run in a transaction
+    migrator.run(&mut conn).await?;
+
+    let applied: Vec<(i64, bool)> =
+        sqlx::query_as("SELECT version, success FROM _sqlx_migrations ORDER BY version")
+            .fetch_all(&mut conn)
+            .await?;
+
+    assert_eq!(applied, [(20201101000000, true), (20201102000000, true)]);
+
+    Ok(())
+}
+
+#[sqlx_macros::test]
+async fn it_can_list_applied_migrations() -> anyhow::Result<()> {
+    let mut conn = SqliteConnection::connect("sqlite::memory:").await?;
+    let migrator = Migrator::new(Path::new("tests/migrate/reversible")).await?;
+
+    migrator.run(&mut conn).await?;
+
+    let applied = conn.list_applied_migrations().await?;
+    let expected: Vec<_> = migrator
+        .iter()
+        .filter(|m| m.migration_type().is_up_migration())
+        .collect();
+
+    assert_eq!(applied.len(), expected.len());
+
+    for (applied, expected) in applied.iter().zip(expected) {
+        assert_eq!(applied.version, expected.version());
+        assert_eq!(applied.description, expected.description());
+        assert_eq!(applied.checksum, &*expected.checksum);
+        assert!(applied.success);
+        assert!(!applied.installed_on.is_empty());
+    }
+
+    Ok(())
+}
+
+#[sqlx_macros::test]
+async fn it_can_list_pending_migrations() -> anyhow::Result<()> {
+    let mut conn = SqliteConnection::connect("sqlite::memory:").await?;
+    let migrator = Migrator::new(Path::new("tests/migrate/reversible")).await?;
+
+    let pending: Vec<_> = migrator
+        .pending(&mut conn)
+        .await?
+        .iter()
+        .map(|m| m.version())
+        .collect();
+
+    assert_eq!(pending, [20201001000000, 20201002000000]);
+
+    // nothing was applied, and the migrations table was not created
+    assert!(!table_exists(&mut conn, "users").await?);
+    assert!(!table_exists(&mut conn, "_sqlx_migrations").await?);
+
+    migrator.run(&mut conn).await?;
+    migrator.undo(&mut conn, 20201001000000).await?;
+
+    let pending: Vec<_> = migrator
+        .pending(&mut conn)
+        .await?
+        .iter()
+        .map(|m| m.version())
+        .collect();
+
+    assert_eq!(pending, [20201002000000]);
+
+    Ok(())
+}
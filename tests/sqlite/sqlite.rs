@@ -1,10 +1,16 @@
 use futures::TryStreamExt;
-use sqlx::sqlite::SqlitePoolOptions;
+use log::LevelFilter;
+use sqlx::sqlite::{
+    SqliteArguments, SqliteAutoVacuum, SqliteConnectOptions, SqliteLockingMode, SqlitePoolOptions,
+    SqliteSynchronous,
+};
 use sqlx::{
-    query, sqlite::Sqlite, sqlite::SqliteRow, Column, Connection, Done, Executor, Row,
-    SqliteConnection, SqlitePool, Statement, TypeInfo,
+    query, sqlite::Sqlite, sqlite::SqliteRow, Arguments, Column, ConnectOptions, Connection, Done,
+    Executor, Row, SqliteConnection, SqlitePool, Statement, TypeInfo,
 };
 use sqlx_test::new;
+use std::env;
+use std::time::Duration;
 
 #[sqlx_macros::test]
 async fn it_connects() -> anyhow::Result<()> {
@@ -186,6 +192,160 @@ async fn it_fetches_in_loop() -> anyhow::Result<()> {
     Ok(())
 }
 
+#[sqlx_macros::test]
+async fn it_fetches_from_many_connections_at_once() -> anyhow::Result<()> {
+    // the connections share a pool of worker threads
+    let conns = futures::future::try_join_all((0..20).map(|_| new::<Sqlite>())).await?;
+
+    let counts = futures::future::try_join_all(conns.into_iter().map(|mut conn| async move {
+        let mut s = sqlx::query(
+            "WITH RECURSIVE n(i) AS (SELECT 1 UNION ALL SELECT i + 1 FROM n WHERE i < 100) \
+             SELECT i FROM n",
+        )
+        .fetch(&mut conn);
+
+        let mut count = 0;
+
+        while s.try_next().await?.is_some() {
+            count += 1;
+        }
+
+        drop(s);
+
+        // a query that is dropped partway through leaves the connection usable
+        let mut s = sqlx::query("SELECT 1 UNION ALL SELECT 2").fetch(&mut conn);
+        let _ = s.try_next().await?;
+        drop(s);
+
+        let value: i32 = sqlx::query_scalar("SELECT 3").fetch_one(&mut conn).await?;
+
+        assert_eq!(value, 3);
+
+        Ok::<_, sqlx::Error>(count)
+    }))
+    .await?;
+
+    assert!(counts.iter().all(|&count| count == 100));
+
+    Ok(())
+}
+
+#[sqlx_macros::test]
+async fn it_steps_while_more_statements_than_threads_wait_on_a_lock() -> anyhow::Result<()> {
+    let filename = env::temp_dir().join(format!("sqlx-busy-{}.db", std::process::id()));
+
+    let options = SqliteConnectOptions::new()
+        .filename(&filename)
+        .create_if_missing(true)
+        .busy_timeout(Duration::from_secs(20));
+
+    let mut writer = options.connect().await?;
+    writer
+        .execute("CREATE TABLE IF NOT EXISTS busy (id INTEGER)")
+        .await?;
+    writer.execute("BEGIN IMMEDIATE").await?;
+
+    let mut conns = Vec::new();
+
+    for _ in 0..80 {
+        conns.push(options.connect().await?);
+    }
+
+    // each of these waits on the write lock, keeping a worker thread busy
+    let waiting = futures::future::try_join_all(
+        conns
+            .iter_mut()
+            .map(|conn| sqlx::query("INSERT INTO busy (id) VALUES (1)").execute(conn)),
+    );
+
+    // the commit still gets a thread, and must not wait for the others to time out
+    let commit = async {
+        sqlx_rt::sleep(Duration::from_millis(500)).await;
+
+        let started = std::time::Instant::now();
+        writer.execute("COMMIT").await?;
+
+        Ok::<_, sqlx::Error>(started.elapsed())
+    };
+
+    let (waiting, commit) = futures::future::join(waiting, commit).await;
+
+    assert_eq!(waiting?.len(), 80);
+    assert!(commit? < Duration::from_secs(10));
+
+    drop(conns);
+    writer.close().await?;
+
+    let _ = std::fs::remove_file(&filename);
+
+    Ok(())
+}
+
+#[sqlx_macros::test]
+async fn it_can_reuse_a_connection_after_dropping_a_waiting_query() -> anyhow::Result<()> {
+    let filename = env::temp_dir().join(format!("sqlx-dropped-{}.db", std::process::id()));
+
+    let options = SqliteConnectOptions::new()
+        .filename(&filename)
+        .create_if_missing(true)
+        .busy_timeout(Duration::from_secs(20));
+
+    let mut writer = options.connect().await?;
+    writer
+        .execute("CREATE TABLE IF NOT EXISTS dropped (id INTEGER)")
+        .await?;
+    writer.execute("BEGIN IMMEDIATE").await?;
+
+    let mut conn = options.connect().await?;
+
+    // the insert waits on the write lock until it is dropped
+    let insert = sqlx::query("INSERT INTO dropped (id) VALUES (1)").execute(&mut conn);
+    assert!(sqlx_rt::timeout(Duration::from_millis(200), insert)
+        .await
+        .is_err());
+
+    writer.execute("COMMIT").await?;
+
+    // the insert completes once the lock is released, before the next query
+    let ids: Vec<i64> = sqlx::query_scalar("SELECT id FROM dropped")
+        .fetch_all(&mut conn)
+        .await?;
+    assert_eq!(ids, vec![1]);
+
+    writer.execute("BEGIN IMMEDIATE").await?;
+
+    let mut tx = conn.begin().await?;
+    let insert = sqlx::query("INSERT INTO dropped (id) VALUES (2)").execute(&mut tx);
+    assert!(sqlx_rt::timeout(Duration::from_millis(200), insert)
+        .await
+        .is_err());
+    drop(tx);
+
+    writer.execute("COMMIT").await?;
+
+    // the transaction is rolled back once the insert completes, before the next query
+    let ids: Vec<i64> = sqlx::query_scalar("SELECT id FROM dropped")
+        .fetch_all(&mut conn)
+        .await?;
+    assert_eq!(ids, vec![1]);
+
+    // a connection dropped while a query waits is closed once the query completes
+    writer.execute("BEGIN IMMEDIATE").await?;
+
+    let insert = sqlx::query("INSERT INTO dropped (id) VALUES (3)").execute(&mut conn);
+    assert!(sqlx_rt::timeout(Duration::from_millis(200), insert)
+        .await
+        .is_err());
+    drop(conn);
+
+    writer.execute("COMMIT").await?;
+    writer.close().await?;
+
+    let _ = std::fs::remove_file(&filename);
+
+    Ok(())
+}
+
 #[sqlx_macros::test]
 async fn it_executes_with_pool() -> anyhow::Result<()> {
     let pool: SqlitePool = SqlitePoolOptions::new()
@@ -367,6 +527,30 @@ SELECT id, text FROM _sqlx_test;
     Ok(())
 }
 
+#[sqlx_macros::test]
+async fn it_can_drop_a_table_after_fetch_optional() -> anyhow::Result<()> {
+    let mut conn = new::<Sqlite>().await?;
+
+    conn.execute(
+        r#"
+CREATE TEMPORARY TABLE _sqlx_drop_test (id INTEGER PRIMARY KEY);
+INSERT INTO _sqlx_drop_test (id) VALUES (1), (2);
+        "#,
+    )
+    .await?;
+
+    // the statement is left with a row to return, which keeps the table in use
+    let row = sqlx::query("SELECT id FROM _sqlx_drop_test")
+        .fetch_optional(&mut conn)
+        .await?;
+
+    assert!(row.is_some());
+
+    conn.execute("DROP TABLE _sqlx_drop_test").await?;
+
+    Ok(())
+}
+
 #[sqlx_macros::test]
 async fn it_supports_collations() -> anyhow::Result<()> {
     let mut conn = new::<Sqlite>().await?;
@@ -400,6 +584,110 @@ CREATE TEMPORARY TABLE users (id INTEGER PRIMARY KEY, name TEXT NOT NULL COLLATE
     Ok(())
 }
 
+#[sqlx_macros::test]
+async fn it_supports_collations_from_options() -> anyhow::Result<()> {
+    let options: SqliteConnectOptions = env::var("DATABASE_URL")?.parse()?;
+    let options = options.collation("test_reverse", |l, r| l.cmp(r).reverse());
+
+    let pool = SqlitePoolOptions::new()
+        .min_connections(2)
+        .max_connections(2)
+        .connect_with(options)
+        .await?;
+
+    // every connection in the pool has the collation
+    let (mut a, mut b) = (pool.acquire().await?, pool.acquire().await?);
+
+    for conn in [&mut a, &mut b] {
+        let names: Vec<String> = sqlx::query_scalar(
+            "SELECT name FROM (SELECT 'a' AS name UNION ALL SELECT 'b') ORDER BY name COLLATE test_reverse",
+        )
+        .fetch_all(conn)
+        .await?;
+
+        assert_eq!(names, vec!["b", "a"]);
+    }
+
+    Ok(())
+}
+
+#[sqlx_macros::test]
+async fn it_supports_user_defined_functions() -> anyhow::Result<()> {
+    use sqlx::error::BoxDynError;
+    use sqlx::sqlite::{SqliteAggregate, SqliteValueRef};
+    use sqlx::Decode;
+
+    #[derive(Default)]
+    struct Product(i64);
+
+    impl SqliteAggregate for Product {
+        type Output = i64;
+
+        fn step(&mut self, args: &[SqliteValueRef<'_>]) -> Result<(), BoxDynError> {
+            self.0 *= <i64 as Decode<Sqlite>>::decode(args[0].clone())?;
+
+            Ok(())
+        }
+
+        fn finalize(self) -> Result<i64, BoxDynError> {
+            Ok(self.0)
+        }
+    }
+
+    let options: SqliteConnectOptions = env::var("DATABASE_URL")?.parse()?;
+
+    let mut conn = options
+        .function("shout", 1, true, |args| {
+            let text = <Option<&str> as Decode<Sqlite>>::decode(args[0].clone())?;
+
+            match text {
+                Some("") => Err("nothing to shout".into()),
+                Some(text) => Ok(Some(format!("{}!", text.to_uppercase()))),
+                None => Ok(None),
+            }
+        })
+        .aggregate("product", 1, true, || Product(1))
+        .connect()
+        .await?;
+
+    let value: String = sqlx::query_scalar("SELECT shout(?)")
+        .bind("hello")
+        .fetch_one(&mut conn)
+        .await?;
+
+    assert_eq!(value, "HELLO!");
+
+    let value: Option<String> = sqlx::query_scalar("SELECT shout(NULL)")
+        .fetch_one(&mut conn)
+        .await?;
+
+    assert_eq!(value, None);
+
+    let err = sqlx::query("SELECT shout('')")
+        .execute(&mut conn)
+        .await
+        .unwrap_err();
+
+    assert!(err.to_string().contains("nothing to shout"));
+
+    let value: i64 = sqlx::query_scalar(
+        "SELECT product(value) FROM (SELECT 2 AS value UNION ALL SELECT 3 UNION ALL SELECT 7)",
+    )
+    .fetch_one(&mut conn)
+    .await?;
+
+    assert_eq!(value, 42);
+
+    // an aggregate over no rows still produces a value
+    let value: i64 = sqlx::query_scalar("SELECT product(value) FROM (SELECT 1 AS value) WHERE 0")
+        .fetch_one(&mut conn)
+        .await?;
+
+    assert_eq!(value, 1);
+
+    Ok(())
+}
+
 #[sqlx_macros::test]
 async fn it_caches_statements() -> anyhow::Result<()> {
     let mut conn = new::<Sqlite>().await?;
@@ -469,3 +757,175 @@ async fn it_can_prepare_then_execute() -> anyhow::Result<()> {
 
     Ok(())
 }
+
+#[sqlx_macros::test]
+async fn it_can_execute_batch() -> anyhow::Result<()> {
+    let mut conn = new::<Sqlite>().await?;
+
+    conn.execute("CREATE TEMPORARY TABLE users (id INTEGER PRIMARY KEY, name TEXT NOT NULL)")
+        .await?;
+
+    let arguments = (1..=3_i32).map(|id| {
+        let mut args = SqliteArguments::default();
+        args.add(id);
+        args.add(format!("user {}", id));
+        args
+    });
+
+    let done = conn
+        .execute_batch("INSERT INTO users (id, name) VALUES (?, ?)", arguments)
+        .await?;
+
+    assert_eq!(done.len(), 3);
+    assert!(done.iter().all(|d| d.rows_affected() == 1));
+    assert_eq!(done[2].last_insert_rowid(), 3);
+
+    let count: i32 = sqlx::query_scalar("SELECT COUNT(*) FROM users")
+        .fetch_one(&mut conn)
+        .await?;
+
+    assert_eq!(count, 3);
+
+    Ok(())
+}
+
+#[sqlx_macros::test]
+async fn it_can_configure_statement_logging() -> anyhow::Result<()> {
+    sqlx_test::setup_if_needed();
+
+    let options: SqliteConnectOptions = env::var("DATABASE_URL")?.parse()?;
+
+    let mut conn = options
+        .clone()
+        .log_statements(LevelFilter::Info)
+        .log_slow_statements(LevelFilter::Error, Duration::from_millis(10))
+        .log_parameters(true)
+        .connect()
+        .await?;
+
+    let value: i32 = sqlx::query_scalar("SELECT ?")
+        .bind(5_i32)
+        .fetch_one(&mut conn)
+        .await?;
+
+    assert_eq!(value, 5);
+
+    let mut conn = options.disable_statement_logging().connect().await?;
+
+    let value: String = sqlx::query_scalar("SELECT ?")
+        .bind("hello")
+        .fetch_one(&mut conn)
+        .await?;
+
+    assert_eq!(value, "hello");
+
+    Ok(())
+}
+
+#[sqlx_macros::test]
+async fn it_sets_pragmas_on_connect() -> anyhow::Result<()> {
+    let options: SqliteConnectOptions = env::var("DATABASE_URL")?.parse()?;
+
+    let mut conn = options
+        .busy_timeout(Duration::from_secs(30))
+        .synchronous(SqliteSynchronous::Normal)
+        .pragma("cache_size", "-1000")
+        .connect()
+        .await?;
+
+    let busy_timeout: i64 = sqlx::query_scalar("PRAGMA busy_timeout")
+        .fetch_one(&mut conn)
+        .await?;
+
+    assert_eq!(busy_timeout, 30_000);
+
+    // NORMAL
+    let synchronous: i64 = sqlx::query_scalar("PRAGMA synchronous")
+        .fetch_one(&mut conn)
+        .await?;
+
+    assert_eq!(synchronous, 1);
+
+    let cache_size: i64 = sqlx::query_scalar("PRAGMA cache_size")
+        .fetch_one(&mut conn)
+        .await?;
+
+    assert_eq!(cache_size, -1000);
+
+    // the page size and auto-vacuum mode only apply to a new database
+    let mut conn = "sqlite::memory:"
+        .parse::<SqliteConnectOptions>()?
+        .page_size(8192)
+        .auto_vacuum(SqliteAutoVacuum::Incremental)
+        .locking_mode(SqliteLockingMode::Exclusive)
+        .connect()
+        .await?;
+
+    let page_size: i64 = sqlx::query_scalar("PRAGMA page_size")
+        .fetch_one(&mut conn)
+        .await?;
+
+    assert_eq!(page_size, 8192);
+
+    // INCREMENTAL
+    let auto_vacuum: i64 = sqlx::query_scalar("PRAGMA auto_vacuum")
+        .fetch_one(&mut conn)
+        .await?;
+
+    assert_eq!(auto_vacuum, 2);
+
+    let locking_mode: String = sqlx::query_scalar("PRAGMA locking_mode")
+        .fetch_one(&mut conn)
+        .await?;
+
+    assert_eq!(locking_mode, "exclusive");
+
+    Ok(())
+}
+
+#[derive(Debug, PartialEq, sqlx::Type)]
+#[sqlx(rename_all = "snake_case")]
+enum Status {
+    Active,
+    OnHold,
+    #[sqlx(rename = "done")]
+    Completed,
+}
+
+#[sqlx_macros::test]
+async fn it_can_bind_and_decode_a_strong_enum() -> anyhow::Result<()> {
+    let mut conn = new::<Sqlite>().await?;
+
+    conn.execute("CREATE TEMPORARY TABLE tasks (id INTEGER PRIMARY KEY, status TEXT NOT NULL)")
+        .await?;
+
+    for status in &[Status::Active, Status::OnHold, Status::Completed] {
+        sqlx::query("INSERT INTO tasks (status) VALUES (?)")
+            .bind(status)
+            .execute(&mut conn)
+            .await?;
+    }
+
+    let raw: Vec<String> = sqlx::query_scalar("SELECT status FROM tasks ORDER BY id")
+        .fetch_all(&mut conn)
+        .await?;
+
+    assert_eq!(raw, vec!["active", "on_hold", "done"]);
+
+    let statuses: Vec<Status> = sqlx::query_scalar("SELECT status FROM tasks ORDER BY id")
+        .fetch_all(&mut conn)
+        .await?;
+
+    assert_eq!(
+        statuses,
+        vec![Status::Active, Status::OnHold, Status::Completed]
+    );
+
+    let res: Result<Status, _> = sqlx::query_scalar("SELECT 'unknown'")
+        .fetch_one(&mut conn)
+        .await;
+
+    assert!(res.is_err());
+
+    Ok(())
+}